@@ -0,0 +1,58 @@
+use std::{cell::RefCell, pin::Pin};
+
+use crate::{inner, pin_ptr, reaction::Reaction, sbase, sbmlcxx, upcast_annotation};
+
+/// A safe wrapper around the libSBML ListOfSpeciesReferences class holding the
+/// products of a reaction.
+///
+/// This struct maintains a reference to the underlying C++ ListOfSpeciesReferences object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+pub struct ListOfProducts<'a> {
+    /// The underlying libSBML ListOfSpeciesReferences pointer wrapped in RefCell and Pin
+    inner: RefCell<Pin<&'a mut sbmlcxx::ListOfSpeciesReferences>>,
+}
+
+impl<'a> ListOfProducts<'a> {
+    pub fn new(reaction: &'a Reaction<'a>) -> Self {
+        let products_ptr = reaction.inner().borrow_mut().as_mut().getListOfProducts1();
+        let products = pin_ptr!(products_ptr, sbmlcxx::ListOfSpeciesReferences);
+
+        Self {
+            inner: RefCell::new(products),
+        }
+    }
+}
+
+// Derive the inner type from the ListOfSpeciesReferences type
+inner!(sbmlcxx::ListOfSpeciesReferences, ListOfProducts<'a>);
+sbase!(ListOfProducts<'a>, sbmlcxx::ListOfSpeciesReferences);
+upcast_annotation!(
+    ListOfProducts<'a>,
+    sbmlcxx::ListOfSpeciesReferences,
+    sbmlcxx::SBase
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::sbmldoc::SBMLDocument;
+
+    #[test]
+    fn test_list_of_products_annotation() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        let reaction = model.build_reaction("r1").product("s2", 1.0).build();
+
+        reaction
+            .set_products_annotation("<test>Test</test>")
+            .expect("Failed to set annotation");
+
+        assert_eq!(
+            reaction
+                .get_products_annotation()
+                .replace("\n", "")
+                .replace(" ", ""),
+            "<annotation><test>Test</test></annotation>"
+        );
+        assert!(reaction.get_reactants_annotation().is_empty());
+    }
+}
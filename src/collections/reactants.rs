@@ -0,0 +1,73 @@
+use std::{cell::RefCell, pin::Pin};
+
+use crate::{inner, pin_ptr, reaction::Reaction, sbase, sbmlcxx, upcast_annotation};
+
+/// A safe wrapper around the libSBML ListOfSpeciesReferences class holding the
+/// reactants of a reaction.
+///
+/// This struct maintains a reference to the underlying C++ ListOfSpeciesReferences object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+pub struct ListOfReactants<'a> {
+    /// The underlying libSBML ListOfSpeciesReferences pointer wrapped in RefCell and Pin
+    inner: RefCell<Pin<&'a mut sbmlcxx::ListOfSpeciesReferences>>,
+}
+
+impl<'a> ListOfReactants<'a> {
+    pub fn new(reaction: &'a Reaction<'a>) -> Self {
+        let reactants_ptr = reaction.inner().borrow_mut().as_mut().getListOfReactants1();
+        let reactants = pin_ptr!(reactants_ptr, sbmlcxx::ListOfSpeciesReferences);
+
+        Self {
+            inner: RefCell::new(reactants),
+        }
+    }
+}
+
+// Derive the inner type from the ListOfSpeciesReferences type
+inner!(sbmlcxx::ListOfSpeciesReferences, ListOfReactants<'a>);
+sbase!(ListOfReactants<'a>, sbmlcxx::ListOfSpeciesReferences);
+upcast_annotation!(
+    ListOfReactants<'a>,
+    sbmlcxx::ListOfSpeciesReferences,
+    sbmlcxx::SBase
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::{reader::SBMLReader, sbmldoc::SBMLDocument};
+
+    #[test]
+    fn test_list_of_reactants_annotation_roundtrip() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        let reaction = model
+            .build_reaction("r1")
+            .reactant("s1", 1.0)
+            .product("s2", 1.0)
+            .build();
+
+        reaction
+            .set_reactants_annotation(
+                "<test:reactants xmlns:test=\"http://test.org\">A</test:reactants>",
+            )
+            .expect("Failed to set annotation");
+
+        // The annotation lives on the libSBML list and survives rebuilding the wrappers
+        let xml = doc.to_xml_string();
+        let read_doc = SBMLReader::from_xml_string(&xml);
+        let read_model = read_doc.model().expect("Model not found");
+        let read_reaction = read_model.get_reaction("r1").expect("Reaction not found");
+
+        assert!(read_reaction
+            .get_reactants_annotation()
+            .contains("<test:reactants"));
+        assert!(!read_reaction
+            .get_products_annotation()
+            .contains("<test:reactants"));
+        assert_eq!(read_reaction.reactants().borrow().len(), 1);
+
+        // Cloning the reaction keeps the list annotation as well
+        let clone = (*read_reaction).clone();
+        assert!(clone.get_reactants_annotation().contains("<test:reactants"));
+    }
+}
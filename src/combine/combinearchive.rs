@@ -1,13 +1,20 @@
 use std::{
-    collections::HashMap,
+    collections::{BTreeMap, HashMap, HashSet},
     io::{Cursor, Read, Write},
     path::Path,
 };
 use zip::{write::SimpleFileOptions, ZipArchive, ZipWriter};
 
-use crate::combine::manifest::OmexManifest;
+use crate::{
+    combine::manifest::OmexManifest, errors::SBMLReadError, reader::SBMLReader,
+    sbmldoc::SBMLDocument,
+};
 
-use super::{error::CombineArchiveError, manifest::Content};
+use super::{
+    error::{CombineArchiveError, CombineValidationError},
+    manifest::Content,
+    metadata::{self, OmexMetadata, METADATA_FORMAT, METADATA_LOCATION},
+};
 
 /// A COMBINE Archive (OMEX) implementation for managing collections of files
 /// with metadata according to the COMBINE Archive specification.
@@ -116,6 +123,12 @@ impl CombineArchive {
     /// - Archive self-reference at location "."
     ///
     /// The manifest reference at "./manifest.xml" must exist in the archive or an error will be thrown.
+    ///
+    /// # Compression
+    ///
+    /// Entries may be stored uncompressed (Store) or compressed with any method supported
+    /// by the `zip` crate, as produced by external tools. Untouched entries keep their
+    /// original compression method when the archive is saved again.
     pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, CombineArchiveError> {
         let path_buf = path.as_ref().to_path_buf();
         let zip_data = std::fs::read(&path_buf)?;
@@ -179,6 +192,54 @@ impl CombineArchive {
         self.add_entry(location, format, master, &data[..])
     }
 
+    /// Adds all files of a directory tree to the archive.
+    ///
+    /// The directory is walked recursively and every file is added as a non-master
+    /// entry with the given format. The path of a file relative to `dir` is appended
+    /// to `base_location`, e.g. `results/run1/data.csv` within `dir` becomes
+    /// `./outputs/results/run1/data.csv` for the base location `./outputs`.
+    ///
+    /// # Arguments
+    ///
+    /// * `dir` - Path to the directory on disk to add
+    /// * `base_location` - Location within the archive to add the files under (e.g., "./outputs")
+    /// * `format` - MIME type or format identifier for all files
+    ///
+    /// # Errors
+    ///
+    /// * `CombineArchiveError::Io` - If the directory cannot be walked or a file cannot be read
+    /// * `CombineArchiveError::Manifest` - If there's an error updating the manifest
+    pub fn add_directory<P: AsRef<Path>>(
+        &mut self,
+        dir: P,
+        base_location: impl Into<String>,
+        format: impl Into<String>,
+    ) -> Result<(), CombineArchiveError> {
+        let base_location = base_location.into();
+        let base_location = base_location.trim_end_matches('/');
+        let format = format.into();
+
+        let mut directories = vec![(dir.as_ref().to_path_buf(), base_location.to_string())];
+        while let Some((directory, location)) = directories.pop() {
+            // Sort the entries to add files in a deterministic order
+            let mut entries = std::fs::read_dir(&directory)?.collect::<Result<Vec<_>, _>>()?;
+            entries.sort_by_key(|entry| entry.file_name());
+
+            for entry in entries {
+                let name = entry.file_name().to_string_lossy().into_owned();
+                let entry_location = format!("{location}/{name}");
+
+                if entry.file_type()?.is_dir() {
+                    directories.push((entry.path(), entry_location));
+                } else {
+                    self.add_file(entry.path(), entry_location, format.as_str(), false)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// Adds data to the archive from any source that implements `Read`.
     ///
     /// This is the primary method for adding content to the archive. It updates
@@ -291,11 +352,76 @@ impl CombineArchive {
         Ok(())
     }
 
+    /// Renames (moves) an entry within the archive.
+    ///
+    /// The manifest entry is updated in place, preserving its format and master flag.
+    /// The change is staged and will take effect when the archive is saved.
+    ///
+    /// # Arguments
+    ///
+    /// * `old_location` - Current location of the entry (e.g., "./model.xml")
+    /// * `new_location` - New location of the entry (e.g., "./models/model.xml")
+    ///
+    /// # Errors
+    ///
+    /// * `CombineArchiveError::CannotRemoveMandatoryEntry` - If either location is a mandatory entry
+    /// * `CombineArchiveError::FileNotFound` - If no entry exists at `old_location`
+    /// * `CombineArchiveError::LocationAlreadyExists` - If an entry already exists at `new_location`
+    /// * Other errors from [`entry`](Self::entry) method
+    pub fn rename_entry(
+        &mut self,
+        old_location: &str,
+        new_location: &str,
+    ) -> Result<(), CombineArchiveError> {
+        // Mandatory entries must stay at their fixed locations
+        for location in [old_location, new_location] {
+            if location == "." || location == "./manifest.xml" {
+                return Err(CombineArchiveError::CannotRemoveMandatoryEntry(
+                    location.to_string(),
+                ));
+            }
+        }
+
+        if self.has_entry(new_location) {
+            return Err(CombineArchiveError::LocationAlreadyExists(
+                new_location.to_string(),
+            ));
+        }
+
+        // Fetch the data before touching any state
+        let data = self.entry(old_location)?.data;
+
+        let old_zip_location = old_location.replace("./", "");
+        let new_zip_location = new_location.replace("./", "");
+
+        // Update the manifest while keeping format and master flag
+        if let Some(content) = self
+            .manifest
+            .content
+            .iter_mut()
+            .find(|c| c.location == old_location)
+        {
+            content.location = new_location.to_string();
+        }
+
+        // Move the data to its new key
+        self.pending_entries.remove(&old_zip_location);
+        self.removed_entries.insert(old_zip_location);
+        self.removed_entries.remove(&new_zip_location);
+        self.pending_entries.insert(new_zip_location, data);
+        self.needs_rebuild = true;
+
+        Ok(())
+    }
+
     /// Retrieves an entry from the archive.
     ///
     /// This method returns both the file data and its metadata. It will check
     /// pending changes first, then fall back to the original archive data.
     ///
+    /// Reading does not modify the archive, so several entries can be held at
+    /// the same time while the archive is only borrowed immutably.
+    ///
     /// # Arguments
     ///
     /// * `location` - Location of the entry to retrieve (e.g., "./model.xml")
@@ -310,7 +436,7 @@ impl CombineArchive {
     /// * `CombineArchiveError::FileNotFound` - If the entry doesn't exist
     /// * `CombineArchiveError::Zip` - If there's an error reading from the ZIP
     /// * `CombineArchiveError::Io` - If there's an I/O error
-    pub fn entry(&mut self, location: &str) -> Result<Entry, CombineArchiveError> {
+    pub fn entry(&self, location: &str) -> Result<Entry, CombineArchiveError> {
         if !self.manifest.has_location(location) {
             return Err(CombineArchiveError::FileNotFound(location.to_string()));
         }
@@ -362,10 +488,7 @@ impl CombineArchive {
     /// # Errors
     ///
     /// * `CombineArchiveError::FileNotFound` - If no entry with the specified format is found
-    pub fn entry_by_format(
-        &mut self,
-        format: impl Into<String>,
-    ) -> Result<Entry, CombineArchiveError> {
+    pub fn entry_by_format(&self, format: impl Into<String>) -> Result<Entry, CombineArchiveError> {
         let format = format.into();
         let location = self
             .manifest
@@ -392,7 +515,7 @@ impl CombineArchive {
     ///
     /// * `CombineArchiveError::MasterFileNotFound` - If no master file is defined
     /// * Other errors from [`entry`](Self::entry) method
-    pub fn master(&mut self) -> Result<Entry, CombineArchiveError> {
+    pub fn master(&self) -> Result<Entry, CombineArchiveError> {
         let location = self
             .manifest
             .master_file()
@@ -402,6 +525,105 @@ impl CombineArchive {
         self.entry(&location)
     }
 
+    /// Sets the Dublin Core metadata of an entry.
+    ///
+    /// The metadata of all entries is stored in the `./metadata.rdf` entry, which
+    /// is created if necessary. The change is staged and will take effect when the
+    /// archive is saved.
+    ///
+    /// An existing `./metadata.rdf` is only updated if all of its statements are
+    /// modelled by [`OmexMetadata`], regardless of formatting and namespace prefixes.
+    /// Metadata of other tools may contain statements beyond these Dublin Core terms
+    /// (e.g. a license) and is left untouched - use
+    /// [`replace_metadata`](Self::replace_metadata) to overwrite it explicitly.
+    ///
+    /// # Arguments
+    ///
+    /// * `location` - Location of the described entry (e.g., "./model.xml" or "." for the archive)
+    /// * `metadata` - The metadata of the entry
+    ///
+    /// # Errors
+    ///
+    /// * `CombineArchiveError::FileNotFound` - If the entry doesn't exist
+    /// * `CombineArchiveError::Metadata` - If the existing metadata cannot be parsed or
+    ///   contains statements that would be lost, or the metadata cannot be serialized
+    pub fn set_metadata(
+        &mut self,
+        location: &str,
+        metadata: OmexMetadata,
+    ) -> Result<(), CombineArchiveError> {
+        if !self.has_entry(location) {
+            return Err(CombineArchiveError::FileNotFound(location.to_string()));
+        }
+
+        let mut all_metadata = match self.read_metadata()? {
+            Some((xml, all_metadata)) => {
+                let unmodelled = metadata::unmodelled_content(&xml)
+                    .map_err(|e| CombineArchiveError::Metadata(e.to_string()))?;
+                if let Some(content) = unmodelled {
+                    return Err(CombineArchiveError::Metadata(format!(
+                        "{METADATA_LOCATION} contains {content}, which would be lost on rewrite"
+                    )));
+                }
+                all_metadata
+            }
+            None => BTreeMap::new(),
+        };
+
+        all_metadata.insert(location.to_string(), metadata);
+        self.write_metadata(&all_metadata)
+    }
+
+    /// Replaces the metadata of all entries.
+    ///
+    /// In contrast to [`set_metadata`](Self::set_metadata), an existing
+    /// `./metadata.rdf` is overwritten regardless of its content. The change is
+    /// staged and will take effect when the archive is saved.
+    ///
+    /// # Arguments
+    ///
+    /// * `metadata` - The metadata of the described entries, keyed by location
+    ///
+    /// # Errors
+    ///
+    /// * `CombineArchiveError::FileNotFound` - If a described entry doesn't exist
+    /// * `CombineArchiveError::Metadata` - If the metadata cannot be serialized
+    pub fn replace_metadata(
+        &mut self,
+        metadata: BTreeMap<String, OmexMetadata>,
+    ) -> Result<(), CombineArchiveError> {
+        if let Some(location) = metadata.keys().find(|location| !self.has_entry(location)) {
+            return Err(CombineArchiveError::FileNotFound(location.to_string()));
+        }
+
+        self.write_metadata(&metadata)
+    }
+
+    /// Gets the Dublin Core metadata of an entry.
+    ///
+    /// The metadata is read from the current `./metadata.rdf` entry, including
+    /// staged changes.
+    ///
+    /// # Arguments
+    ///
+    /// * `location` - Location of the described entry (e.g., "./model.xml" or "." for the archive)
+    ///
+    /// # Returns
+    ///
+    /// The metadata of the entry, or `None` if the entry has no metadata.
+    ///
+    /// # Errors
+    ///
+    /// * `CombineArchiveError::Metadata` - If `./metadata.rdf` cannot be parsed
+    pub fn get_metadata(
+        &self,
+        location: &str,
+    ) -> Result<Option<OmexMetadata>, CombineArchiveError> {
+        Ok(self
+            .read_metadata()?
+            .and_then(|(_, mut all_metadata)| all_metadata.remove(location)))
+    }
+
     /// Lists all entries in the archive.
     ///
     /// Returns references to the metadata for all files in the archive.
@@ -414,6 +636,33 @@ impl CombineArchive {
         self.manifest.content.iter().collect()
     }
 
+    /// Returns the number of entries in the archive.
+    ///
+    /// Like [`list_entries`](Self::list_entries), this includes the mandatory
+    /// entries and reflects any pending additions or removals.
+    ///
+    /// # Returns
+    ///
+    /// The number of entries listed in the manifest.
+    pub fn num_entries(&self) -> usize {
+        self.manifest.content.len()
+    }
+
+    /// Checks whether the archive contains no content besides the mandatory entries.
+    ///
+    /// The archive self-reference (".") and the manifest ("./manifest.xml") are
+    /// always present and therefore not counted as content.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the archive holds only the mandatory entries, `false` otherwise.
+    pub fn is_empty(&self) -> bool {
+        self.manifest
+            .content
+            .iter()
+            .all(|c| c.location == "." || c.location == "./manifest.xml")
+    }
+
     /// Checks if an entry exists in the archive.
     ///
     /// This checks the manifest for the specified location, reflecting
@@ -430,6 +679,85 @@ impl CombineArchive {
         self.manifest.has_location(location)
     }
 
+    /// Validates the archive against the COMBINE Archive specification.
+    ///
+    /// This checks the current state of the archive, including pending changes:
+    /// - Exactly one entry is flagged as master file
+    /// - Every manifest entry has corresponding data (pending or in the original ZIP)
+    /// - Every location is listed only once in the manifest
+    /// - The archive self-reference entry (".") is present
+    ///
+    /// The self-reference and the manifest entry itself are not backed by data
+    /// and are exempt from the data check.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the archive is valid, or all violations that were found.
+    pub fn validate(&self) -> Result<(), Vec<CombineValidationError>> {
+        let mut errors = Vec::new();
+
+        // Master file constraints
+        let masters: Vec<String> = self
+            .manifest
+            .content
+            .iter()
+            .filter(|c| c.master)
+            .map(|c| c.location.clone())
+            .collect();
+
+        match masters.len() {
+            0 => errors.push(CombineValidationError::MissingMaster),
+            1 => {}
+            _ => errors.push(CombineValidationError::MultipleMasters(masters)),
+        }
+
+        // Archive self-reference
+        if !self.manifest.has_location(".") {
+            errors.push(CombineValidationError::MissingSelfEntry);
+        }
+
+        // Unique locations
+        let mut seen = HashSet::new();
+        for content in &self.manifest.content {
+            if !seen.insert(content.location.as_str()) {
+                errors.push(CombineValidationError::DuplicateLocation(
+                    content.location.clone(),
+                ));
+            }
+        }
+
+        // Every entry must be backed by data
+        let original_files: HashSet<String> = self
+            .original_zip
+            .as_ref()
+            .and_then(|zip_data| ZipArchive::new(Cursor::new(zip_data)).ok())
+            .map(|archive| archive.file_names().map(str::to_string).collect())
+            .unwrap_or_default();
+
+        for content in &self.manifest.content {
+            if content.location == "." || content.location == "./manifest.xml" {
+                continue;
+            }
+
+            let zip_location = content.location.replace("./", "");
+            let has_data = self.pending_entries.contains_key(&zip_location)
+                || (!self.removed_entries.contains(&zip_location)
+                    && original_files.contains(&zip_location));
+
+            if !has_data {
+                errors.push(CombineValidationError::DanglingEntry(
+                    content.location.clone(),
+                ));
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
     /// Saves the archive to a file.
     ///
     /// This method builds the complete ZIP archive with all current entries
@@ -493,6 +821,48 @@ impl CombineArchive {
         self.build_zip()
     }
 
+    /// Extracts all entries of the archive to a directory.
+    ///
+    /// Every entry, including the manifest and pending changes, is written to its
+    /// location below `dir`. Missing subdirectories are created. Entries whose path
+    /// would escape `dir` (e.g. `../file.txt`) are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `dir` - Path to the directory to extract the archive to
+    ///
+    /// # Errors
+    ///
+    /// * `CombineArchiveError::Io` - If a file or directory cannot be written
+    /// * `CombineArchiveError::Zip` - If there's an error reading the ZIP
+    /// * `CombineArchiveError::Manifest` - If the manifest cannot be serialized
+    pub fn extract_to<P: AsRef<Path>>(&self, dir: P) -> Result<(), CombineArchiveError> {
+        let dir = dir.as_ref();
+        let zip_data = self.build_zip()?;
+        let mut archive = ZipArchive::new(Cursor::new(zip_data))?;
+
+        for i in 0..archive.len() {
+            let mut file = archive.by_index(i)?;
+            let Some(relative_path) = file.enclosed_name() else {
+                continue;
+            };
+            let path = dir.join(relative_path);
+
+            if file.is_dir() {
+                std::fs::create_dir_all(&path)?;
+                continue;
+            }
+
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            let mut output = std::fs::File::create(&path)?;
+            std::io::copy(&mut file, &mut output)?;
+        }
+
+        Ok(())
+    }
+
     // Private helper methods
 
     /// Extracts and parses the manifest from ZIP data.
@@ -517,6 +887,51 @@ impl CombineArchive {
         Ok(manifest)
     }
 
+    /// Reads and parses the metadata.rdf entry, if present.
+    ///
+    /// # Returns
+    ///
+    /// The raw content of the entry together with the parsed metadata, or `None` if
+    /// the archive has no metadata.rdf entry
+    fn read_metadata(
+        &self,
+    ) -> Result<Option<(String, BTreeMap<String, OmexMetadata>)>, CombineArchiveError> {
+        let data = match self.entry(METADATA_LOCATION) {
+            Ok(entry) => entry.data,
+            Err(CombineArchiveError::FileNotFound(_)) => return Ok(None),
+            Err(e) => return Err(e),
+        };
+
+        let xml = String::from_utf8(data)?;
+        let metadata =
+            metadata::from_xml(&xml).map_err(|e| CombineArchiveError::Metadata(e.to_string()))?;
+
+        Ok(Some((xml, metadata)))
+    }
+
+    /// Serializes metadata into the content of the metadata.rdf entry.
+    fn serialize_metadata(
+        metadata: &BTreeMap<String, OmexMetadata>,
+    ) -> Result<String, CombineArchiveError> {
+        metadata::to_xml(metadata).map_err(|e| CombineArchiveError::Metadata(e.to_string()))
+    }
+
+    /// Stages the metadata.rdf entry with the given metadata.
+    fn write_metadata(
+        &mut self,
+        metadata: &BTreeMap<String, OmexMetadata>,
+    ) -> Result<(), CombineArchiveError> {
+        let xml = Self::serialize_metadata(metadata)?;
+
+        // Keep the format of an existing metadata entry
+        let format = self
+            .find_content(METADATA_LOCATION)
+            .map(|content| content.format.clone())
+            .unwrap_or_else(|| METADATA_FORMAT.to_string());
+
+        self.add_entry(METADATA_LOCATION, format, false, xml.as_bytes())
+    }
+
     /// Finds content metadata by location.
     fn find_content(&self, location: &str) -> Option<&Content> {
         self.manifest
@@ -536,7 +951,7 @@ impl CombineArchive {
         if let Some(ref original_data) = self.original_zip {
             let mut original_archive = ZipArchive::new(Cursor::new(original_data))?;
             for i in 0..original_archive.len() {
-                let mut file = original_archive.by_index(i)?;
+                let file = original_archive.by_index_raw(i)?;
                 let name = file.name().to_string();
 
                 // Skip if removed, overwritten, or is manifest (we'll add manifest last)
@@ -547,8 +962,8 @@ impl CombineArchive {
                     continue;
                 }
 
-                writer.start_file(&name, options)?;
-                std::io::copy(&mut file, &mut writer)?;
+                // Copy without recompression to preserve the original method
+                writer.raw_copy_file(file)?;
             }
         }
 
@@ -608,6 +1023,16 @@ impl Entry {
     pub fn reader(&self) -> Cursor<&[u8]> {
         Cursor::new(&self.data)
     }
+
+    /// Parses the entry data into an SBML document.
+    ///
+    /// # Returns
+    ///
+    /// Returns the parsed document, or an [`SBMLReadError`] if the data is not
+    /// valid UTF-8 or the document contains errors of severity Error or Fatal.
+    pub fn to_sbml_document(&self) -> Result<SBMLDocument, SBMLReadError> {
+        SBMLReader::from_bytes(&self.data)
+    }
 }
 
 #[cfg(test)]
@@ -637,7 +1062,7 @@ mod tests {
     #[test]
     fn test_open_archive_to_sbml() {
         let archive_path = Path::new("tests/data/test.omex");
-        let mut archive = CombineArchive::open(archive_path).unwrap();
+        let archive = CombineArchive::open(archive_path).unwrap();
 
         // Get the master SBML file
         let master = archive.master().unwrap();
@@ -659,6 +1084,125 @@ mod tests {
         assert_eq!(csv_string, expected_csv_content);
     }
 
+    #[test]
+    fn test_open_stored_archive() {
+        let archive_path = Path::new("tests/data/test_stored.omex");
+        let archive = CombineArchive::open(archive_path).unwrap();
+
+        let master = archive.master().unwrap();
+        let expected_content = fs::read_to_string("tests/data/expected_omex_content.xml")
+            .unwrap()
+            .replace("\r\n", "\n");
+        assert_eq!(master.as_string().unwrap(), expected_content);
+
+        let csv_entry = archive.entry("./data.tsv").unwrap();
+        let expected_csv_content = fs::read_to_string("tests/data/expected_omex_data.tsv")
+            .unwrap()
+            .replace("\r\n", "\n");
+        assert_eq!(csv_entry.as_string().unwrap(), expected_csv_content);
+    }
+
+    #[test]
+    fn test_resave_stored_archive() {
+        let temp_dir = create_test_dir();
+        let archive_path = temp_dir.path().join("stored.omex");
+
+        let mut archive = CombineArchive::open("tests/data/test_stored.omex").unwrap();
+        archive
+            .add_entry("./notes.txt", "text/plain", false, b"notes".as_slice())
+            .unwrap();
+        archive.save(&archive_path).unwrap();
+
+        let reloaded = CombineArchive::open(&archive_path).unwrap();
+        let notes = reloaded.entry("./notes.txt").unwrap();
+        assert_eq!(notes.as_string().unwrap(), "notes");
+        assert!(reloaded
+            .entry("./model.xml")
+            .unwrap()
+            .as_string()
+            .unwrap()
+            .contains("<sbml"));
+    }
+
+    #[test]
+    fn test_rename_entry() {
+        let temp_dir = create_test_dir();
+        let archive_path = temp_dir.path().join("renamed.omex");
+
+        let mut archive = CombineArchive::open("tests/data/test.omex").unwrap();
+        archive
+            .rename_entry("./model.xml", "./models/model.xml")
+            .unwrap();
+
+        assert!(!archive.has_entry("./model.xml"));
+        assert!(archive.has_entry("./models/model.xml"));
+        archive.save(&archive_path).unwrap();
+
+        let reloaded = CombineArchive::open(&archive_path).unwrap();
+        assert!(!reloaded.has_entry("./model.xml"));
+        assert!(reloaded.entry("./model.xml").is_err());
+
+        let entry = reloaded.entry("./models/model.xml").unwrap();
+        assert!(entry.content.master);
+        assert_eq!(
+            entry.content.format,
+            "http://identifiers.org/combine.specifications/sbml"
+        );
+
+        let expected_content = fs::read_to_string("tests/data/expected_omex_content.xml")
+            .unwrap()
+            .replace("\r\n", "\n");
+        assert_eq!(entry.as_string().unwrap(), expected_content);
+    }
+
+    #[test]
+    fn test_rename_entry_errors() {
+        let mut archive = CombineArchive::new();
+        archive
+            .add_entry("./a.txt", "text/plain", false, b"a".as_slice())
+            .unwrap();
+        archive
+            .add_entry("./b.txt", "text/plain", false, b"b".as_slice())
+            .unwrap();
+
+        assert!(matches!(
+            archive.rename_entry("./a.txt", "./b.txt"),
+            Err(CombineArchiveError::LocationAlreadyExists(_))
+        ));
+        assert!(matches!(
+            archive.rename_entry("./missing.txt", "./c.txt"),
+            Err(CombineArchiveError::FileNotFound(_))
+        ));
+        assert!(matches!(
+            archive.rename_entry("./manifest.xml", "./c.xml"),
+            Err(CombineArchiveError::CannotRemoveMandatoryEntry(_))
+        ));
+    }
+
+    #[test]
+    fn test_num_entries_and_is_empty() {
+        let mut archive = CombineArchive::new();
+        assert_eq!(archive.num_entries(), 2);
+        assert!(archive.is_empty());
+
+        archive
+            .add_entry("./a.txt", "text/plain", false, b"a".as_slice())
+            .unwrap();
+        archive
+            .add_entry("./b.txt", "text/plain", false, b"b".as_slice())
+            .unwrap();
+        assert_eq!(archive.num_entries(), 4);
+        assert!(!archive.is_empty());
+
+        archive.remove_entry("./a.txt").unwrap();
+        assert_eq!(archive.num_entries(), 3);
+        assert!(!archive.is_empty());
+
+        archive.remove_entry("./b.txt").unwrap();
+        assert_eq!(archive.num_entries(), 2);
+        assert!(archive.is_empty());
+    }
+
     #[test]
     fn test_add_entry_basic() {
         let mut archive = CombineArchive::new();
@@ -738,6 +1282,50 @@ mod tests {
         assert_eq!(entry.as_string().unwrap(), "Hello from file!");
     }
 
+    #[test]
+    fn test_add_directory() {
+        let temp_dir = create_test_dir();
+        let results = temp_dir.path().join("results");
+        fs::create_dir_all(results.join("run1")).unwrap();
+        fs::write(results.join("summary.csv"), "a,b").unwrap();
+        fs::write(results.join("run1").join("data.csv"), "1,2").unwrap();
+
+        let mut archive = CombineArchive::new();
+        archive
+            .add_directory(&results, "./outputs/", "text/csv")
+            .unwrap();
+
+        // Two files besides the mandatory archive and manifest entries
+        assert_eq!(archive.num_entries(), 4);
+        assert!(archive.has_entry("./outputs/summary.csv"));
+        assert!(archive.has_entry("./outputs/run1/data.csv"));
+
+        let entry = archive.entry("./outputs/run1/data.csv").unwrap();
+        assert_eq!(entry.as_string().unwrap(), "1,2");
+        assert_eq!(entry.content.format, "text/csv");
+        assert!(!entry.content.master);
+    }
+
+    #[test]
+    fn test_extract_to() {
+        let temp_dir = create_test_dir();
+        let mut archive = CombineArchive::open("tests/data/test.omex").unwrap();
+        archive
+            .rename_entry("./data.tsv", "./data/data.tsv")
+            .unwrap();
+
+        archive.extract_to(temp_dir.path()).unwrap();
+
+        let model = fs::read(temp_dir.path().join("model.xml")).unwrap();
+        assert_eq!(model, archive.master().unwrap().data);
+
+        let data = fs::read(temp_dir.path().join("data").join("data.tsv")).unwrap();
+        assert_eq!(data, archive.entry("./data/data.tsv").unwrap().data);
+
+        assert!(temp_dir.path().join("manifest.xml").exists());
+        assert!(!temp_dir.path().join("data.tsv").exists());
+    }
+
     #[test]
     fn test_end_to_end_save_and_load() {
         let temp_dir = create_test_dir();
@@ -769,7 +1357,7 @@ mod tests {
         assert!(!archive.needs_rebuild); // Should be clean after save
 
         // Load from disk
-        let mut loaded_archive = CombineArchive::open(&archive_path).unwrap();
+        let loaded_archive = CombineArchive::open(&archive_path).unwrap();
         assert_eq!(loaded_archive.list_entries().len(), 4);
         assert!(loaded_archive.has_entry("./model.xml"));
         assert!(loaded_archive.has_entry("./data.csv"));
@@ -853,7 +1441,7 @@ mod tests {
         loaded_archive.save_changes().unwrap();
 
         // Reload and verify mutations
-        let mut final_archive = CombineArchive::open(&archive_path).unwrap();
+        let final_archive = CombineArchive::open(&archive_path).unwrap();
         assert_eq!(final_archive.list_entries().len(), 5);
         assert!(!final_archive.has_entry("./data1.csv"));
         assert!(final_archive.has_entry("./data2.csv"));
@@ -924,7 +1512,7 @@ mod tests {
 
         // Save and reload
         archive.save_changes().unwrap();
-        let mut final_archive = CombineArchive::open(&archive_path).unwrap();
+        let final_archive = CombineArchive::open(&archive_path).unwrap();
 
         // Verify final state
         assert_eq!(final_archive.list_entries().len(), 7);
@@ -959,7 +1547,7 @@ mod tests {
         let temp_path = temp_dir.path().join("from_bytes.omex");
         fs::write(&temp_path, &bytes).unwrap();
 
-        let mut loaded = CombineArchive::open(&temp_path).unwrap();
+        let loaded = CombineArchive::open(&temp_path).unwrap();
         assert!(loaded.has_entry("./test.txt"));
         let entry = loaded.entry("./test.txt").unwrap();
         assert_eq!(entry.as_string().unwrap(), "test content");
@@ -1293,7 +1881,7 @@ mod tests {
         archive.save_changes().unwrap();
 
         // Reload and verify
-        let mut final_archive = CombineArchive::open(&archive_path).unwrap();
+        let final_archive = CombineArchive::open(&archive_path).unwrap();
         assert_eq!(final_archive.list_entries().len(), 4);
 
         let model = final_archive.entry("./model.xml").unwrap();
@@ -1609,4 +2197,242 @@ mod tests {
             Err(CombineArchiveError::ManifestFileMissing)
         ));
     }
+
+    #[test]
+    fn test_entry_to_sbml_document() {
+        let archive = CombineArchive::open("tests/data/test.omex").unwrap();
+        let doc = archive
+            .master()
+            .unwrap()
+            .to_sbml_document()
+            .expect("Failed to parse master entry");
+
+        let model = doc.model().expect("Model not found");
+        assert_eq!(model.name(), "Test");
+        assert_eq!(model.list_of_species().len(), 4);
+    }
+
+    #[test]
+    fn test_set_and_get_metadata() {
+        let temp_dir = create_test_dir();
+        let archive_path = temp_dir.path().join("metadata.omex");
+
+        let mut archive = CombineArchive::new();
+        archive
+            .add_entry(
+                "./model.xml",
+                KnownFormats::SBML,
+                true,
+                b"<sbml/>".as_slice(),
+            )
+            .unwrap();
+
+        let metadata = OmexMetadata {
+            description: Some("A kinetic model of glycolysis".to_string()),
+            created: Some("2024-01-15T10:30:00Z".to_string()),
+            ..Default::default()
+        };
+        archive
+            .set_metadata("./model.xml", metadata.clone())
+            .unwrap();
+        assert!(archive.has_entry("./metadata.rdf"));
+        archive.save(&archive_path).unwrap();
+
+        let mut reopened = CombineArchive::open(&archive_path).unwrap();
+        assert_eq!(
+            reopened.get_metadata("./model.xml").unwrap(),
+            Some(metadata)
+        );
+        assert_eq!(reopened.get_metadata(".").unwrap(), None);
+
+        // Metadata written by this crate can be updated after reopening
+        let archive_metadata = OmexMetadata {
+            description: Some("Glycolysis archive".to_string()),
+            ..Default::default()
+        };
+        reopened
+            .set_metadata(".", archive_metadata.clone())
+            .unwrap();
+        assert_eq!(reopened.get_metadata(".").unwrap(), Some(archive_metadata));
+    }
+
+    #[test]
+    fn test_set_metadata_preserves_foreign_metadata() {
+        let foreign = r#"<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#" xmlns:dcterms="http://purl.org/dc/terms/">
+  <rdf:Description rdf:about="./model.xml">
+    <dcterms:description>A kinetic model</dcterms:description>
+    <dcterms:license rdf:resource="https://creativecommons.org/licenses/by/4.0/"/>
+  </rdf:Description>
+</rdf:RDF>"#;
+
+        let mut archive = CombineArchive::new();
+        archive
+            .add_entry(
+                "./model.xml",
+                KnownFormats::SBML,
+                true,
+                b"<sbml/>".as_slice(),
+            )
+            .unwrap();
+        archive
+            .add_entry("./metadata.rdf", METADATA_FORMAT, false, foreign.as_bytes())
+            .unwrap();
+
+        let metadata = archive.get_metadata("./model.xml").unwrap().unwrap();
+        assert_eq!(metadata.description, Some("A kinetic model".to_string()));
+
+        // The license statement cannot be represented and must not be dropped
+        let result = archive.set_metadata("./model.xml", OmexMetadata::default());
+        assert!(matches!(result, Err(CombineArchiveError::Metadata(_))));
+        let entry = archive.entry("./metadata.rdf").unwrap();
+        assert_eq!(entry.as_string().unwrap(), foreign);
+
+        // Explicit replacement overwrites the entry
+        let mut replacement = BTreeMap::new();
+        replacement.insert("./model.xml".to_string(), OmexMetadata::default());
+        archive.replace_metadata(replacement).unwrap();
+        assert_eq!(
+            archive.get_metadata("./model.xml").unwrap(),
+            Some(OmexMetadata::default())
+        );
+    }
+
+    #[test]
+    fn test_set_metadata_updates_reformatted_metadata() {
+        // Different prefixes, attribute order and whitespace than written by this crate
+        let existing = r#"<?xml version="1.0" encoding="UTF-8"?>
+<rdf:RDF xmlns:dc="http://purl.org/dc/terms/" xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#">
+<rdf:Description rdf:about="."><dc:created rdf:parseType="Resource"><dc:W3CDTF>2024-08-19T20:14:00Z</dc:W3CDTF></dc:created></rdf:Description>
+</rdf:RDF>"#;
+
+        let mut archive = CombineArchive::new();
+        archive
+            .add_entry(
+                "./model.xml",
+                KnownFormats::SBML,
+                true,
+                b"<sbml/>".as_slice(),
+            )
+            .unwrap();
+        archive
+            .add_entry(
+                "./metadata.rdf",
+                METADATA_FORMAT,
+                false,
+                existing.as_bytes(),
+            )
+            .unwrap();
+
+        let metadata = OmexMetadata {
+            description: Some("A kinetic model".to_string()),
+            ..Default::default()
+        };
+        archive
+            .set_metadata("./model.xml", metadata.clone())
+            .unwrap();
+
+        assert_eq!(archive.get_metadata("./model.xml").unwrap(), Some(metadata));
+        assert_eq!(
+            archive.get_metadata(".").unwrap().unwrap().created,
+            Some("2024-08-19T20:14:00Z".to_string())
+        );
+    }
+
+    #[test]
+    fn test_get_metadata_invalid() {
+        let mut archive = CombineArchive::new();
+        archive
+            .add_entry(
+                "./metadata.rdf",
+                METADATA_FORMAT,
+                false,
+                b"<rdf:RDF><unclosed>".as_slice(),
+            )
+            .unwrap();
+
+        let result = archive.get_metadata(".");
+        assert!(matches!(result, Err(CombineArchiveError::Metadata(_))));
+    }
+
+    #[test]
+    fn test_set_metadata_missing_entry() {
+        let mut archive = CombineArchive::new();
+        let result = archive.set_metadata("./missing.xml", OmexMetadata::default());
+        assert!(matches!(result, Err(CombineArchiveError::FileNotFound(_))));
+    }
+
+    #[test]
+    fn test_validate_valid_archive() {
+        let mut archive = CombineArchive::new();
+        archive
+            .add_entry(
+                "./model.xml",
+                KnownFormats::SBML,
+                true,
+                b"<sbml/>".as_slice(),
+            )
+            .unwrap();
+        assert!(archive.validate().is_ok());
+
+        let archive = CombineArchive::open("tests/data/test.omex").unwrap();
+        assert!(archive.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_missing_master() {
+        let mut archive = CombineArchive::new();
+        archive
+            .add_entry(
+                "./model.xml",
+                KnownFormats::SBML,
+                false,
+                b"<sbml/>".as_slice(),
+            )
+            .unwrap();
+
+        let errors = archive.validate().unwrap_err();
+        assert_eq!(errors, vec![CombineValidationError::MissingMaster]);
+    }
+
+    #[test]
+    fn test_validate_dangling_entry() {
+        let mut archive = CombineArchive::new();
+        archive
+            .add_entry(
+                "./model.xml",
+                KnownFormats::SBML,
+                true,
+                b"<sbml/>".as_slice(),
+            )
+            .unwrap();
+        archive
+            .manifest
+            .add_entry(
+                "./data.csv",
+                "http://purl.org/NET/mediatypes/text/csv",
+                false,
+            )
+            .unwrap();
+
+        let errors = archive.validate().unwrap_err();
+        assert_eq!(
+            errors,
+            vec![CombineValidationError::DanglingEntry(
+                "./data.csv".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_hold_multiple_entries() {
+        let archive = CombineArchive::open("tests/data/test.omex").unwrap();
+
+        let model = archive.entry("./model.xml").unwrap();
+        let data = archive.entry("./data.tsv").unwrap();
+        let master = archive.master().unwrap();
+
+        assert_eq!(model.data, master.data);
+        assert_eq!(data.content.location, "./data.tsv");
+        assert!(archive.validate().is_ok());
+    }
 }
@@ -9,10 +9,18 @@ pub enum CombineArchiveError {
     #[error("Zip error: {0}")]
     Zip(#[from] zip::result::ZipError),
 
+    /// Entry content is not valid UTF-8
+    #[error("UTF-8 error: {0}")]
+    Utf8(#[from] std::string::FromUtf8Error),
+
     /// Manifest parsing or serialization error
     #[error("Manifest error: {0}")]
     Manifest(#[from] quick_xml::DeError),
 
+    /// Metadata (metadata.rdf) parsing or serialization error
+    #[error("Metadata error: {0}")]
+    Metadata(String),
+
     /// Requested file not found in archive
     #[error("File not found: {0}")]
     FileNotFound(String),
@@ -41,3 +49,28 @@ pub enum CombineArchiveError {
     #[error("Manifest file (manifest.xml) is missing from the archive")]
     ManifestFileMissing,
 }
+
+/// Violations of the COMBINE Archive specification found by
+/// [`CombineArchive::validate`](super::combinearchive::CombineArchive::validate).
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum CombineValidationError {
+    /// No entry is flagged as master file
+    #[error("No master file defined in the archive")]
+    MissingMaster,
+
+    /// More than one entry is flagged as master file
+    #[error("Multiple master files defined: {}", .0.join(", "))]
+    MultipleMasters(Vec<String>),
+
+    /// A manifest entry has no corresponding data in the archive
+    #[error("No data found for manifest entry: {0}")]
+    DanglingEntry(String),
+
+    /// A location is listed more than once in the manifest
+    #[error("Duplicate location in manifest: {0}")]
+    DuplicateLocation(String),
+
+    /// The manifest does not reference the archive itself at location "."
+    #[error("Archive self-reference entry (.) is missing from the manifest")]
+    MissingSelfEntry,
+}
@@ -349,6 +349,18 @@ mod tests {
         assert_eq!(manifest.content[2].location, "./model.xml");
     }
 
+    #[test]
+    fn test_xml_deserialization_without_declaration() {
+        let xml = r#"<omexManifest xmlns="http://identifiers.org/combine.specifications/omex-manifest">
+  <content location="./model.xml" format="http://identifiers.org/combine.specifications/sbml" master="true" />
+</omexManifest>"#;
+
+        let manifest = OmexManifest::from_xml(xml).expect("Failed to deserialize from XML");
+
+        assert_eq!(manifest.content.len(), 1);
+        assert_eq!(manifest.content[0].location, "./model.xml");
+    }
+
     #[test]
     fn test_roundtrip_serialization() {
         let mut original = OmexManifest::new();
@@ -0,0 +1,522 @@
+//! The metadata module provides functionality for working with COMBINE archive metadata.
+//!
+//! Besides the manifest, COMBINE archives may contain a `metadata.rdf` file describing
+//! the archive and its entries using Dublin Core terms and vCard (descriptions,
+//! creators and timestamps). Each description refers to an entry by its location.
+//!
+//! This module provides:
+//! - The [`OmexMetadata`] type holding the metadata of a single entry
+//! - Serialization and deserialization of the RDF/XML `metadata.rdf` file
+
+use std::collections::{BTreeMap, HashSet};
+
+use quick_xml::{
+    events::{BytesStart, Event},
+    name::{Namespace, ResolveResult},
+    se::Serializer,
+    DeError, NsReader, SeError,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::history::ModelCreator;
+
+/// Location of the metadata file within a COMBINE archive
+pub(crate) const METADATA_LOCATION: &str = "./metadata.rdf";
+
+/// Format identifier of the metadata file
+pub(crate) const METADATA_FORMAT: &str =
+    "http://identifiers.org/combine.specifications/omex-metadata";
+
+const RDF_NS: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#";
+const DCTERMS_NS: &str = "http://purl.org/dc/terms/";
+const VCARD_NS: &str = "http://www.w3.org/2006/vcard/ns#";
+
+/// Dublin Core metadata of an entry within a COMBINE archive
+///
+/// Dates are W3C-DTF timestamps (e.g. "2024-01-15T10:30:00Z") and are stored as given.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OmexMetadata {
+    /// A human-readable description of the entry
+    pub description: Option<String>,
+    /// The date the entry was created
+    pub created: Option<String>,
+    /// The dates the entry was modified
+    pub modified: Vec<String>,
+    /// The people who created the entry
+    pub creators: Vec<ModelCreator>,
+}
+
+/// Root element of the `metadata.rdf` file
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename = "rdf:RDF")]
+struct Rdf {
+    #[serde(rename = "@xmlns:rdf", default)]
+    xmlns_rdf: String,
+    #[serde(rename = "@xmlns:dcterms", default)]
+    xmlns_dcterms: String,
+    #[serde(rename = "@xmlns:vCard", default)]
+    xmlns_vcard: String,
+    #[serde(rename = "rdf:Description", default)]
+    descriptions: Vec<Description>,
+}
+
+/// Metadata about a single entry, referenced by `rdf:about`
+#[derive(Debug, Serialize, Deserialize)]
+struct Description {
+    #[serde(rename = "@rdf:about")]
+    about: String,
+    #[serde(
+        rename = "dcterms:description",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    description: Option<String>,
+    #[serde(
+        rename = "dcterms:created",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    created: Option<W3CDate>,
+    #[serde(
+        rename = "dcterms:modified",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    modified: Vec<W3CDate>,
+    #[serde(
+        rename = "dcterms:creator",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    creators: Vec<VCard>,
+}
+
+/// A date in W3C-DTF notation
+#[derive(Debug, Serialize, Deserialize)]
+struct W3CDate {
+    #[serde(rename = "@rdf:parseType", default)]
+    parse_type: String,
+    #[serde(rename = "dcterms:W3CDTF")]
+    value: String,
+}
+
+/// A creator described as vCard
+#[derive(Debug, Serialize, Deserialize)]
+struct VCard {
+    #[serde(rename = "@rdf:parseType", default)]
+    parse_type: String,
+    #[serde(
+        rename = "vCard:hasName",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    name: Option<VCardName>,
+    #[serde(
+        rename = "vCard:email",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    email: Option<String>,
+    #[serde(
+        rename = "vCard:organization-name",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    organization: Option<String>,
+}
+
+/// The name of a vCard
+#[derive(Debug, Serialize, Deserialize)]
+struct VCardName {
+    #[serde(rename = "@rdf:parseType", default)]
+    parse_type: String,
+    #[serde(
+        rename = "vCard:family-name",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    family_name: Option<String>,
+    #[serde(
+        rename = "vCard:given-name",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    given_name: Option<String>,
+}
+
+impl W3CDate {
+    fn new(value: &str) -> Self {
+        Self {
+            parse_type: "Resource".to_string(),
+            value: value.to_string(),
+        }
+    }
+}
+
+impl From<&ModelCreator> for VCard {
+    fn from(creator: &ModelCreator) -> Self {
+        let name =
+            (creator.given_name.is_some() || creator.family_name.is_some()).then(|| VCardName {
+                parse_type: "Resource".to_string(),
+                family_name: creator.family_name.clone(),
+                given_name: creator.given_name.clone(),
+            });
+
+        Self {
+            parse_type: "Resource".to_string(),
+            name,
+            email: creator.email.clone(),
+            organization: creator.organization.clone(),
+        }
+    }
+}
+
+impl From<VCard> for ModelCreator {
+    fn from(vcard: VCard) -> Self {
+        let (given_name, family_name) = vcard
+            .name
+            .map(|name| (name.given_name, name.family_name))
+            .unwrap_or_default();
+
+        Self {
+            given_name,
+            family_name,
+            email: vcard.email,
+            organization: vcard.organization,
+        }
+    }
+}
+
+/// Parses the content of a `metadata.rdf` file.
+///
+/// # Arguments
+///
+/// * `xml` - The RDF/XML content
+///
+/// # Returns
+///
+/// The metadata of all described entries, keyed by location
+pub(crate) fn from_xml(xml: &str) -> Result<BTreeMap<String, OmexMetadata>, DeError> {
+    let rdf: Rdf = quick_xml::de::from_str(xml)?;
+
+    Ok(rdf
+        .descriptions
+        .into_iter()
+        .map(|description| {
+            let metadata = OmexMetadata {
+                description: description.description,
+                created: description.created.map(|date| date.value),
+                modified: description
+                    .modified
+                    .into_iter()
+                    .map(|date| date.value)
+                    .collect(),
+                creators: description
+                    .creators
+                    .into_iter()
+                    .map(ModelCreator::from)
+                    .collect(),
+            };
+
+            (description.about, metadata)
+        })
+        .collect())
+}
+
+/// Serializes metadata into the content of a `metadata.rdf` file.
+///
+/// # Arguments
+///
+/// * `metadata` - The metadata of all described entries, keyed by location
+///
+/// # Returns
+///
+/// The RDF/XML content
+pub(crate) fn to_xml(metadata: &BTreeMap<String, OmexMetadata>) -> Result<String, SeError> {
+    let rdf = Rdf {
+        xmlns_rdf: RDF_NS.to_string(),
+        xmlns_dcterms: DCTERMS_NS.to_string(),
+        xmlns_vcard: VCARD_NS.to_string(),
+        descriptions: metadata
+            .iter()
+            .map(|(location, metadata)| Description {
+                about: location.clone(),
+                description: metadata.description.clone(),
+                created: metadata.created.as_deref().map(W3CDate::new),
+                modified: metadata
+                    .modified
+                    .iter()
+                    .map(|date| W3CDate::new(date))
+                    .collect(),
+                creators: metadata.creators.iter().map(VCard::from).collect(),
+            })
+            .collect(),
+    };
+
+    let mut buffer = String::new();
+    let mut ser = Serializer::new(&mut buffer);
+    ser.indent(' ', 4);
+    rdf.serialize(ser)?;
+    Ok(buffer)
+}
+
+/// Finds content of a `metadata.rdf` file that is not modelled by [`OmexMetadata`].
+///
+/// Namespaces are resolved, so prefixes, attribute order and whitespace do not
+/// matter. Reported is everything that would be dropped by parsing the file with
+/// [`from_xml`] and writing it back with [`to_xml`], such as unknown properties,
+/// properties given as attributes (e.g. `rdf:resource`), repeated single-valued
+/// properties or several descriptions of the same entry.
+///
+/// # Arguments
+///
+/// * `xml` - The RDF/XML content
+///
+/// # Returns
+///
+/// A description of the first unmodelled content, or `None` if the file can be
+/// rewritten without losing statements
+pub(crate) fn unmodelled_content(xml: &str) -> Result<Option<String>, quick_xml::Error> {
+    let mut reader = NsReader::from_str(xml);
+    // Open elements with the single-valued children seen so far
+    let mut stack: Vec<((String, String), HashSet<(String, String)>)> = Vec::new();
+    let mut abouts = HashSet::new();
+
+    loop {
+        let (namespace, event) = reader.read_resolved_event()?;
+        let namespace = match namespace {
+            ResolveResult::Bound(Namespace(ns)) => String::from_utf8_lossy(ns).into_owned(),
+            _ => String::new(),
+        };
+
+        let is_empty = matches!(event, Event::Empty(_));
+        match event {
+            Event::Start(e) | Event::Empty(e) => {
+                let name = (
+                    namespace,
+                    String::from_utf8_lossy(e.local_name().as_ref()).into_owned(),
+                );
+                let qualified_name = String::from_utf8_lossy(e.name().as_ref()).into_owned();
+
+                let parent = stack.last_mut();
+                let Some(repeatable) = modelled_child(parent.as_ref().map(|(n, _)| n), &name)
+                else {
+                    return Ok(Some(format!("the unknown element {qualified_name}")));
+                };
+                if let Some((_, seen)) = parent {
+                    if !repeatable && !seen.insert(name.clone()) {
+                        return Ok(Some(format!("a repeated {qualified_name}")));
+                    }
+                }
+
+                if let Some(content) = unmodelled_attribute(&reader, &e, &name, &mut abouts)? {
+                    return Ok(Some(content));
+                }
+
+                if !is_empty {
+                    stack.push((name, HashSet::new()));
+                }
+            }
+            Event::Text(text) if !text.iter().all(u8::is_ascii_whitespace) => {
+                // Only the innermost elements hold text
+                if let Some((name, _)) = stack.last() {
+                    if !is_leaf(name) {
+                        return Ok(Some(format!("text within {}", name.1)));
+                    }
+                }
+            }
+            Event::End(_) => {
+                stack.pop();
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    Ok(None)
+}
+
+/// Checks the attributes of a modelled element for unmodelled content.
+///
+/// Besides namespace declarations, only `rdf:parseType` and the `rdf:about` of
+/// descriptions are modelled. Each entry may only be described once.
+fn unmodelled_attribute(
+    reader: &NsReader<&[u8]>,
+    element: &BytesStart,
+    name: &(String, String),
+    abouts: &mut HashSet<String>,
+) -> Result<Option<String>, quick_xml::Error> {
+    let is_description = name.0 == RDF_NS && name.1 == "Description";
+
+    for attribute in element.attributes() {
+        let attribute = attribute?;
+        if attribute.key.as_namespace_binding().is_some() {
+            continue;
+        }
+
+        let (namespace, local_name) = reader.resolve_attribute(attribute.key);
+        let is_rdf =
+            matches!(namespace, ResolveResult::Bound(Namespace(ns)) if ns == RDF_NS.as_bytes());
+
+        match (is_rdf, local_name.as_ref()) {
+            (true, b"parseType") => {}
+            (true, b"about") if is_description => {
+                let about = attribute.unescape_value()?.into_owned();
+                if !abouts.insert(about.clone()) {
+                    return Ok(Some(format!("several descriptions of {about}")));
+                }
+            }
+            _ => {
+                return Ok(Some(format!(
+                    "the unknown attribute {} of {}",
+                    String::from_utf8_lossy(attribute.key.as_ref()),
+                    String::from_utf8_lossy(element.name().as_ref())
+                )))
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+/// Checks whether an element may appear within the given parent.
+///
+/// # Returns
+///
+/// `Some(repeatable)` if the element is modelled at this position, `None` otherwise
+fn modelled_child(parent: Option<&(String, String)>, child: &(String, String)) -> Option<bool> {
+    let children: &[(&str, &str, bool)] =
+        match parent.map(|(ns, name)| (ns.as_str(), name.as_str())) {
+            None => &[(RDF_NS, "RDF", false)],
+            Some((RDF_NS, "RDF")) => &[(RDF_NS, "Description", true)],
+            Some((RDF_NS, "Description")) => &[
+                (DCTERMS_NS, "description", false),
+                (DCTERMS_NS, "created", false),
+                (DCTERMS_NS, "modified", true),
+                (DCTERMS_NS, "creator", true),
+            ],
+            Some((DCTERMS_NS, "created" | "modified")) => &[(DCTERMS_NS, "W3CDTF", false)],
+            Some((DCTERMS_NS, "creator")) => &[
+                (VCARD_NS, "hasName", false),
+                (VCARD_NS, "email", false),
+                (VCARD_NS, "organization-name", false),
+            ],
+            Some((VCARD_NS, "hasName")) => &[
+                (VCARD_NS, "family-name", false),
+                (VCARD_NS, "given-name", false),
+            ],
+            _ => &[],
+        };
+
+    children
+        .iter()
+        .find(|(ns, name, _)| *ns == child.0 && *name == child.1)
+        .map(|(_, _, repeatable)| *repeatable)
+}
+
+/// Checks whether an element holds a text value rather than other elements.
+fn is_leaf(name: &(String, String)) -> bool {
+    matches!(
+        (name.0.as_str(), name.1.as_str()),
+        (DCTERMS_NS, "description" | "W3CDTF")
+            | (
+                VCARD_NS,
+                "email" | "organization-name" | "family-name" | "given-name"
+            )
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metadata_roundtrip() {
+        let mut metadata = BTreeMap::new();
+        metadata.insert(
+            "./model.xml".to_string(),
+            OmexMetadata {
+                description: Some("A kinetic model".to_string()),
+                created: Some("2024-01-15T10:30:00Z".to_string()),
+                modified: vec!["2024-02-01T08:00:00Z".to_string()],
+                creators: vec![ModelCreator {
+                    given_name: Some("Jane".to_string()),
+                    family_name: Some("Doe".to_string()),
+                    email: Some("jane.doe@example.org".to_string()),
+                    organization: None,
+                }],
+            },
+        );
+
+        let xml = to_xml(&metadata).expect("Failed to serialize metadata");
+        assert!(xml.contains(r#"rdf:about="./model.xml""#));
+        assert!(xml.contains("<dcterms:description>A kinetic model</dcterms:description>"));
+
+        let parsed = from_xml(&xml).expect("Failed to parse metadata");
+        assert_eq!(parsed, metadata);
+    }
+
+    #[test]
+    fn test_metadata_from_xml() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#" xmlns:dcterms="http://purl.org/dc/terms/" xmlns:vCard="http://www.w3.org/2006/vcard/ns#">
+  <rdf:Description rdf:about=".">
+    <dcterms:created rdf:parseType="Resource">
+      <dcterms:W3CDTF>2024-08-19T20:14:00Z</dcterms:W3CDTF>
+    </dcterms:created>
+    <dcterms:creator rdf:parseType="Resource">
+      <vCard:hasName rdf:parseType="Resource">
+        <vCard:family-name>Doe</vCard:family-name>
+        <vCard:given-name>Jane</vCard:given-name>
+      </vCard:hasName>
+      <vCard:organization-name>University</vCard:organization-name>
+    </dcterms:creator>
+  </rdf:Description>
+</rdf:RDF>"#;
+
+        let metadata = from_xml(xml).expect("Failed to parse metadata");
+        let archive = metadata.get(".").expect("Archive metadata not found");
+
+        assert_eq!(archive.description, None);
+        assert_eq!(archive.created, Some("2024-08-19T20:14:00Z".to_string()));
+        assert_eq!(archive.creators[0].family_name, Some("Doe".to_string()));
+        assert_eq!(
+            archive.creators[0].organization,
+            Some("University".to_string())
+        );
+
+        assert_eq!(unmodelled_content(xml).unwrap(), None);
+    }
+
+    #[test]
+    fn test_unmodelled_content() {
+        let mut metadata = BTreeMap::new();
+        metadata.insert(".".to_string(), OmexMetadata::default());
+        let xml = to_xml(&metadata).expect("Failed to serialize metadata");
+        assert_eq!(unmodelled_content(&xml).unwrap(), None);
+
+        let rdf = |body: &str| {
+            format!(
+                r#"<rdf:RDF xmlns:rdf="{RDF_NS}" xmlns:dcterms="{DCTERMS_NS}">{body}</rdf:RDF>"#
+            )
+        };
+
+        let unmodelled = [
+            // Unknown property
+            r#"<rdf:Description rdf:about="."><dcterms:license>CC0</dcterms:license></rdf:Description>"#,
+            // Property given as a resource
+            r#"<rdf:Description rdf:about="."><dcterms:description rdf:resource="https://example.org"/></rdf:Description>"#,
+            // Repeated single-valued property
+            r#"<rdf:Description rdf:about="."><dcterms:description>a</dcterms:description><dcterms:description>b</dcterms:description></rdf:Description>"#,
+            // Several descriptions of the same entry
+            r#"<rdf:Description rdf:about="."/><rdf:Description rdf:about="."/>"#,
+        ];
+
+        for body in unmodelled {
+            assert!(
+                unmodelled_content(&rdf(body)).unwrap().is_some(),
+                "Not detected: {body}"
+            );
+        }
+    }
+}
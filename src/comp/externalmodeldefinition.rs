@@ -0,0 +1,176 @@
+//! This module provides a safe Rust interface to the libSBML ExternalModelDefinition class.
+//!
+//! The ExternalModelDefinition class belongs to the SBML Hierarchical Model Composition
+//! (comp) package. It declares a model stored outside of the current document, e.g. in
+//! another SBML file, which can then be instantiated as a submodel.
+//!
+//! This wrapper provides safe access to the underlying C++ libSBML ExternalModelDefinition
+//! class while maintaining Rust's safety guarantees through the use of RefCell and Pin.
+
+use std::{cell::RefCell, pin::Pin};
+
+use cxx::let_cxx_string;
+
+use crate::{
+    clone, errors::LibSBMLError, inner, optional_property, pin_ptr, plugin::get_document_plugin,
+    required_property, sbase, sbmlcxx, sbmldoc::SBMLDocument, traits::fromptr::FromPtr,
+    upcast_annotation, upcast_notes,
+};
+
+/// A safe wrapper around the libSBML ExternalModelDefinition class.
+///
+/// ExternalModelDefinition references a model defined outside of the document. It consists of:
+/// - An identifier, used by submodels to refer to it
+/// - A name (optional)
+/// - The source URI of the document containing the model
+/// - The identifier of the model within that document (optional, defaults to its main model)
+///
+/// This struct maintains a reference to the underlying C++ ExternalModelDefinition object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+pub struct ExternalModelDefinition<'a> {
+    inner: RefCell<Pin<&'a mut sbmlcxx::ExternalModelDefinition>>,
+}
+
+// Set the inner trait for the ExternalModelDefinition struct
+inner!(
+    sbmlcxx::ExternalModelDefinition,
+    ExternalModelDefinition<'a>
+);
+
+// Set the sbase trait for the ExternalModelDefinition struct
+sbase!(
+    ExternalModelDefinition<'a>,
+    sbmlcxx::ExternalModelDefinition
+);
+
+// Set the annotation trait for the ExternalModelDefinition struct
+upcast_annotation!(
+    ExternalModelDefinition<'a>,
+    sbmlcxx::ExternalModelDefinition,
+    sbmlcxx::SBase
+);
+
+// Set the notes trait for the ExternalModelDefinition struct
+upcast_notes!(
+    ExternalModelDefinition<'a>,
+    sbmlcxx::ExternalModelDefinition,
+    sbmlcxx::SBase
+);
+
+// Implement the Clone trait for the ExternalModelDefinition struct
+clone!(
+    ExternalModelDefinition<'a>,
+    sbmlcxx::ExternalModelDefinition
+);
+
+impl<'a> ExternalModelDefinition<'a> {
+    /// Creates a new ExternalModelDefinition instance within the given document.
+    ///
+    /// # Arguments
+    /// * `document` - The parent document that will contain this definition
+    /// * `id` - The identifier for this definition (must be unique within the document)
+    /// * `source` - The URI of the document containing the model (e.g. "enzyme.xml")
+    ///
+    /// # Returns
+    /// A new ExternalModelDefinition instance added to the document
+    ///
+    /// # Errors
+    /// Returns `LibSBMLError` if the comp plugin is not available or enabled in the document
+    pub fn new(document: &'a SBMLDocument, id: &str, source: &str) -> Result<Self, LibSBMLError> {
+        let mut comp_plugin =
+            get_document_plugin::<sbmlcxx::CompSBMLDocumentPlugin>(document, "comp")?;
+
+        let definition_ptr = comp_plugin.as_mut().createExternalModelDefinition();
+        let mut definition = pin_ptr!(definition_ptr, sbmlcxx::ExternalModelDefinition);
+
+        let_cxx_string!(id = id);
+        definition.as_mut().setId(&id);
+
+        let_cxx_string!(source = source);
+        definition.as_mut().setSource(&source);
+
+        Ok(Self {
+            inner: RefCell::new(definition),
+        })
+    }
+
+    // Getter and setter for id
+    required_property!(ExternalModelDefinition<'a>, id, String, getId, setId);
+
+    // Getter and setter for name
+    optional_property!(
+        ExternalModelDefinition<'a>,
+        name,
+        String,
+        getName,
+        setName,
+        isSetName
+    );
+
+    // Getter and setter for source
+    required_property!(
+        ExternalModelDefinition<'a>,
+        source,
+        String,
+        getSource,
+        setSource
+    );
+
+    // Getter and setter for model_ref
+    optional_property!(
+        ExternalModelDefinition<'a>,
+        model_ref,
+        String,
+        getModelRef,
+        setModelRef,
+        isSetModelRef
+    );
+}
+
+impl<'a> FromPtr<sbmlcxx::ExternalModelDefinition> for ExternalModelDefinition<'a> {
+    fn from_ptr(ptr: *mut sbmlcxx::ExternalModelDefinition) -> Self {
+        let definition = pin_ptr!(ptr, sbmlcxx::ExternalModelDefinition);
+
+        Self {
+            inner: RefCell::new(definition),
+        }
+    }
+}
+
+impl<'a> std::fmt::Debug for ExternalModelDefinition<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut ds = f.debug_struct("ExternalModelDefinition");
+        ds.field("id", &self.id());
+        ds.field("name", &self.name());
+        ds.field("source", &self.source());
+        ds.field("model_ref", &self.model_ref());
+        ds.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::packages::Package;
+
+    #[test]
+    fn test_external_model_definition_new() {
+        let doc = SBMLDocument::new(3, 1, vec![Package::Comp(1).into()]);
+
+        let definition = ExternalModelDefinition::new(&doc, "ext_enzyme", "enzyme.xml")
+            .expect("Failed to create external model definition");
+        definition.set_model_ref("enzyme");
+
+        assert_eq!(definition.id(), "ext_enzyme");
+        assert_eq!(definition.source(), "enzyme.xml");
+        assert_eq!(definition.model_ref(), Some("enzyme".to_string()));
+    }
+
+    #[test]
+    fn test_external_model_definition_without_plugin() {
+        let doc = SBMLDocument::new(3, 1, None);
+
+        let result = ExternalModelDefinition::new(&doc, "ext_enzyme", "enzyme.xml");
+        assert!(matches!(result, Err(LibSBMLError::PluginNotFound(_))));
+    }
+}
@@ -0,0 +1,136 @@
+//! This module provides a safe Rust interface to the libSBML Submodel class.
+//!
+//! The Submodel class belongs to the SBML Hierarchical Model Composition (comp) package.
+//! A submodel instantiates another model, either a model definition of the same document
+//! or an external model definition, as part of the containing model.
+//!
+//! This wrapper provides safe access to the underlying C++ libSBML Submodel class while
+//! maintaining Rust's safety guarantees through the use of RefCell and Pin.
+
+use std::{cell::RefCell, pin::Pin};
+
+use cxx::let_cxx_string;
+
+use crate::{
+    clone, errors::LibSBMLError, inner, model::Model, optional_property, pin_ptr,
+    plugin::get_plugin, required_property, sbase, sbmlcxx, traits::fromptr::FromPtr,
+    upcast_annotation, upcast_notes,
+};
+
+/// A safe wrapper around the libSBML Submodel class.
+///
+/// Submodel represents an instance of another model within a model. It consists of:
+/// - An identifier (required)
+/// - A name (optional)
+/// - A reference to the instantiated model or external model definition
+///
+/// This struct maintains a reference to the underlying C++ Submodel object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+pub struct Submodel<'a> {
+    inner: RefCell<Pin<&'a mut sbmlcxx::Submodel>>,
+}
+
+// Set the inner trait for the Submodel struct
+inner!(sbmlcxx::Submodel, Submodel<'a>);
+
+// Set the sbase trait for the Submodel struct
+sbase!(Submodel<'a>, sbmlcxx::Submodel);
+
+// Set the annotation trait for the Submodel struct
+upcast_annotation!(Submodel<'a>, sbmlcxx::Submodel, sbmlcxx::SBase);
+
+// Set the notes trait for the Submodel struct
+upcast_notes!(Submodel<'a>, sbmlcxx::Submodel, sbmlcxx::SBase);
+
+// Implement the Clone trait for the Submodel struct
+clone!(Submodel<'a>, sbmlcxx::Submodel);
+
+impl<'a> Submodel<'a> {
+    /// Creates a new Submodel instance within the given Model.
+    ///
+    /// # Arguments
+    /// * `model` - The parent Model that will contain this submodel
+    /// * `id` - The identifier for this submodel (must be unique within the model)
+    /// * `model_ref` - The identifier of the model or external model definition to instantiate
+    ///
+    /// # Returns
+    /// A new Submodel instance added to the model
+    ///
+    /// # Errors
+    /// Returns `LibSBMLError` if the comp plugin is not available or enabled in the model
+    pub fn new(model: &Model<'a>, id: &str, model_ref: &str) -> Result<Self, LibSBMLError> {
+        let mut comp_plugin =
+            get_plugin::<sbmlcxx::CompModelPlugin, Model<'a>, sbmlcxx::Model>(model, "comp")?;
+
+        let submodel_ptr = comp_plugin.as_mut().createSubmodel();
+        let mut submodel = pin_ptr!(submodel_ptr, sbmlcxx::Submodel);
+
+        let_cxx_string!(id = id);
+        submodel.as_mut().setId(&id);
+
+        let_cxx_string!(model_ref = model_ref);
+        submodel.as_mut().setModelRef(&model_ref);
+
+        Ok(Self {
+            inner: RefCell::new(submodel),
+        })
+    }
+
+    // Getter and setter for id
+    required_property!(Submodel<'a>, id, String, getId, setId);
+
+    // Getter and setter for name
+    optional_property!(Submodel<'a>, name, String, getName, setName, isSetName);
+
+    // Getter and setter for model_ref
+    required_property!(Submodel<'a>, model_ref, String, getModelRef, setModelRef);
+}
+
+impl<'a> FromPtr<sbmlcxx::Submodel> for Submodel<'a> {
+    fn from_ptr(ptr: *mut sbmlcxx::Submodel) -> Self {
+        let submodel = pin_ptr!(ptr, sbmlcxx::Submodel);
+
+        Self {
+            inner: RefCell::new(submodel),
+        }
+    }
+}
+
+impl<'a> std::fmt::Debug for Submodel<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut ds = f.debug_struct("Submodel");
+        ds.field("id", &self.id());
+        ds.field("name", &self.name());
+        ds.field("model_ref", &self.model_ref());
+        ds.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{packages::Package, sbmldoc::SBMLDocument};
+
+    #[test]
+    fn test_submodel_new() {
+        let doc = SBMLDocument::new(3, 1, vec![Package::Comp(1).into()]);
+        let model = Model::new(&doc, "test_model");
+
+        let submodel =
+            Submodel::new(&model, "sub1", "ext_enzyme").expect("Failed to create submodel");
+        submodel.set_name("Enzyme module");
+
+        assert_eq!(submodel.id(), "sub1");
+        assert_eq!(submodel.model_ref(), "ext_enzyme");
+        assert_eq!(submodel.name(), Some("Enzyme module".to_string()));
+    }
+
+    #[test]
+    fn test_submodel_without_plugin() {
+        let doc = SBMLDocument::new(3, 1, None);
+        let model = Model::new(&doc, "test_model");
+
+        let result = Submodel::new(&model, "sub1", "ext_enzyme");
+        assert!(matches!(result, Err(LibSBMLError::PluginNotFound(_))));
+    }
+}
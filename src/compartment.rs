@@ -12,11 +12,12 @@ use std::{cell::RefCell, pin::Pin, rc::Rc};
 use cxx::let_cxx_string;
 
 use crate::{
-    clone, get_unit_definition, inner, into_id,
+    clone, inner, into_id,
     model::Model,
     optional_property, pin_ptr, required_property, sbase, sbmlcxx, sbo_term,
-    traits::{fromptr::FromPtr, intoid::IntoId, sbase::SBase},
-    upcast_annotation,
+    traits::{fromptr::FromPtr, intoid::IntoId},
+    unitdef::UnitDefinition,
+    upcast_annotation, upcast_notes,
 };
 
 /// A safe wrapper around the libSBML Compartment class.
@@ -38,6 +39,9 @@ sbase!(Compartment<'a>, sbmlcxx::Compartment);
 // Set the annotation trait for the Compartment struct
 upcast_annotation!(Compartment<'a>, sbmlcxx::Compartment, sbmlcxx::SBase);
 
+// Set the notes trait for the Compartment struct
+upcast_notes!(Compartment<'a>, sbmlcxx::Compartment, sbmlcxx::SBase);
+
 // Set the into_id trait for the Compartment struct
 into_id!(&Rc<Compartment<'_>>, id);
 
@@ -66,8 +70,28 @@ impl<'a> Compartment<'a> {
         }
     }
 
-    // Gets the entire unit definition for the compartment.
-    get_unit_definition!(unit);
+    /// Resolves the unit definition of this compartment.
+    ///
+    /// The compartment's own `units` take precedence. If they are not set, the model's
+    /// default volume, area or length units apply for three, two or one spatial
+    /// dimensions respectively.
+    ///
+    /// # Arguments
+    /// * `model` - The model containing this compartment
+    ///
+    /// # Returns
+    /// The unit definition, or None if no units apply or they refer to a base unit
+    /// (e.g. "litre") instead of a unit definition of the model
+    pub fn unit_definition(&self, model: &Model<'a>) -> Option<Rc<UnitDefinition<'a>>> {
+        let unit = self.unit().or_else(|| match self.spatial_dimensions() {
+            Some(3) => model.volume_units(),
+            Some(2) => model.area_units(),
+            Some(1) => model.length_units(),
+            _ => None,
+        })?;
+
+        model.get_unit_definition(&unit)
+    }
 
     // Getter and setter methods for the id property
     required_property!(Compartment<'a>, id, String, getId, setId);
@@ -99,6 +123,22 @@ impl<'a> Compartment<'a> {
     // Getter and setter methods for the size property
     optional_property!(Compartment<'a>, size, f64, getSize, setSize, isSetSize);
 
+    /// Returns the effective initial size of this compartment.
+    ///
+    /// An initial assignment to the compartment takes precedence over its `size`
+    /// attribute, as defined by the SBML specification.
+    ///
+    /// # Arguments
+    /// * `model` - The model containing this compartment
+    ///
+    /// # Returns
+    /// The size from the initial assignment if present, otherwise the `size` attribute
+    pub fn effective_size(&self, model: &Model<'a>) -> Option<f64> {
+        model
+            .initial_assignment_value(&self.id())
+            .or_else(|| self.size())
+    }
+
     // Getter and setter methods for the volume property
     optional_property!(
         Compartment<'a>,
@@ -154,6 +194,10 @@ pub struct CompartmentBuilder<'a> {
 impl<'a> CompartmentBuilder<'a> {
     /// Creates a new CompartmentBuilder.
     ///
+    /// The `constant` attribute is required in SBML Level 3 and defaults to `true`,
+    /// so compartments built without calling [`CompartmentBuilder::constant`] still
+    /// serialize to valid SBML.
+    ///
     /// # Arguments
     /// * `model` - The model that will contain the compartment
     /// * `id` - The identifier for the new compartment
@@ -162,6 +206,7 @@ impl<'a> CompartmentBuilder<'a> {
     /// A new CompartmentBuilder instance
     pub fn new(model: &Model<'a>, id: &str) -> Self {
         let compartment = model.create_compartment(id);
+        compartment.set_constant(true);
         Self { compartment }
     }
 
@@ -302,6 +347,22 @@ impl std::fmt::Debug for Compartment<'_> {
     }
 }
 
+/// Compares two compartments by their SBML content.
+///
+/// Compartments are equal if their identifier, name, spatial dimensions, unit, size,
+/// outside and constant attributes are equal.
+impl PartialEq for Compartment<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id() == other.id()
+            && self.name() == other.name()
+            && self.spatial_dimensions() == other.spatial_dimensions()
+            && self.unit() == other.unit()
+            && self.size() == other.size()
+            && self.outside() == other.outside()
+            && self.constant() == other.constant()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -356,6 +417,27 @@ mod tests {
         assert_eq!(compartment.constant(), Some(true));
     }
 
+    #[test]
+    fn test_compartment_dimensions_serialized() {
+        let doc = SBMLDocument::default();
+        let model = Model::new(&doc, "test");
+        let compartment = model
+            .build_compartment("cytosol")
+            .spatial_dimensions(3)
+            .size(1.5)
+            .unit("litre")
+            .build();
+
+        assert_eq!(compartment.spatial_dimensions(), Some(3));
+        assert_eq!(compartment.size(), Some(1.5));
+        assert_eq!(compartment.unit(), Some("litre".to_string()));
+
+        let xml = compartment.to_xml_fragment();
+        assert!(xml.contains(r#"spatialDimensions="3""#));
+        assert!(xml.contains(r#"size="1.5""#));
+        assert!(xml.contains(r#"units="litre""#));
+    }
+
     #[test]
     fn test_compartment_annotation() {
         let doc = SBMLDocument::default();
@@ -413,7 +495,7 @@ mod tests {
 
         assert!(doc.check_consistency().valid);
 
-        let unit_definition = compartment.unit_definition().unwrap();
+        let unit_definition = compartment.unit_definition(&model).unwrap();
         assert_eq!(unit_definition.id(), "ml");
         assert_eq!(unit_definition.units().len(), 1);
         assert_eq!(unit_definition.units()[0].kind(), UnitKind::Litre);
@@ -422,4 +504,92 @@ mod tests {
         assert_eq!(unit_definition.units()[0].multiplier(), 1.0);
         assert_eq!(unit_definition.units()[0].offset(), 0.0);
     }
+
+    #[test]
+    fn test_compartment_builder_default_constant() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        let compartment = CompartmentBuilder::new(&model, "compartment")
+            .size(1.0)
+            .build();
+
+        assert_eq!(compartment.constant(), Some(true));
+
+        let xml = doc.to_xml_string();
+        assert!(xml.contains(r#"<compartment id="compartment" size="1" constant="true"/>"#));
+    }
+
+    #[test]
+    fn test_compartment_effective_size() {
+        let doc = SBMLDocument::default();
+        let model = Model::new(&doc, "test");
+        model.build_parameter("V").value(2.5).constant(true).build();
+
+        let cell = model.build_compartment("cell").size(1.0).build();
+        let medium = model.build_compartment("medium").size(10.0).build();
+
+        // Initial assignment overriding the size of the cell
+        let assignment_ptr = model
+            .inner()
+            .borrow_mut()
+            .as_mut()
+            .createInitialAssignment();
+        let mut assignment = pin_ptr!(assignment_ptr, sbmlcxx::InitialAssignment);
+        let_cxx_string!(symbol = "cell");
+        assignment.as_mut().setSymbol(&symbol);
+        let math = crate::math::parse_formula("2 * V").unwrap();
+        unsafe {
+            assignment
+                .as_mut()
+                .setMath(&*math as *const sbmlcxx::ASTNode)
+        };
+
+        assert_eq!(cell.size(), Some(1.0));
+        assert_eq!(cell.effective_size(&model), Some(5.0));
+        assert_eq!(medium.effective_size(&model), Some(10.0));
+    }
+
+    #[test]
+    fn test_compartment_eq() {
+        let doc = SBMLDocument::default();
+        let model = Model::new(&doc, "test");
+        let compartment = model
+            .build_compartment("cytosol")
+            .size(1.0)
+            .constant(true)
+            .build();
+
+        let clone = (*compartment).clone();
+        assert_eq!(*compartment, clone);
+
+        clone.set_size(2.0);
+        assert_ne!(*compartment, clone);
+    }
+
+    #[test]
+    fn test_compartment_unit_definition_model_default() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        model
+            .build_unit_definition("ml", "milliliter")
+            .unit(UnitKind::Litre, Some(1), Some(-3), None, None)
+            .build();
+        model.set_volume_units("ml");
+
+        let cytosol = CompartmentBuilder::new(&model, "cytosol")
+            .spatial_dimensions(3)
+            .constant(true)
+            .build();
+        let membrane = CompartmentBuilder::new(&model, "membrane")
+            .spatial_dimensions(2)
+            .constant(true)
+            .build();
+
+        let unit_definition = cytosol.unit_definition(&model).unwrap();
+        assert_eq!(unit_definition.id(), "ml");
+        assert_eq!(unit_definition.units()[0].scale(), -3);
+
+        // No area units are defined for the model
+        assert!(membrane.unit_definition(&model).is_none());
+    }
 }
@@ -0,0 +1,251 @@
+//! This module provides a safe Rust interface to the libSBML Constraint class.
+//!
+//! The Constraint class represents a condition that is expected to hold during a
+//! simulation of an SBML model. It consists of a boolean math expression and an
+//! optional XHTML message that is displayed when the constraint is violated.
+//!
+//! This wrapper provides safe access to the underlying C++ libSBML Constraint class while
+//! maintaining Rust's safety guarantees through the use of RefCell and Pin.
+
+use std::{cell::RefCell, pin::Pin};
+
+use cxx::let_cxx_string;
+
+use crate::{
+    clone,
+    errors::LibSBMLError,
+    inner,
+    math::{formula_from_ast, parse_formula},
+    model::Model,
+    pin_ptr, sbase,
+    sbmlcxx::{self},
+    sbo_term,
+    traits::fromptr::FromPtr,
+    upcast_annotation, upcast_notes,
+};
+
+/// A safe wrapper around the libSBML Constraint class.
+///
+/// This struct maintains a reference to the underlying C++ Constraint object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+pub struct Constraint<'a> {
+    inner: RefCell<Pin<&'a mut sbmlcxx::Constraint>>,
+}
+
+// Set the inner trait for the Constraint struct
+inner!(sbmlcxx::Constraint, Constraint<'a>);
+
+// Set the sbase trait for the Constraint struct
+sbase!(Constraint<'a>, sbmlcxx::Constraint);
+
+// Set the annotation trait for the Constraint struct
+upcast_annotation!(Constraint<'a>, sbmlcxx::Constraint, sbmlcxx::SBase);
+
+// Set the notes trait for the Constraint struct
+upcast_notes!(Constraint<'a>, sbmlcxx::Constraint, sbmlcxx::SBase);
+
+// Implement the Clone trait for the Constraint struct
+clone!(Constraint<'a>, sbmlcxx::Constraint);
+
+impl<'a> Constraint<'a> {
+    /// Creates a new Constraint instance within the given Model.
+    ///
+    /// # Arguments
+    /// * `model` - The parent Model that will contain this constraint
+    /// * `formula` - The boolean condition that should hold (e.g. "s1 >= 0")
+    ///
+    /// # Returns
+    /// A new Constraint instance, or an error if the formula is invalid
+    pub fn new(model: &Model<'a>, formula: &str) -> Result<Self, LibSBMLError> {
+        // Parse first, so that no constraint without math is created on failure
+        let math = parse_formula(formula)?;
+
+        let constraint_ptr = model.inner().borrow_mut().as_mut().createConstraint();
+        let mut constraint = pin_ptr!(constraint_ptr, sbmlcxx::Constraint);
+        unsafe { constraint.as_mut().setMath(&*math) };
+
+        Ok(Self {
+            inner: RefCell::new(constraint),
+        })
+    }
+
+    /// Returns the boolean condition of this constraint.
+    ///
+    /// # Returns
+    /// The formula as a String, or None if no math is set
+    pub fn formula(&self) -> Option<String> {
+        formula_from_ast(self.inner.borrow().getMath())
+    }
+
+    /// Sets the boolean condition of this constraint.
+    ///
+    /// # Arguments
+    /// * `formula` - The boolean condition that should hold
+    ///
+    /// # Returns
+    /// An error if the formula could not be parsed
+    pub fn set_formula(&self, formula: &str) -> Result<(), LibSBMLError> {
+        let math = parse_formula(formula)?;
+        unsafe { self.inner.borrow_mut().as_mut().setMath(&*math) };
+        Ok(())
+    }
+
+    /// Returns the message of this constraint.
+    ///
+    /// libSBML stores the message as an XML node, which is returned here in its
+    /// serialized XHTML form including the enclosing `<message>` element.
+    ///
+    /// # Returns
+    /// The serialized message, or None if no message is set
+    pub fn message(&self) -> Option<String> {
+        let inner = self.inner.borrow();
+        if !inner.isSetMessage() {
+            return None;
+        }
+
+        Some(inner.getMessageString().to_str().unwrap().to_string())
+    }
+
+    /// Sets the message of this constraint.
+    ///
+    /// The message is parsed into an XML node. Plain text is wrapped in XHTML
+    /// markup automatically, while strings starting with an XML element are
+    /// expected to already be valid XHTML.
+    ///
+    /// # Arguments
+    /// * `message` - The message to display when the constraint is violated
+    ///
+    /// # Returns
+    /// An error if the message could not be parsed
+    pub fn set_message(&self, message: &str) -> Result<(), LibSBMLError> {
+        let add_xhtml_markup = !message.trim_start().starts_with('<');
+
+        let_cxx_string!(cxx_message = message);
+        let result = self
+            .inner
+            .borrow_mut()
+            .as_mut()
+            .setMessage1(&cxx_message, add_xhtml_markup);
+
+        if result.0 != 0 {
+            return Err(LibSBMLError::InvalidArgument(format!(
+                "Invalid constraint message: {message}"
+            )));
+        }
+
+        Ok(())
+    }
+
+    // SBO Term Methods generated by the `sbo_term` macro
+    sbo_term!(sbmlcxx::Constraint, sbmlcxx::SBase);
+}
+
+impl std::fmt::Debug for Constraint<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut ds = f.debug_struct("Constraint");
+        ds.field("formula", &self.formula());
+        ds.field("message", &self.message());
+        ds.finish()
+    }
+}
+
+impl FromPtr<sbmlcxx::Constraint> for Constraint<'_> {
+    /// Creates a new Constraint instance from a unique pointer to a libSBML Constraint.
+    ///
+    /// This method is primarily used internally by the Model class to create
+    /// Constraint instances from libSBML Constraint pointers.
+    ///
+    /// # Arguments
+    /// * `ptr` - A unique pointer to a libSBML Constraint
+    ///
+    /// # Returns
+    /// A new Constraint instance
+    fn from_ptr(ptr: *mut sbmlcxx::Constraint) -> Self {
+        let constraint = pin_ptr!(ptr, sbmlcxx::Constraint);
+        Self {
+            inner: RefCell::new(constraint),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_constraint_new() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        let constraint = Constraint::new(&model, "s1 >= 0").expect("Failed to create constraint");
+
+        assert_eq!(constraint.formula(), Some("s1 >= 0".to_string()));
+        assert_eq!(constraint.message(), None);
+
+        constraint
+            .set_formula("s1 < 10")
+            .expect("Failed to set formula");
+        assert_eq!(constraint.formula(), Some("s1 < 10".to_string()));
+    }
+
+    #[test]
+    fn test_constraint_invalid_formula() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+
+        assert!(Constraint::new(&model, "s1 >=").is_err());
+    }
+
+    #[test]
+    fn test_constraint_message() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        let constraint = model
+            .create_constraint("s1 >= 0")
+            .expect("Failed to create constraint");
+
+        constraint
+            .set_message("Species s1 must not be negative")
+            .expect("Failed to set message");
+
+        let message = constraint.message().expect("Message not set");
+        assert!(message.contains("<message>"));
+        assert!(message.contains("Species s1 must not be negative"));
+    }
+
+    #[test]
+    fn test_constraint_roundtrip() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        model.build_compartment("c").size(1.0).build();
+        model
+            .build_species("s1")
+            .compartment("c")
+            .initial_concentration(1.0)
+            .has_only_substance_units(false)
+            .boundary_condition(false)
+            .constant(false)
+            .build();
+
+        let constraint = model
+            .create_constraint("s1 >= 0")
+            .expect("Failed to create constraint");
+        constraint
+            .set_message(r#"<p xmlns="http://www.w3.org/1999/xhtml">s1 must be positive</p>"#)
+            .expect("Failed to set message");
+
+        let log = doc.check_consistency();
+        assert!(log.valid, "{:#?}", log.errors);
+
+        let doc = SBMLReader::from_xml_string(&doc.to_xml_string());
+        let model = doc.model().expect("Model not found");
+        let constraints = model.list_of_constraints();
+
+        assert_eq!(constraints.len(), 1);
+        assert_eq!(constraints[0].formula(), Some("s1 >= 0".to_string()));
+        assert!(constraints[0]
+            .message()
+            .expect("Message not set")
+            .contains("s1 must be positive"));
+    }
+}
@@ -0,0 +1,341 @@
+//! Controlled vocabulary (CV) terms for MIRIAM annotations.
+//!
+//! Standard biological annotations are stored as RDF within the annotation of an
+//! SBML element. Each CV term relates the element to one or more external resources
+//! (e.g. a ChEBI or UniProt identifier) through a qualifier such as `bqbiol:is`.
+//!
+//! CV terms are attached to and read from elements through the
+//! [`Annotation`](crate::traits::annotation::Annotation) trait, using
+//! [`add_cv_term`](crate::traits::annotation::Annotation::add_cv_term) and
+//! [`cv_terms`](crate::traits::annotation::Annotation::cv_terms).
+
+use std::pin::Pin;
+
+use autocxx::WithinUniquePtr;
+use cxx::let_cxx_string;
+
+use crate::{errors::LibSBMLError, pin_ptr, sbmlcxx};
+
+/// A controlled vocabulary term relating an element to external resources.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CVTerm {
+    /// The relationship between the element and the resources
+    pub qualifier: Qualifier,
+    /// The URIs of the referenced resources (e.g. "https://identifiers.org/CHEBI:17234")
+    pub resources: Vec<String>,
+}
+
+/// The qualifier of a CV term.
+///
+/// Biological qualifiers (`bqbiol`) describe the biological entity an element
+/// represents, model qualifiers (`bqmodel`) describe the model itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Qualifier {
+    /// A biological qualifier (`bqbiol`)
+    Biological(BiologicalQualifier),
+    /// A model qualifier (`bqmodel`)
+    Model(ModelQualifier),
+    /// Unknown qualifier type
+    Unknown,
+}
+
+/// Biological qualifiers (`bqbiol`) of CV terms.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BiologicalQualifier {
+    /// `bqbiol:is` - the element is identical to the resource
+    Is,
+    /// `bqbiol:hasPart` - the element includes the resource
+    HasPart,
+    /// `bqbiol:isPartOf` - the element is a part of the resource
+    IsPartOf,
+    /// `bqbiol:isVersionOf` - the element is a version or instance of the resource
+    IsVersionOf,
+    /// `bqbiol:hasVersion` - the resource is a version or instance of the element
+    HasVersion,
+    /// `bqbiol:isHomologTo` - the element is homologous to the resource
+    IsHomologTo,
+    /// `bqbiol:isDescribedBy` - the element is described by the resource
+    IsDescribedBy,
+    /// `bqbiol:isEncodedBy` - the element is encoded by the resource
+    IsEncodedBy,
+    /// `bqbiol:encodes` - the element encodes the resource
+    Encodes,
+    /// `bqbiol:occursIn` - the element takes place in the resource
+    OccursIn,
+    /// `bqbiol:hasProperty` - the resource is a property of the element
+    HasProperty,
+    /// `bqbiol:isPropertyOf` - the element is a property of the resource
+    IsPropertyOf,
+    /// `bqbiol:hasTaxon` - the element is taxonomically restricted to the resource
+    HasTaxon,
+    /// Unknown biological qualifier
+    Unknown,
+}
+
+/// Model qualifiers (`bqmodel`) of CV terms.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ModelQualifier {
+    /// `bqmodel:is` - the model is identical to the resource
+    Is,
+    /// `bqmodel:isDescribedBy` - the model is described by the resource
+    IsDescribedBy,
+    /// `bqmodel:isDerivedFrom` - the model is derived from the resource
+    IsDerivedFrom,
+    /// `bqmodel:isInstanceOf` - the model is an instance of the resource
+    IsInstanceOf,
+    /// `bqmodel:hasInstance` - the resource is an instance of the model
+    HasInstance,
+    /// Unknown model qualifier
+    Unknown,
+}
+
+impl From<BiologicalQualifier> for Qualifier {
+    fn from(qualifier: BiologicalQualifier) -> Self {
+        Qualifier::Biological(qualifier)
+    }
+}
+
+impl From<ModelQualifier> for Qualifier {
+    fn from(qualifier: ModelQualifier) -> Self {
+        Qualifier::Model(qualifier)
+    }
+}
+
+impl From<BiologicalQualifier> for sbmlcxx::BiolQualifierType_t {
+    /// Converts a Rust BiologicalQualifier to the corresponding C++ SBML enum value
+    fn from(value: BiologicalQualifier) -> Self {
+        use sbmlcxx::BiolQualifierType_t as Cxx;
+
+        match value {
+            BiologicalQualifier::Is => Cxx::BQB_IS,
+            BiologicalQualifier::HasPart => Cxx::BQB_HAS_PART,
+            BiologicalQualifier::IsPartOf => Cxx::BQB_IS_PART_OF,
+            BiologicalQualifier::IsVersionOf => Cxx::BQB_IS_VERSION_OF,
+            BiologicalQualifier::HasVersion => Cxx::BQB_HAS_VERSION,
+            BiologicalQualifier::IsHomologTo => Cxx::BQB_IS_HOMOLOG_TO,
+            BiologicalQualifier::IsDescribedBy => Cxx::BQB_IS_DESCRIBED_BY,
+            BiologicalQualifier::IsEncodedBy => Cxx::BQB_IS_ENCODED_BY,
+            BiologicalQualifier::Encodes => Cxx::BQB_ENCODES,
+            BiologicalQualifier::OccursIn => Cxx::BQB_OCCURS_IN,
+            BiologicalQualifier::HasProperty => Cxx::BQB_HAS_PROPERTY,
+            BiologicalQualifier::IsPropertyOf => Cxx::BQB_IS_PROPERTY_OF,
+            BiologicalQualifier::HasTaxon => Cxx::BQB_HAS_TAXON,
+            BiologicalQualifier::Unknown => Cxx::BQB_UNKNOWN,
+        }
+    }
+}
+
+impl From<sbmlcxx::BiolQualifierType_t> for BiologicalQualifier {
+    /// Converts a C++ SBML BiolQualifierType_t enum to the Rust equivalent
+    fn from(value: sbmlcxx::BiolQualifierType_t) -> Self {
+        use sbmlcxx::BiolQualifierType_t as Cxx;
+
+        match value {
+            Cxx::BQB_IS => BiologicalQualifier::Is,
+            Cxx::BQB_HAS_PART => BiologicalQualifier::HasPart,
+            Cxx::BQB_IS_PART_OF => BiologicalQualifier::IsPartOf,
+            Cxx::BQB_IS_VERSION_OF => BiologicalQualifier::IsVersionOf,
+            Cxx::BQB_HAS_VERSION => BiologicalQualifier::HasVersion,
+            Cxx::BQB_IS_HOMOLOG_TO => BiologicalQualifier::IsHomologTo,
+            Cxx::BQB_IS_DESCRIBED_BY => BiologicalQualifier::IsDescribedBy,
+            Cxx::BQB_IS_ENCODED_BY => BiologicalQualifier::IsEncodedBy,
+            Cxx::BQB_ENCODES => BiologicalQualifier::Encodes,
+            Cxx::BQB_OCCURS_IN => BiologicalQualifier::OccursIn,
+            Cxx::BQB_HAS_PROPERTY => BiologicalQualifier::HasProperty,
+            Cxx::BQB_IS_PROPERTY_OF => BiologicalQualifier::IsPropertyOf,
+            Cxx::BQB_HAS_TAXON => BiologicalQualifier::HasTaxon,
+            Cxx::BQB_UNKNOWN => BiologicalQualifier::Unknown,
+        }
+    }
+}
+
+impl From<ModelQualifier> for sbmlcxx::ModelQualifierType_t {
+    /// Converts a Rust ModelQualifier to the corresponding C++ SBML enum value
+    fn from(value: ModelQualifier) -> Self {
+        use sbmlcxx::ModelQualifierType_t as Cxx;
+
+        match value {
+            ModelQualifier::Is => Cxx::BQM_IS,
+            ModelQualifier::IsDescribedBy => Cxx::BQM_IS_DESCRIBED_BY,
+            ModelQualifier::IsDerivedFrom => Cxx::BQM_IS_DERIVED_FROM,
+            ModelQualifier::IsInstanceOf => Cxx::BQM_IS_INSTANCE_OF,
+            ModelQualifier::HasInstance => Cxx::BQM_HAS_INSTANCE,
+            ModelQualifier::Unknown => Cxx::BQM_UNKNOWN,
+        }
+    }
+}
+
+impl From<sbmlcxx::ModelQualifierType_t> for ModelQualifier {
+    /// Converts a C++ SBML ModelQualifierType_t enum to the Rust equivalent
+    fn from(value: sbmlcxx::ModelQualifierType_t) -> Self {
+        use sbmlcxx::ModelQualifierType_t as Cxx;
+
+        match value {
+            Cxx::BQM_IS => ModelQualifier::Is,
+            Cxx::BQM_IS_DESCRIBED_BY => ModelQualifier::IsDescribedBy,
+            Cxx::BQM_IS_DERIVED_FROM => ModelQualifier::IsDerivedFrom,
+            Cxx::BQM_IS_INSTANCE_OF => ModelQualifier::IsInstanceOf,
+            Cxx::BQM_HAS_INSTANCE => ModelQualifier::HasInstance,
+            Cxx::BQM_UNKNOWN => ModelQualifier::Unknown,
+        }
+    }
+}
+
+/// Adds a CV term to the given SBML element.
+///
+/// CV terms can only be attached to elements with a meta identifier. If the element
+/// has none, `meta_<id>` is assigned.
+///
+/// # Arguments
+/// * `base` - The element to annotate
+/// * `qualifier` - The qualifier of the CV term
+/// * `resources` - The URIs of the referenced resources
+///
+/// # Returns
+/// `Ok(())` if the term has been added, or an error if the element has neither a
+/// meta identifier nor an identifier, or libSBML rejected the term
+pub(crate) fn add_cv_term(
+    mut base: Pin<&mut sbmlcxx::SBase>,
+    qualifier: Qualifier,
+    resources: &[&str],
+) -> Result<(), LibSBMLError> {
+    ensure_meta_id(base.as_mut())?;
+
+    let mut term = match qualifier {
+        Qualifier::Biological(qualifier) => {
+            let mut term = sbmlcxx::CVTerm::new(sbmlcxx::QualifierType_t::BIOLOGICAL_QUALIFIER)
+                .within_unique_ptr();
+            term.pin_mut().setBiologicalQualifierType(qualifier.into());
+            term
+        }
+        Qualifier::Model(qualifier) => {
+            let mut term =
+                sbmlcxx::CVTerm::new(sbmlcxx::QualifierType_t::MODEL_QUALIFIER).within_unique_ptr();
+            term.pin_mut().setModelQualifierType(qualifier.into());
+            term
+        }
+        Qualifier::Unknown => {
+            return Err(LibSBMLError::InvalidArgument(
+                "CV terms require a known qualifier".to_string(),
+            ))
+        }
+    };
+
+    for resource in resources {
+        let_cxx_string!(resource = *resource);
+        term.pin_mut().addResource(&resource);
+    }
+
+    // libSBML copies the term, so the local one is dropped afterwards
+    let result = unsafe { base.as_mut().addCVTerm(term.as_mut_ptr(), false) };
+
+    // LIBSBML_OPERATION_SUCCESS
+    if result.0 != 0 {
+        return Err(LibSBMLError::InvalidArgument(format!(
+            "Failed to add CV term (libSBML code {})",
+            result.0
+        )));
+    }
+
+    Ok(())
+}
+
+/// Assigns the meta identifier `meta_<id>` to an element that has none.
+///
+/// RDF annotations such as CV terms and model histories refer to elements by
+/// their meta identifier.
+///
+/// # Arguments
+/// * `base` - The element that requires a meta identifier
+///
+/// # Returns
+/// `Ok(())` if the element has a meta identifier, or an error if it has neither
+/// a meta identifier nor an identifier
+pub(crate) fn ensure_meta_id(mut base: Pin<&mut sbmlcxx::SBase>) -> Result<(), LibSBMLError> {
+    if base.isSetMetaId() {
+        return Ok(());
+    }
+
+    let id = base.getId().to_str().unwrap().to_string();
+    if id.is_empty() {
+        return Err(LibSBMLError::InvalidArgument(
+            "RDF annotations require the element to have a metaid or id".to_string(),
+        ));
+    }
+
+    let_cxx_string!(metaid = format!("meta_{id}"));
+    base.as_mut().setMetaId(&metaid);
+    Ok(())
+}
+
+/// Reads all CV terms of the given SBML element.
+///
+/// # Arguments
+/// * `base` - The element to read the CV terms from
+///
+/// # Returns
+/// All CV terms of the element in the order they are defined
+pub(crate) fn cv_terms(mut base: Pin<&mut sbmlcxx::SBase>) -> Vec<CVTerm> {
+    let n_terms = base.as_mut().getNumCVTerms().0;
+
+    (0..n_terms)
+        .map(|i| {
+            let term_ptr = base.as_mut().getCVTerm(i.into());
+            let term = pin_ptr!(term_ptr, sbmlcxx::CVTerm);
+
+            let qualifier = match term.getQualifierType() {
+                sbmlcxx::QualifierType_t::BIOLOGICAL_QUALIFIER => {
+                    Qualifier::Biological(term.getBiologicalQualifierType().into())
+                }
+                sbmlcxx::QualifierType_t::MODEL_QUALIFIER => {
+                    Qualifier::Model(term.getModelQualifierType().into())
+                }
+                sbmlcxx::QualifierType_t::UNKNOWN_QUALIFIER => Qualifier::Unknown,
+            };
+
+            let n_resources = term.getNumResources().0;
+            let resources = (0..n_resources)
+                .map(|j| term.getResourceURI(j.into()).to_str().unwrap().to_string())
+                .collect();
+
+            CVTerm {
+                qualifier,
+                resources,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_biological_qualifier_cxx_roundtrip() {
+        let qualifiers = [
+            BiologicalQualifier::Is,
+            BiologicalQualifier::HasPart,
+            BiologicalQualifier::IsVersionOf,
+            BiologicalQualifier::HasTaxon,
+        ];
+
+        for qualifier in qualifiers {
+            let cxx_qualifier: sbmlcxx::BiolQualifierType_t = qualifier.into();
+            assert_eq!(BiologicalQualifier::from(cxx_qualifier), qualifier);
+        }
+    }
+
+    #[test]
+    fn test_model_qualifier_cxx_roundtrip() {
+        let qualifiers = [
+            ModelQualifier::Is,
+            ModelQualifier::IsDescribedBy,
+            ModelQualifier::IsDerivedFrom,
+        ];
+
+        for qualifier in qualifiers {
+            let cxx_qualifier: sbmlcxx::ModelQualifierType_t = qualifier.into();
+            assert_eq!(ModelQualifier::from(cxx_qualifier), qualifier);
+        }
+    }
+}
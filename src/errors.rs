@@ -1,7 +1,37 @@
+use crate::sbmlerror::SBMLErrorLog;
+
 #[derive(Debug, thiserror::Error)]
 pub enum LibSBMLError {
     #[error("Plugin not found: {0}")]
     PluginNotFound(String),
     #[error("Invalid argument: {0}")]
     InvalidArgument(String),
+    #[error("Duplicate id: {0}")]
+    DuplicateId(String),
+    #[error("Missing value: {0}")]
+    MissingValue(String),
+}
+
+/// Errors that can occur when reading an SBML document from a file.
+#[derive(Debug, thiserror::Error)]
+pub enum SBMLReadError {
+    /// I/O error (missing file, insufficient permissions, etc.)
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// The content is not valid UTF-8
+    #[error("UTF-8 error: {0}")]
+    Utf8(#[from] std::string::FromUtf8Error),
+
+    /// The document was read but contains errors of severity Error or Fatal
+    #[error("Invalid SBML document: {} error(s) found", .0.errors.len())]
+    Invalid(SBMLErrorLog),
+}
+
+/// Errors that can occur when writing an SBML document to a file.
+#[derive(Debug, thiserror::Error)]
+pub enum SBMLWriteError {
+    /// I/O error (missing parent directory, insufficient permissions, etc.)
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
 }
@@ -0,0 +1,560 @@
+//! This module provides a safe Rust interface to the libSBML Event class.
+//!
+//! The Event class represents a discontinuous change in an SBML model. An event
+//! fires when its trigger condition switches from false to true, optionally after
+//! a delay, and then carries out a set of event assignments. When several events
+//! fire simultaneously, their priority determines the order of execution.
+//!
+//! This wrapper provides safe access to the underlying C++ libSBML Event class while
+//! maintaining Rust's safety guarantees through the use of RefCell and Pin.
+
+use std::{cell::RefCell, pin::Pin, rc::Rc};
+
+use cxx::let_cxx_string;
+
+use crate::{
+    clone,
+    errors::LibSBMLError,
+    eventassignment::EventAssignment,
+    inner, into_id,
+    math::{formula_from_ast, parse_formula},
+    model::Model,
+    optional_property, pin_ptr,
+    prelude::IntoId,
+    required_property, sbase,
+    sbmlcxx::{self},
+    sbo_term,
+    traits::fromptr::FromPtr,
+    upcast_annotation, upcast_notes,
+};
+
+/// A safe wrapper around the libSBML Event class.
+///
+/// This struct maintains a reference to the underlying C++ Event object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+/// It also maintains a vector of the event assignments associated with the event.
+pub struct Event<'a> {
+    inner: RefCell<Pin<&'a mut sbmlcxx::Event>>,
+    event_assignments: RefCell<Vec<Rc<EventAssignment<'a>>>>,
+}
+
+// Set the inner trait for the Event struct
+inner!(sbmlcxx::Event, Event<'a>);
+
+// Set the sbase trait for the Event struct
+sbase!(Event<'a>, sbmlcxx::Event);
+
+// Set the annotation trait for the Event struct
+upcast_annotation!(Event<'a>, sbmlcxx::Event, sbmlcxx::SBase);
+
+// Set the notes trait for the Event struct
+upcast_notes!(Event<'a>, sbmlcxx::Event, sbmlcxx::SBase);
+
+// Set the into_id trait for the Event struct
+into_id!(&Rc<Event<'_>>, id);
+
+// Implement the Clone trait for the Event struct
+clone!(Event<'a>, sbmlcxx::Event, event_assignments);
+
+impl<'a> Event<'a> {
+    /// Creates a new Event instance within the given Model.
+    ///
+    /// The `useValuesFromTriggerTime` attribute is required in SBML Level 3 and
+    /// defaults to `true`.
+    ///
+    /// # Arguments
+    /// * `model` - The parent Model that will contain this event
+    /// * `id` - The identifier for this event
+    ///
+    /// # Returns
+    /// A new Event instance
+    pub fn new(model: &Model<'a>, id: &str) -> Self {
+        let event_ptr = model.inner().borrow_mut().as_mut().createEvent();
+        let mut event = pin_ptr!(event_ptr, sbmlcxx::Event);
+
+        // Set the id of the event
+        let_cxx_string!(id = id);
+        event.as_mut().setId(&id);
+        event.as_mut().setUseValuesFromTriggerTime(true);
+
+        Self {
+            inner: RefCell::new(event),
+            event_assignments: RefCell::new(Vec::new()),
+        }
+    }
+
+    // Getter and setter for id
+    required_property!(Event<'a>, id, String, getId, setId);
+
+    // Getter and setter for name
+    optional_property!(Event<'a>, name, String, getName, setName, isSetName);
+
+    // Getter and setter for useValuesFromTriggerTime
+    optional_property!(
+        Event<'a>,
+        use_values_from_trigger_time,
+        bool,
+        getUseValuesFromTriggerTime,
+        setUseValuesFromTriggerTime,
+        isSetUseValuesFromTriggerTime
+    );
+
+    /// Returns the trigger condition of this event.
+    ///
+    /// # Returns
+    /// The trigger formula as a String, or None if no trigger is set
+    pub fn trigger(&self) -> Option<String> {
+        let inner = self.inner.borrow();
+        if !inner.isSetTrigger() {
+            return None;
+        }
+
+        let trigger = unsafe { &*inner.getTrigger() };
+        formula_from_ast(trigger.getMath())
+    }
+
+    /// Sets the trigger condition of this event.
+    ///
+    /// If the event has no trigger yet, one is created with the L3 attributes
+    /// `initialValue` and `persistent` set to `true`.
+    ///
+    /// # Arguments
+    /// * `formula` - The trigger condition (e.g. "time >= 10")
+    ///
+    /// # Returns
+    /// An error if the formula could not be parsed
+    pub fn set_trigger(&self, formula: &str) -> Result<(), LibSBMLError> {
+        let math = parse_formula(formula)?;
+
+        let mut inner = self.inner.borrow_mut();
+        let trigger_ptr = if inner.isSetTrigger() {
+            inner.as_mut().getTrigger1()
+        } else {
+            inner.as_mut().createTrigger()
+        };
+        let mut trigger = pin_ptr!(trigger_ptr, sbmlcxx::Trigger);
+
+        if !trigger.isSetInitialValue() {
+            trigger.as_mut().setInitialValue(true);
+        }
+
+        if !trigger.isSetPersistent() {
+            trigger.as_mut().setPersistent(true);
+        }
+
+        unsafe { trigger.as_mut().setMath(&*math) };
+        Ok(())
+    }
+
+    /// Returns the delay of this event.
+    ///
+    /// # Returns
+    /// The delay formula as a String, or None if no delay is set
+    pub fn delay(&self) -> Option<String> {
+        let inner = self.inner.borrow();
+        if !inner.isSetDelay() {
+            return None;
+        }
+
+        let delay = unsafe { &*inner.getDelay() };
+        formula_from_ast(delay.getMath())
+    }
+
+    /// Sets the delay between the trigger firing and the execution of the event.
+    ///
+    /// # Arguments
+    /// * `formula` - The delay formula (e.g. "5" or "tau")
+    ///
+    /// # Returns
+    /// An error if the formula could not be parsed
+    pub fn set_delay(&self, formula: &str) -> Result<(), LibSBMLError> {
+        let math = parse_formula(formula)?;
+
+        let mut inner = self.inner.borrow_mut();
+        let delay_ptr = if inner.isSetDelay() {
+            inner.as_mut().getDelay1()
+        } else {
+            inner.as_mut().createDelay()
+        };
+        let mut delay = pin_ptr!(delay_ptr, sbmlcxx::Delay);
+
+        unsafe { delay.as_mut().setMath(&*math) };
+        Ok(())
+    }
+
+    /// Returns the priority of this event.
+    ///
+    /// # Returns
+    /// The priority formula as a String, or None if no priority is set
+    pub fn priority(&self) -> Option<String> {
+        let inner = self.inner.borrow();
+        if !inner.isSetPriority() {
+            return None;
+        }
+
+        let priority = unsafe { &*inner.getPriority() };
+        formula_from_ast(priority.getMath())
+    }
+
+    /// Sets the priority of this event.
+    ///
+    /// # Arguments
+    /// * `formula` - The priority formula (e.g. "1")
+    ///
+    /// # Returns
+    /// An error if the formula could not be parsed
+    pub fn set_priority(&self, formula: &str) -> Result<(), LibSBMLError> {
+        let math = parse_formula(formula)?;
+
+        let mut inner = self.inner.borrow_mut();
+        let priority_ptr = if inner.isSetPriority() {
+            inner.as_mut().getPriority1()
+        } else {
+            inner.as_mut().createPriority()
+        };
+        let mut priority = pin_ptr!(priority_ptr, sbmlcxx::Priority);
+
+        unsafe { priority.as_mut().setMath(&*math) };
+        Ok(())
+    }
+
+    /// Creates a new event assignment for this event.
+    ///
+    /// # Arguments
+    /// * `variable` - The identifier of the variable to assign
+    /// * `formula` - The formula defining the value to assign to the variable
+    ///
+    /// # Returns
+    /// A reference-counted pointer to the new EventAssignment, or an error if the
+    /// formula could not be parsed
+    pub fn create_event_assignment(
+        &self,
+        variable: impl IntoId,
+        formula: &str,
+    ) -> Result<Rc<EventAssignment<'a>>, LibSBMLError> {
+        let event_assignment = Rc::new(EventAssignment::new(self, variable, formula)?);
+        self.event_assignments
+            .borrow_mut()
+            .push(Rc::clone(&event_assignment));
+        Ok(event_assignment)
+    }
+
+    /// Returns a vector of all event assignments of this event.
+    ///
+    /// # Returns
+    /// A vector containing Rc references to all EventAssignments of this event
+    pub fn event_assignments(&self) -> Vec<Rc<EventAssignment<'a>>> {
+        self.event_assignments.borrow().to_vec()
+    }
+
+    /// Retrieves an event assignment by the variable it assigns.
+    ///
+    /// # Arguments
+    /// * `variable` - The identifier of the assigned variable
+    ///
+    /// # Returns
+    /// Some(`Rc<EventAssignment>`) if found, None if not found
+    pub fn get_event_assignment(&self, variable: &str) -> Option<Rc<EventAssignment<'a>>> {
+        self.event_assignments
+            .borrow()
+            .iter()
+            .find(|event_assignment| event_assignment.variable() == variable)
+            .map(Rc::clone)
+    }
+
+    // SBO Term Methods generated by the `sbo_term` macro
+    sbo_term!(sbmlcxx::Event, sbmlcxx::SBase);
+}
+
+impl std::fmt::Debug for Event<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut ds = f.debug_struct("Event");
+        ds.field("id", &self.id());
+        ds.field("name", &self.name());
+        ds.field("trigger", &self.trigger());
+        ds.field("delay", &self.delay());
+        ds.field("priority", &self.priority());
+        ds.field("event_assignments", &self.event_assignments());
+        ds.finish()
+    }
+}
+
+impl FromPtr<sbmlcxx::Event> for Event<'_> {
+    /// Creates a new Event instance from a unique pointer to a libSBML Event.
+    ///
+    /// This method is primarily used internally by the Model class to create
+    /// Event instances from libSBML Event pointers.
+    ///
+    /// # Arguments
+    /// * `ptr` - A unique pointer to a libSBML Event
+    ///
+    /// # Returns
+    /// A new Event instance
+    fn from_ptr(ptr: *mut sbmlcxx::Event) -> Self {
+        let event = RefCell::new(pin_ptr!(ptr, sbmlcxx::Event));
+
+        // Get the list of event assignments
+        let n_event_assignments = event.borrow().getNumEventAssignments().0;
+        let event_assignments = (0..n_event_assignments)
+            .map(|i| {
+                let event_assignment = event.borrow_mut().as_mut().getEventAssignment1(i.into());
+                Rc::new(EventAssignment::from_ptr(event_assignment))
+            })
+            .collect();
+
+        Self {
+            inner: event,
+            event_assignments: RefCell::new(event_assignments),
+        }
+    }
+}
+
+/// A builder for creating Event instances with a fluent interface.
+pub struct EventBuilder<'a> {
+    event: Rc<Event<'a>>,
+}
+
+impl<'a> EventBuilder<'a> {
+    /// Creates a new EventBuilder instance.
+    ///
+    /// # Arguments
+    /// * `model` - The parent Model that will contain the event
+    /// * `id` - The identifier for the event
+    ///
+    /// # Returns
+    /// A new EventBuilder instance
+    pub fn new(model: &Model<'a>, id: &str) -> Self {
+        let event = model.create_event(id);
+        Self { event }
+    }
+
+    /// Sets the name of the event.
+    ///
+    /// # Arguments
+    /// * `name` - The name to set
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    pub fn name(self, name: &str) -> Self {
+        self.event.set_name(name);
+        self
+    }
+
+    /// Sets the trigger condition of the event.
+    ///
+    /// # Arguments
+    /// * `formula` - The trigger condition (e.g. "time >= 10")
+    ///
+    /// # Returns
+    /// Result containing the builder instance or an error if the formula is invalid
+    pub fn trigger(self, formula: &str) -> Result<Self, LibSBMLError> {
+        self.event.set_trigger(formula)?;
+        Ok(self)
+    }
+
+    /// Sets the delay of the event.
+    ///
+    /// # Arguments
+    /// * `formula` - The delay formula
+    ///
+    /// # Returns
+    /// Result containing the builder instance or an error if the formula is invalid
+    pub fn delay(self, formula: &str) -> Result<Self, LibSBMLError> {
+        self.event.set_delay(formula)?;
+        Ok(self)
+    }
+
+    /// Sets the priority of the event.
+    ///
+    /// # Arguments
+    /// * `formula` - The priority formula
+    ///
+    /// # Returns
+    /// Result containing the builder instance or an error if the formula is invalid
+    pub fn priority(self, formula: &str) -> Result<Self, LibSBMLError> {
+        self.event.set_priority(formula)?;
+        Ok(self)
+    }
+
+    /// Sets whether assignments are evaluated at the time the event is triggered.
+    ///
+    /// # Arguments
+    /// * `use_values_from_trigger_time` - true to evaluate assignments at trigger time
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    pub fn use_values_from_trigger_time(self, use_values_from_trigger_time: bool) -> Self {
+        self.event
+            .set_use_values_from_trigger_time(use_values_from_trigger_time);
+        self
+    }
+
+    /// Adds an event assignment to the event being built.
+    ///
+    /// # Arguments
+    /// * `variable` - The identifier of the variable to assign
+    /// * `formula` - The formula defining the value to assign to the variable
+    ///
+    /// # Returns
+    /// Result containing the builder instance or an error if the formula is invalid
+    pub fn event_assignment(
+        self,
+        variable: impl IntoId,
+        formula: &str,
+    ) -> Result<Self, LibSBMLError> {
+        self.event.create_event_assignment(variable, formula)?;
+        Ok(self)
+    }
+
+    /// Sets the annotation for this event.
+    ///
+    /// # Arguments
+    /// * `annotation` - The XML annotation string to set
+    ///
+    /// # Returns
+    /// Result containing the builder instance or an error if the annotation is invalid
+    pub fn annotation(self, annotation: &str) -> Result<Self, SeError> {
+        self.event
+            .set_annotation(annotation)
+            .map_err(|e| SeError::Custom(e.to_string()))?;
+        Ok(self)
+    }
+
+    /// Sets the annotation using a serializable type.
+    ///
+    /// # Arguments
+    /// * `annotation` - The annotation data to serialize and set
+    ///
+    /// # Returns
+    /// Result containing the builder instance or a serialization error
+    pub fn annotation_serde<T: Serialize>(self, annotation: &T) -> Result<Self, SeError> {
+        self.event.set_annotation_serde(annotation)?;
+        Ok(self)
+    }
+
+    /// Builds and returns the configured Event.
+    ///
+    /// # Returns
+    /// The constructed Event instance wrapped in an Rc
+    pub fn build(self) -> Rc<Event<'a>> {
+        self.event
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_event_new() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        let event = Event::new(&model, "event");
+
+        event.set_name("Event");
+        event.set_trigger("time >= 10").unwrap();
+        event.set_delay("5").unwrap();
+        event.set_priority("1").unwrap();
+
+        assert_eq!(event.id(), "event");
+        assert_eq!(event.name(), Some("Event".to_string()));
+        assert_eq!(event.use_values_from_trigger_time(), Some(true));
+        assert_eq!(event.trigger(), Some("time >= 10".to_string()));
+        assert_eq!(event.delay(), Some("5".to_string()));
+        assert_eq!(event.priority(), Some("1".to_string()));
+    }
+
+    #[test]
+    fn test_event_unset_math() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        let event = model.create_event("event");
+
+        assert_eq!(event.trigger(), None);
+        assert_eq!(event.delay(), None);
+        assert_eq!(event.priority(), None);
+    }
+
+    #[test]
+    fn test_event_invalid_trigger() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        let event = model.create_event("event");
+
+        assert!(event.set_trigger("time >=").is_err());
+        assert_eq!(event.trigger(), None);
+    }
+
+    #[test]
+    fn test_event_builder() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        let event = model
+            .build_event("event")
+            .name("Event")
+            .trigger("time >= 10")
+            .unwrap()
+            .use_values_from_trigger_time(false)
+            .event_assignment("s1", "0")
+            .unwrap()
+            .event_assignment("s2", "s2 * 2")
+            .unwrap()
+            .build();
+
+        assert_eq!(event.name(), Some("Event".to_string()));
+        assert_eq!(event.use_values_from_trigger_time(), Some(false));
+        assert_eq!(event.event_assignments().len(), 2);
+
+        let assignment = event.get_event_assignment("s2").unwrap();
+        assert_eq!(assignment.formula(), Some("s2 * 2".to_string()));
+    }
+
+    #[test]
+    fn test_event_roundtrip() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        model
+            .build_event("event")
+            .trigger("time >= 10")
+            .unwrap()
+            .delay("2")
+            .unwrap()
+            .event_assignment("s1", "0")
+            .unwrap()
+            .build();
+
+        let xml = doc.to_xml_string();
+        assert!(xml.contains("<listOfEvents>"));
+
+        let doc = SBMLReader::from_xml_string(&xml);
+        let model = doc.model().expect("Model not found");
+        let events = model.list_of_events();
+        assert_eq!(events.len(), 1);
+
+        let event = model.get_event("event").expect("Event not found");
+        assert_eq!(event.trigger(), Some("time >= 10".to_string()));
+        assert_eq!(event.delay(), Some("2".to_string()));
+
+        let assignments = event.event_assignments();
+        assert_eq!(assignments.len(), 1);
+        assert_eq!(assignments[0].variable(), "s1");
+        assert_eq!(assignments[0].formula(), Some("0".to_string()));
+    }
+
+    #[test]
+    fn test_event_annotation() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        let event = model
+            .build_event("event")
+            .annotation("<test>test</test>")
+            .unwrap()
+            .build();
+
+        assert_eq!(
+            event.get_annotation().replace("\n", "").replace(" ", ""),
+            "<annotation><test>test</test></annotation>"
+        );
+    }
+}
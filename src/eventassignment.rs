@@ -0,0 +1,186 @@
+//! This module provides a safe Rust interface to the libSBML EventAssignment class.
+//!
+//! The EventAssignment class represents a single assignment that is carried out
+//! when an event fires. It consists of the variable to assign and a mathematical
+//! formula defining the value assigned to it.
+//!
+//! This wrapper provides safe access to the underlying C++ libSBML EventAssignment class while
+//! maintaining Rust's safety guarantees through the use of RefCell and Pin.
+
+use std::{cell::RefCell, pin::Pin};
+
+use cxx::let_cxx_string;
+
+use crate::{
+    clone,
+    errors::LibSBMLError,
+    event::Event,
+    inner,
+    math::{formula_from_ast, parse_formula},
+    pin_ptr,
+    prelude::IntoId,
+    required_property, sbase,
+    sbmlcxx::{self},
+    sbo_term,
+    traits::fromptr::FromPtr,
+    upcast_annotation, upcast_notes,
+};
+
+/// A safe wrapper around the libSBML EventAssignment class.
+///
+/// This struct maintains a reference to the underlying C++ EventAssignment object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+pub struct EventAssignment<'a> {
+    inner: RefCell<Pin<&'a mut sbmlcxx::EventAssignment>>,
+}
+
+// Set the inner trait for the EventAssignment struct
+inner!(sbmlcxx::EventAssignment, EventAssignment<'a>);
+
+// Set the sbase trait for the EventAssignment struct
+sbase!(EventAssignment<'a>, sbmlcxx::EventAssignment);
+
+// Set the annotation trait for the EventAssignment struct
+upcast_annotation!(
+    EventAssignment<'a>,
+    sbmlcxx::EventAssignment,
+    sbmlcxx::SBase
+);
+
+// Set the notes trait for the EventAssignment struct
+upcast_notes!(
+    EventAssignment<'a>,
+    sbmlcxx::EventAssignment,
+    sbmlcxx::SBase
+);
+
+// Implement the Clone trait for the EventAssignment struct
+clone!(EventAssignment<'a>, sbmlcxx::EventAssignment);
+
+impl<'a> EventAssignment<'a> {
+    /// Creates a new EventAssignment instance within the given Event.
+    ///
+    /// # Arguments
+    /// * `event` - The parent Event that will contain this event assignment
+    /// * `variable` - The identifier of the variable to assign
+    /// * `formula` - The formula defining the value to assign to the variable
+    ///
+    /// # Returns
+    /// A new EventAssignment instance, or an error if the formula is invalid
+    pub fn new(
+        event: &Event<'a>,
+        variable: impl IntoId,
+        formula: &str,
+    ) -> Result<Self, LibSBMLError> {
+        // Parse first, so that no dangling assignment is created on failure
+        let math = parse_formula(formula)?;
+
+        let event_assignment_ptr = event.inner().borrow_mut().as_mut().createEventAssignment();
+        let mut event_assignment = pin_ptr!(event_assignment_ptr, sbmlcxx::EventAssignment);
+
+        let_cxx_string!(variable = variable.into_id());
+        event_assignment.as_mut().setVariable(&variable);
+        unsafe { event_assignment.as_mut().setMath(&*math) };
+
+        Ok(Self {
+            inner: RefCell::new(event_assignment),
+        })
+    }
+
+    // Getter and setter for variable
+    required_property!(
+        EventAssignment<'a>,
+        variable,
+        String,
+        getVariable,
+        setVariable,
+        impl IntoId
+    );
+
+    /// Returns the formula of the value assigned by this event assignment.
+    ///
+    /// # Returns
+    /// The formula as a String, or None if no math is set
+    pub fn formula(&self) -> Option<String> {
+        formula_from_ast(self.inner.borrow().getMath())
+    }
+
+    /// Sets the formula of the value assigned by this event assignment.
+    ///
+    /// # Arguments
+    /// * `formula` - The formula defining the value to assign to the variable
+    ///
+    /// # Returns
+    /// An error if the formula could not be parsed
+    pub fn set_formula(&self, formula: &str) -> Result<(), LibSBMLError> {
+        let math = parse_formula(formula)?;
+        unsafe { self.inner.borrow_mut().as_mut().setMath(&*math) };
+        Ok(())
+    }
+
+    // SBO Term Methods generated by the `sbo_term` macro
+    sbo_term!(sbmlcxx::EventAssignment, sbmlcxx::SBase);
+}
+
+impl std::fmt::Debug for EventAssignment<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut ds = f.debug_struct("EventAssignment");
+        ds.field("variable", &self.variable());
+        ds.field("formula", &self.formula());
+        ds.finish()
+    }
+}
+
+impl FromPtr<sbmlcxx::EventAssignment> for EventAssignment<'_> {
+    /// Creates a new EventAssignment instance from a unique pointer to a libSBML EventAssignment.
+    ///
+    /// This method is primarily used internally by the Event class to create
+    /// EventAssignment instances from libSBML EventAssignment pointers.
+    ///
+    /// # Arguments
+    /// * `ptr` - A unique pointer to a libSBML EventAssignment
+    ///
+    /// # Returns
+    /// A new EventAssignment instance
+    fn from_ptr(ptr: *mut sbmlcxx::EventAssignment) -> Self {
+        let event_assignment = pin_ptr!(ptr, sbmlcxx::EventAssignment);
+        Self {
+            inner: RefCell::new(event_assignment),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_event_assignment_new() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        let event = model.create_event("event");
+        let event_assignment = EventAssignment::new(&event, "s1", "s1 / 2")
+            .expect("Failed to create event assignment");
+
+        assert_eq!(event_assignment.variable(), "s1");
+        assert_eq!(event_assignment.formula(), Some("s1 / 2".to_string()));
+
+        event_assignment.set_variable("s2");
+        event_assignment
+            .set_formula("0")
+            .expect("Failed to set formula");
+
+        assert_eq!(event_assignment.variable(), "s2");
+        assert_eq!(event_assignment.formula(), Some("0".to_string()));
+    }
+
+    #[test]
+    fn test_event_assignment_invalid_formula() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        let event = model.create_event("event");
+
+        assert!(EventAssignment::new(&event, "s1", "s1 / (").is_err());
+    }
+}
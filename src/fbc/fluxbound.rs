@@ -7,20 +7,20 @@
 //! This wrapper provides safe access to the underlying C++ libSBML FluxBound class while
 //! maintaining Rust's safety guarantees through the use of RefCell and Pin.
 
-use std::{cell::RefCell, pin::Pin};
+use std::{cell::RefCell, pin::Pin, rc::Rc};
 
 use cxx::let_cxx_string;
 
 use crate::{
     clone,
     errors::LibSBMLError,
-    inner,
+    id_is_set, inner,
     model::Model,
     optional_property, pin_ptr,
     plugin::get_plugin,
-    required_property, sbmlcxx,
+    required_property, sbase, sbmlcxx,
     traits::{fromptr::FromPtr, intoid::IntoId},
-    upcast_annotation,
+    upcast_annotation, upcast_notes,
 };
 
 use super::fluxboundop::FluxBoundOperation;
@@ -42,8 +42,13 @@ pub struct FluxBound<'a> {
 
 inner!(sbmlcxx::FluxBound, FluxBound<'a>);
 
+sbase!(FluxBound<'a>, sbmlcxx::FluxBound);
+
 upcast_annotation!(FluxBound<'a>, sbmlcxx::FluxBound, sbmlcxx::SBase);
 
+// Set the notes trait for the FluxBound struct
+upcast_notes!(FluxBound<'a>, sbmlcxx::FluxBound, sbmlcxx::SBase);
+
 clone!(FluxBound<'a>, sbmlcxx::FluxBound);
 
 impl<'a> FluxBound<'a> {
@@ -115,6 +120,67 @@ impl<'a> FluxBound<'a> {
         getFluxBoundOperation,
         setOperation1
     );
+
+    // Getter and setter for value
+    optional_property!(FluxBound<'a>, value, f64, getValue, setValue, isSetValue);
+
+    // Checks whether the id is set, generated by the `id_is_set` macro
+    id_is_set!(sbmlcxx::FluxBound, sbmlcxx::SBase);
+}
+
+/// A builder for creating FluxBound instances with a fluent interface.
+///
+/// The reaction, operation and value of the flux bound are unset initially and
+/// should all be provided before the model is written.
+pub struct FluxBoundBuilder<'a> {
+    flux_bound: Rc<FluxBound<'a>>,
+}
+
+impl<'a> FluxBoundBuilder<'a> {
+    /// Creates a new FluxBoundBuilder.
+    ///
+    /// # Arguments
+    /// * `model` - The model that will contain the flux bound
+    /// * `id` - The identifier for the new flux bound
+    ///
+    /// # Errors
+    /// Returns `LibSBMLError` if the FBC plugin is not available or enabled in the model
+    pub fn new(model: &Model<'a>, id: &str) -> Result<Self, LibSBMLError> {
+        let flux_bound = model.create_flux_bound(id, "", FluxBoundOperation::Unknown)?;
+        Ok(Self { flux_bound })
+    }
+
+    /// Sets the reaction constrained by the flux bound.
+    ///
+    /// # Arguments
+    /// * `reaction_id` - The identifier of the reaction
+    pub fn reaction(self, reaction_id: impl IntoId) -> Self {
+        self.flux_bound.set_reaction(reaction_id.into_id());
+        self
+    }
+
+    /// Sets the operation of the flux bound.
+    ///
+    /// # Arguments
+    /// * `operation` - The type of constraint (e.g. [`FluxBoundOperation::GreaterEqual`])
+    pub fn operation(self, operation: impl Into<FluxBoundOperation>) -> Self {
+        self.flux_bound.set_operation(operation);
+        self
+    }
+
+    /// Sets the value of the flux bound.
+    ///
+    /// # Arguments
+    /// * `value` - The bound on the flux
+    pub fn value(self, value: f64) -> Self {
+        self.flux_bound.set_value(value);
+        self
+    }
+
+    /// Builds and returns the configured FluxBound instance.
+    pub fn build(self) -> Rc<FluxBound<'a>> {
+        self.flux_bound
+    }
 }
 
 impl<'a> FromPtr<sbmlcxx::FluxBound> for FluxBound<'a> {
@@ -372,4 +438,38 @@ mod tests {
         assert_eq!(fb2.id(), Some("upper_bound".to_string()));
         assert_eq!(fb3.id(), Some("exact_bound".to_string()));
     }
+
+    #[test]
+    fn test_flux_bound_builder() {
+        let doc = SBMLDocument::default();
+        let model = Model::new(&doc, "test_model");
+        model.create_reaction("r1");
+
+        let flux_bound = model
+            .build_flux_bound("lb_r1")
+            .expect("Failed to create flux bound builder")
+            .reaction("r1")
+            .operation(FluxBoundOperation::GreaterEqual)
+            .value(0.0)
+            .build();
+
+        assert_eq!(flux_bound.reaction(), Some("r1".to_string()));
+        assert_eq!(flux_bound.operation(), FluxBoundOperation::GreaterEqual);
+        assert_eq!(flux_bound.value(), Some(0.0));
+
+        let xml = doc.to_xml_string();
+        assert!(xml.contains(r#"fbc:id="lb_r1""#));
+        assert!(xml.contains(r#"fbc:reaction="r1""#));
+        assert!(xml.contains(r#"fbc:operation="greaterEqual""#));
+        assert!(xml.contains(r#"fbc:value="0""#));
+
+        let doc = crate::reader::SBMLReader::from_xml_string(&xml);
+        let model = doc.model().expect("Model not found");
+        assert_eq!(model.list_of_flux_bounds().len(), 1);
+
+        let flux_bound = model.get_flux_bound("lb_r1").expect("Flux bound not found");
+        assert_eq!(flux_bound.reaction(), Some("r1".to_string()));
+        assert_eq!(flux_bound.operation(), FluxBoundOperation::GreaterEqual);
+        assert_eq!(flux_bound.value(), Some(0.0));
+    }
 }
@@ -15,9 +15,9 @@ use cxx::let_cxx_string;
 use crate::{
     clone,
     errors::LibSBMLError,
-    inner, optional_property, pin_ptr, sbmlcxx,
+    id_is_set, inner, optional_property, pin_ptr, sbase, sbmlcxx,
     traits::{fromptr::FromPtr, intoid::IntoId},
-    upcast_annotation,
+    upcast_annotation, upcast_notes,
 };
 
 use super::objective::Objective;
@@ -38,8 +38,13 @@ pub struct FluxObjective<'a> {
 
 inner!(sbmlcxx::FluxObjective, FluxObjective<'a>);
 
+sbase!(FluxObjective<'a>, sbmlcxx::FluxObjective);
+
 upcast_annotation!(FluxObjective<'a>, sbmlcxx::FluxObjective, sbmlcxx::SBase);
 
+// Set the notes trait for the FluxObjective struct
+upcast_notes!(FluxObjective<'a>, sbmlcxx::FluxObjective, sbmlcxx::SBase);
+
 clone!(FluxObjective<'a>, sbmlcxx::FluxObjective);
 
 impl<'a> FluxObjective<'a> {
@@ -112,6 +117,9 @@ impl<'a> FluxObjective<'a> {
         setCoefficient,
         isSetCoefficient
     );
+
+    // Checks whether the id is set, generated by the `id_is_set` macro
+    id_is_set!(sbmlcxx::FluxObjective, sbmlcxx::SBase);
 }
 
 impl<'a> FromPtr<sbmlcxx::FluxObjective> for FluxObjective<'a> {
@@ -155,6 +163,23 @@ mod tests {
         assert_eq!(flux_objective.coefficient(), Some(1.0));
     }
 
+    #[test]
+    fn test_flux_objective_id_is_set() {
+        let doc = SBMLDocument::default();
+        let model = Model::new(&doc, "test_model");
+        let objective = Objective::new(&model, "obj1", ObjectiveType::Maximize)
+            .expect("Failed to create objective");
+
+        let with_id = FluxObjective::new(&objective, "fo1", "reaction1", 1.0)
+            .expect("Failed to create flux objective");
+        let without_id = FluxObjective::new(&objective, "", "reaction2", 1.0)
+            .expect("Failed to create flux objective");
+
+        assert!(with_id.id_is_set());
+        assert!(!without_id.id_is_set());
+        assert_eq!(without_id.id(), None);
+    }
+
     #[test]
     fn test_flux_objective_new_with_different_coefficients() {
         let doc = SBMLDocument::default();
@@ -0,0 +1,204 @@
+//! This module provides a safe Rust interface to the libSBML GeneProduct class.
+//!
+//! The GeneProduct class represents a gene or gene product in an SBML FBC (Flux Balance
+//! Constraints) model. Gene products are referenced by gene product associations of
+//! reactions to express gene-protein-reaction (GPR) rules, as commonly found in
+//! genome-scale metabolic models. Gene products are only available in FBC version 2.
+//!
+//! This wrapper provides safe access to the underlying C++ libSBML GeneProduct class while
+//! maintaining Rust's safety guarantees through the use of RefCell and Pin.
+
+use std::{cell::RefCell, pin::Pin};
+
+use cxx::let_cxx_string;
+
+use crate::{
+    clone,
+    errors::LibSBMLError,
+    inner,
+    model::Model,
+    optional_property, pin_ptr,
+    plugin::{get_plugin, package_version},
+    prelude::IntoId,
+    required_property, sbase, sbmlcxx,
+    traits::fromptr::FromPtr,
+    upcast_annotation, upcast_notes,
+};
+
+/// A safe wrapper around the libSBML GeneProduct class.
+///
+/// GeneProduct represents a gene or gene product in an SBML FBC model. It consists of:
+/// - An identifier (required)
+/// - A label, usually the gene identifier of an external database (required)
+/// - A name (optional)
+/// - A reference to a species representing the gene product (optional)
+///
+/// This struct maintains a reference to the underlying C++ GeneProduct object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+pub struct GeneProduct<'a> {
+    inner: RefCell<Pin<&'a mut sbmlcxx::GeneProduct>>,
+}
+
+// Set the inner trait for the GeneProduct struct
+inner!(sbmlcxx::GeneProduct, GeneProduct<'a>);
+
+// Set the sbase trait for the GeneProduct struct
+sbase!(GeneProduct<'a>, sbmlcxx::GeneProduct);
+
+// Set the annotation trait for the GeneProduct struct
+upcast_annotation!(GeneProduct<'a>, sbmlcxx::GeneProduct, sbmlcxx::SBase);
+
+// Set the notes trait for the GeneProduct struct
+upcast_notes!(GeneProduct<'a>, sbmlcxx::GeneProduct, sbmlcxx::SBase);
+
+// Implement the Clone trait for the GeneProduct struct
+clone!(GeneProduct<'a>, sbmlcxx::GeneProduct);
+
+impl<'a> GeneProduct<'a> {
+    /// Creates a new GeneProduct instance within the given Model.
+    ///
+    /// # Arguments
+    /// * `model` - The parent Model that will contain this gene product
+    /// * `id` - The identifier for this gene product (must be unique within the model)
+    /// * `label` - The label of this gene product (e.g. "b0001")
+    ///
+    /// # Returns
+    /// A new GeneProduct instance initialized with the given parameters and added to the model
+    ///
+    /// # Errors
+    /// Returns `LibSBMLError` if:
+    /// - The FBC plugin is not available or enabled in the model
+    /// - The FBC package version does not support gene products (version 1)
+    pub fn new(model: &Model<'a>, id: &str, label: &str) -> Result<Self, LibSBMLError> {
+        let mut fbc_plugin =
+            get_plugin::<sbmlcxx::FbcModelPlugin, Model<'a>, sbmlcxx::Model>(model, "fbc")?;
+
+        if package_version(&mut fbc_plugin) < 2 {
+            return Err(LibSBMLError::InvalidArgument(
+                "Gene products require version 2 of the FBC package".to_string(),
+            ));
+        }
+
+        let gene_product_ptr = fbc_plugin.as_mut().createGeneProduct();
+
+        let mut gene_product = pin_ptr!(gene_product_ptr, sbmlcxx::GeneProduct);
+
+        // Set the id of the gene product
+        let_cxx_string!(id = id);
+        gene_product.as_mut().setId(&id);
+
+        // Set the label of the gene product
+        let_cxx_string!(label = label);
+        gene_product.as_mut().setLabel(&label);
+
+        Ok(Self {
+            inner: RefCell::new(gene_product),
+        })
+    }
+
+    // Getter and setter for id
+    required_property!(GeneProduct<'a>, id, String, getId, setId);
+
+    // Getter and setter for label
+    required_property!(GeneProduct<'a>, label, String, getLabel, setLabel);
+
+    // Getter and setter for name
+    optional_property!(GeneProduct<'a>, name, String, getName, setName, isSetName);
+
+    // Getter and setter for associated species
+    optional_property!(
+        GeneProduct<'a>,
+        associated_species,
+        String,
+        getAssociatedSpecies,
+        setAssociatedSpecies,
+        isSetAssociatedSpecies,
+        impl IntoId
+    );
+}
+
+impl<'a> FromPtr<sbmlcxx::GeneProduct> for GeneProduct<'a> {
+    fn from_ptr(ptr: *mut sbmlcxx::GeneProduct) -> Self {
+        let gene_product = pin_ptr!(ptr, sbmlcxx::GeneProduct);
+
+        Self {
+            inner: RefCell::new(gene_product),
+        }
+    }
+}
+
+impl<'a> std::fmt::Debug for GeneProduct<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut ds = f.debug_struct("GeneProduct");
+        ds.field("id", &self.id());
+        ds.field("label", &self.label());
+        ds.field("name", &self.name());
+        ds.field("associated_species", &self.associated_species());
+        ds.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        packages::Package,
+        sbmldoc::{Profile, SBMLDocument},
+    };
+
+    #[test]
+    fn test_gene_product_new() {
+        let doc = SBMLDocument::with_profile(Profile::Cobra);
+        let model = Model::new(&doc, "test_model");
+
+        let gene_product =
+            GeneProduct::new(&model, "g1", "b0001").expect("Failed to create gene product");
+
+        assert_eq!(gene_product.id(), "g1");
+        assert_eq!(gene_product.label(), "b0001");
+        assert_eq!(gene_product.name(), None);
+        assert_eq!(gene_product.associated_species(), None);
+    }
+
+    #[test]
+    fn test_gene_product_properties() {
+        let doc = SBMLDocument::with_profile(Profile::Cobra);
+        let model = Model::new(&doc, "test_model");
+
+        let gene_product =
+            GeneProduct::new(&model, "g1", "b0001").expect("Failed to create gene product");
+        gene_product.set_name("thrL");
+        gene_product.set_associated_species("protein");
+        gene_product.set_label("b0002");
+
+        assert_eq!(gene_product.name(), Some("thrL".to_string()));
+        assert_eq!(
+            gene_product.associated_species(),
+            Some("protein".to_string())
+        );
+        assert_eq!(gene_product.label(), "b0002");
+    }
+
+    #[test]
+    fn test_gene_product_requires_fbc_v2() {
+        let doc = SBMLDocument::new(3, 1, vec![Package::Fbc(1).into()]);
+        let model = Model::new(&doc, "test_model");
+
+        let result = GeneProduct::new(&model, "g1", "b0001");
+        assert!(matches!(result, Err(LibSBMLError::InvalidArgument(_))));
+    }
+
+    #[test]
+    fn test_gene_product_debug() {
+        let doc = SBMLDocument::with_profile(Profile::Cobra);
+        let model = Model::new(&doc, "test_model");
+
+        let gene_product =
+            GeneProduct::new(&model, "g1", "b0001").expect("Failed to create gene product");
+
+        let debug_string = format!("{gene_product:?}");
+        assert!(debug_string.contains("GeneProduct"));
+        assert!(debug_string.contains("g1"));
+        assert!(debug_string.contains("b0001"));
+    }
+}
@@ -0,0 +1,254 @@
+//! This module provides a safe Rust interface to the libSBML GeneProductAssociation class.
+//!
+//! The GeneProductAssociation class represents the gene-protein-reaction (GPR) rule of a
+//! reaction in an SBML FBC (Flux Balance Constraints) model. The rule is a boolean
+//! combination of gene products, e.g. `g1 and (g2 or g3)`, and is attached to a reaction
+//! through the FBC reaction plugin. Gene product associations are only available in
+//! FBC version 2.
+//!
+//! This wrapper provides safe access to the underlying C++ libSBML GeneProductAssociation
+//! class while maintaining Rust's safety guarantees through the use of RefCell and Pin.
+
+use std::{cell::RefCell, pin::Pin};
+
+use cxx::let_cxx_string;
+
+use crate::{
+    clone,
+    errors::LibSBMLError,
+    inner, optional_property, pin_const_ptr, pin_ptr,
+    plugin::{get_plugin, package_version},
+    reaction::Reaction,
+    sbase, sbmlcxx,
+    traits::fromptr::FromPtr,
+    upcast_annotation, upcast_notes,
+};
+
+/// A safe wrapper around the libSBML GeneProductAssociation class.
+///
+/// GeneProductAssociation holds the GPR rule of a reaction. The rule is exposed as an
+/// infix string (e.g. `g1 and (g2 or g3)`) that references gene products by their id.
+///
+/// This struct maintains a reference to the underlying C++ GeneProductAssociation object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+pub struct GeneProductAssociation<'a> {
+    inner: RefCell<Pin<&'a mut sbmlcxx::GeneProductAssociation>>,
+}
+
+// Set the inner trait for the GeneProductAssociation struct
+inner!(sbmlcxx::GeneProductAssociation, GeneProductAssociation<'a>);
+
+// Set the sbase trait for the GeneProductAssociation struct
+sbase!(GeneProductAssociation<'a>, sbmlcxx::GeneProductAssociation);
+
+// Set the annotation trait for the GeneProductAssociation struct
+upcast_annotation!(
+    GeneProductAssociation<'a>,
+    sbmlcxx::GeneProductAssociation,
+    sbmlcxx::SBase
+);
+
+// Set the notes trait for the GeneProductAssociation struct
+upcast_notes!(
+    GeneProductAssociation<'a>,
+    sbmlcxx::GeneProductAssociation,
+    sbmlcxx::SBase
+);
+
+// Implement the Clone trait for the GeneProductAssociation struct
+clone!(GeneProductAssociation<'a>, sbmlcxx::GeneProductAssociation);
+
+impl<'a> GeneProductAssociation<'a> {
+    /// Creates a new GeneProductAssociation for the given Reaction.
+    ///
+    /// Any existing association of the reaction is replaced. The gene products referenced
+    /// in the association should already be defined in the model.
+    ///
+    /// # Arguments
+    /// * `reaction` - The parent Reaction that will contain this association
+    /// * `association` - The GPR rule as infix string (e.g. "g1 and (g2 or g3)")
+    ///
+    /// # Returns
+    /// A new GeneProductAssociation instance attached to the reaction
+    ///
+    /// # Errors
+    /// Returns `LibSBMLError` if:
+    /// - The FBC plugin is not available or enabled in the reaction
+    /// - The FBC package version does not support gene product associations (version 1)
+    /// - The association could not be parsed
+    pub fn new(reaction: &Reaction<'a>, association: &str) -> Result<Self, LibSBMLError> {
+        let mut fbc_plugin =
+            get_plugin::<sbmlcxx::FbcReactionPlugin, Reaction<'a>, sbmlcxx::Reaction>(
+                reaction, "fbc",
+            )?;
+
+        if package_version(&mut fbc_plugin) < 2 {
+            return Err(LibSBMLError::InvalidArgument(
+                "Gene product associations require version 2 of the FBC package".to_string(),
+            ));
+        }
+
+        let gpa_ptr = fbc_plugin.as_mut().createGeneProductAssociation();
+
+        let gpa = Self::from_ptr(gpa_ptr);
+        gpa.set_association(association)?;
+
+        Ok(gpa)
+    }
+
+    // Getter and setter for id
+    optional_property!(
+        GeneProductAssociation<'a>,
+        id,
+        String,
+        getId,
+        setId,
+        isSetId
+    );
+
+    // Getter and setter for name
+    optional_property!(
+        GeneProductAssociation<'a>,
+        name,
+        String,
+        getName,
+        setName,
+        isSetName
+    );
+
+    /// Returns the GPR rule of this association as infix string.
+    ///
+    /// Gene products are referenced by their id.
+    ///
+    /// # Returns
+    /// The association as infix string (e.g. "(g1 and g2)"), or None if not set
+    pub fn association(&self) -> Option<String> {
+        let inner = self.inner.borrow();
+
+        if !inner.isSetAssociation() {
+            return None;
+        }
+
+        let association_ptr = inner.getAssociation();
+        let association = pin_const_ptr!(association_ptr, sbmlcxx::FbcAssociation);
+
+        Some(association.toInfix(true).to_string())
+    }
+
+    /// Sets the GPR rule of this association from an infix string.
+    ///
+    /// # Arguments
+    /// * `association` - The GPR rule referencing gene products by id (e.g. "g1 and g2")
+    ///
+    /// # Returns
+    /// `Ok(())` if the association has been set, or an error if it could not be parsed
+    pub fn set_association(&self, association: &str) -> Result<(), LibSBMLError> {
+        let_cxx_string!(association_cxx = association);
+
+        let result =
+            self.inner
+                .borrow_mut()
+                .as_mut()
+                .setAssociation1(&association_cxx, true, false);
+
+        // LIBSBML_OPERATION_SUCCESS
+        if result.0 != 0 {
+            return Err(LibSBMLError::InvalidArgument(format!(
+                "Invalid gene product association: {association}"
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> FromPtr<sbmlcxx::GeneProductAssociation> for GeneProductAssociation<'a> {
+    fn from_ptr(ptr: *mut sbmlcxx::GeneProductAssociation) -> Self {
+        let gpa = pin_ptr!(ptr, sbmlcxx::GeneProductAssociation);
+
+        Self {
+            inner: RefCell::new(gpa),
+        }
+    }
+}
+
+impl<'a> std::fmt::Debug for GeneProductAssociation<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut ds = f.debug_struct("GeneProductAssociation");
+        ds.field("id", &self.id());
+        ds.field("name", &self.name());
+        ds.field("association", &self.association());
+        ds.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        model::Model,
+        packages::Package,
+        sbmldoc::{Profile, SBMLDocument},
+    };
+
+    #[test]
+    fn test_gene_product_association_new() {
+        let doc = SBMLDocument::with_profile(Profile::Cobra);
+        let model = Model::new(&doc, "test_model");
+        model.create_gene_product("g1", "b0001").unwrap();
+        model.create_gene_product("g2", "b0002").unwrap();
+        let reaction = model.create_reaction("r1");
+
+        let gpa = GeneProductAssociation::new(&reaction, "g1 and g2")
+            .expect("Failed to create gene product association");
+
+        let association = gpa.association().expect("Association not set");
+        assert!(association.contains("g1"));
+        assert!(association.contains("and"));
+        assert!(association.contains("g2"));
+    }
+
+    #[test]
+    fn test_gene_product_association_set_association() {
+        let doc = SBMLDocument::with_profile(Profile::Cobra);
+        let model = Model::new(&doc, "test_model");
+        model.create_gene_product("g1", "b0001").unwrap();
+        model.create_gene_product("g2", "b0002").unwrap();
+        let reaction = model.create_reaction("r1");
+
+        let gpa = GeneProductAssociation::new(&reaction, "g1")
+            .expect("Failed to create gene product association");
+        assert_eq!(gpa.association(), Some("g1".to_string()));
+
+        gpa.set_association("g1 or g2").unwrap();
+        let association = gpa.association().unwrap();
+        assert!(association.contains("or"));
+        assert!(association.contains("g2"));
+    }
+
+    #[test]
+    fn test_gene_product_association_id_and_name() {
+        let doc = SBMLDocument::with_profile(Profile::Cobra);
+        let model = Model::new(&doc, "test_model");
+        model.create_gene_product("g1", "b0001").unwrap();
+        let reaction = model.create_reaction("r1");
+
+        let gpa = GeneProductAssociation::new(&reaction, "g1")
+            .expect("Failed to create gene product association");
+        gpa.set_id("gpa1");
+        gpa.set_name("GPR of r1");
+
+        assert_eq!(gpa.id(), Some("gpa1".to_string()));
+        assert_eq!(gpa.name(), Some("GPR of r1".to_string()));
+    }
+
+    #[test]
+    fn test_gene_product_association_requires_fbc_v2() {
+        let doc = SBMLDocument::new(3, 1, vec![Package::Fbc(1).into()]);
+        let model = Model::new(&doc, "test_model");
+        let reaction = model.create_reaction("r1");
+
+        let result = GeneProductAssociation::new(&reaction, "g1");
+        assert!(matches!(result, Err(LibSBMLError::InvalidArgument(_))));
+    }
+}
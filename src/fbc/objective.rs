@@ -14,7 +14,7 @@ use cxx::let_cxx_string;
 
 use crate::{
     clone, errors::LibSBMLError, inner, model::Model, pin_ptr, plugin::get_plugin, prelude::IntoId,
-    required_property, sbmlcxx, traits::fromptr::FromPtr, upcast_annotation,
+    required_property, sbase, sbmlcxx, traits::fromptr::FromPtr, upcast_annotation, upcast_notes,
 };
 
 use super::{fluxobjective::FluxObjective, objectivetype::ObjectiveType};
@@ -37,9 +37,15 @@ pub struct Objective<'a> {
 // Set the inner trait for the Compartment struct
 inner!(sbmlcxx::Objective, Objective<'a>);
 
+// Set the sbase trait for the Objective struct
+sbase!(Objective<'a>, sbmlcxx::Objective);
+
 // Set the annotation trait for the Compartment struct
 upcast_annotation!(Objective<'a>, sbmlcxx::Objective, sbmlcxx::SBase);
 
+// Set the notes trait for the Objective struct
+upcast_notes!(Objective<'a>, sbmlcxx::Objective, sbmlcxx::SBase);
+
 // Implement the Clone trait for the Compartment struct
 clone!(Objective<'a>, sbmlcxx::Objective, list_of_flux_objective);
 
@@ -81,6 +87,11 @@ impl<'a> Objective<'a> {
         let obj_type = obj_type.into();
         objective.as_mut().setType(obj_type.into());
 
+        // The first objective of a model becomes the active one
+        if fbc_plugin.getNumObjectives().0 == 1 {
+            fbc_plugin.as_mut().setActiveObjectiveId(&id);
+        }
+
         Ok(Self {
             inner: RefCell::new(objective),
             list_of_flux_objective: RefCell::new(vec![]),
@@ -147,6 +158,79 @@ impl<'a> Objective<'a> {
             .find(|flux_objective| (*flux_objective).id() == Some(id.to_string()))
             .map(Rc::clone)
     }
+
+    /// Returns the objective function as linear terms.
+    ///
+    /// Flux objectives without a reaction or coefficient are skipped.
+    ///
+    /// # Returns
+    /// A vector of `(reaction id, coefficient)` pairs in the order of the flux objectives
+    pub fn as_linear_terms(&self) -> Vec<(String, f64)> {
+        self.list_of_flux_objective
+            .borrow()
+            .iter()
+            .filter_map(|flux_objective| {
+                Some((flux_objective.reaction()?, flux_objective.coefficient()?))
+            })
+            .collect()
+    }
+}
+
+/// A builder for creating Objective instances with a fluent interface.
+///
+/// The objective type defaults to [`ObjectiveType::Maximize`] and only needs to be
+/// set for minimization problems.
+pub struct ObjectiveBuilder<'a> {
+    objective: Rc<Objective<'a>>,
+}
+
+impl<'a> ObjectiveBuilder<'a> {
+    /// Creates a new ObjectiveBuilder.
+    ///
+    /// # Arguments
+    /// * `model` - The model that will contain the objective
+    /// * `id` - The identifier for the new objective
+    ///
+    /// # Errors
+    /// Returns `LibSBMLError` if the FBC plugin is not available or enabled in the model
+    pub fn new(model: &Model<'a>, id: &str) -> Result<Self, LibSBMLError> {
+        let objective = model.create_objective(id, ObjectiveType::default())?;
+        Ok(Self { objective })
+    }
+
+    /// Sets the type of the objective.
+    ///
+    /// # Arguments
+    /// * `obj_type` - The type of optimization (maximize or minimize)
+    pub fn obj_type(self, obj_type: impl Into<ObjectiveType>) -> Self {
+        self.objective.set_obj_type(obj_type);
+        self
+    }
+
+    /// Adds a flux objective to the objective.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier for the flux objective
+    /// * `reaction_id` - The identifier of the reaction that contributes to the objective
+    /// * `coefficient` - The coefficient of the reaction in the objective function
+    ///
+    /// # Errors
+    /// Returns `LibSBMLError` if the flux objective could not be created
+    pub fn flux_objective(
+        self,
+        id: &str,
+        reaction_id: impl IntoId,
+        coefficient: f64,
+    ) -> Result<Self, LibSBMLError> {
+        self.objective
+            .create_flux_objective(id, reaction_id, coefficient)?;
+        Ok(self)
+    }
+
+    /// Builds and returns the configured Objective instance.
+    pub fn build(self) -> Rc<Objective<'a>> {
+        self.objective
+    }
 }
 
 impl<'a> FromPtr<sbmlcxx::Objective> for Objective<'a> {
@@ -462,4 +546,57 @@ mod tests {
         assert_eq!(objective.obj_type(), ObjectiveType::Maximize);
         assert_eq!(objective.flux_objectives().len(), 1);
     }
+
+    #[test]
+    fn test_objective_builder_defaults_to_maximize() {
+        let doc = SBMLDocument::default();
+        let model = Model::new(&doc, "test_model");
+        model.create_reaction("biomass");
+
+        let objective = model
+            .build_objective("obj1")
+            .expect("Failed to create objective builder")
+            .flux_objective("fo1", "biomass", 1.0)
+            .expect("Failed to create flux objective")
+            .build();
+
+        assert_eq!(objective.obj_type(), ObjectiveType::Maximize);
+        assert_eq!(objective.flux_objectives().len(), 1);
+        assert_eq!(model.list_of_objectives().len(), 1);
+
+        let objective = model
+            .build_objective("obj2")
+            .expect("Failed to create objective builder")
+            .obj_type(ObjectiveType::Minimize)
+            .build();
+
+        assert_eq!(objective.obj_type(), ObjectiveType::Minimize);
+    }
+
+    #[test]
+    fn test_active_objective_linear_terms() {
+        let doc = SBMLDocument::with_profile(crate::sbmldoc::Profile::Cobra);
+        let model = Model::new(&doc, "test_model");
+        model.create_reaction("R_BIOMASS");
+        model.create_reaction("R_ATPM");
+
+        model
+            .build_objective("obj")
+            .unwrap()
+            .flux_objective("fo_biomass", "R_BIOMASS", 1.0)
+            .unwrap()
+            .flux_objective("fo_atpm", "R_ATPM", -0.5)
+            .unwrap()
+            .build();
+
+        let objective = model
+            .active_objective_resolved()
+            .expect("No active objective");
+
+        assert_eq!(objective.id(), "obj");
+        assert_eq!(
+            objective.as_linear_terms(),
+            vec![("R_BIOMASS".to_string(), 1.0), ("R_ATPM".to_string(), -0.5)]
+        );
+    }
 }
@@ -7,9 +7,12 @@ use crate::{errors::LibSBMLError, sbmlcxx};
 /// In flux balance analysis and related constraint-based modeling approaches,
 /// objectives can be either maximized (e.g., biomass production) or minimized
 /// (e.g., nutrient uptake).
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+///
+/// The default is [`ObjectiveType::Maximize`], the common case in flux balance analysis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum ObjectiveType {
     /// The objective function should be maximized during optimization
+    #[default]
     Maximize,
     /// The objective function should be minimized during optimization
     Minimize,
@@ -0,0 +1,331 @@
+//! This module provides a safe Rust interface to the libSBML FunctionDefinition class.
+//!
+//! The FunctionDefinition class represents a reusable mathematical function in an
+//! SBML model. Its math is a lambda expression (e.g. `lambda(x, y, x + y)`) whose
+//! arguments are bound when the function is called from other math expressions.
+//!
+//! This wrapper provides safe access to the underlying C++ libSBML FunctionDefinition class while
+//! maintaining Rust's safety guarantees through the use of RefCell and Pin.
+
+use std::{cell::RefCell, pin::Pin, rc::Rc};
+
+use cxx::{let_cxx_string, UniquePtr};
+
+use crate::{
+    clone,
+    errors::LibSBMLError,
+    inner, into_id,
+    math::{formula_from_ast, parse_formula},
+    model::Model,
+    optional_property, pin_ptr, required_property, sbase,
+    sbmlcxx::{self},
+    sbo_term,
+    traits::fromptr::FromPtr,
+    upcast_annotation, upcast_notes,
+};
+
+/// A safe wrapper around the libSBML FunctionDefinition class.
+///
+/// This struct maintains a reference to the underlying C++ FunctionDefinition object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+pub struct FunctionDefinition<'a> {
+    inner: RefCell<Pin<&'a mut sbmlcxx::FunctionDefinition>>,
+}
+
+// Set the inner trait for the FunctionDefinition struct
+inner!(sbmlcxx::FunctionDefinition, FunctionDefinition<'a>);
+
+// Set the sbase trait for the FunctionDefinition struct
+sbase!(FunctionDefinition<'a>, sbmlcxx::FunctionDefinition);
+
+// Set the annotation trait for the FunctionDefinition struct
+upcast_annotation!(
+    FunctionDefinition<'a>,
+    sbmlcxx::FunctionDefinition,
+    sbmlcxx::SBase
+);
+
+// Set the notes trait for the FunctionDefinition struct
+upcast_notes!(
+    FunctionDefinition<'a>,
+    sbmlcxx::FunctionDefinition,
+    sbmlcxx::SBase
+);
+
+// Set the into_id trait for the FunctionDefinition struct
+into_id!(&Rc<FunctionDefinition<'_>>, id);
+
+// Implement the Clone trait for the FunctionDefinition struct
+clone!(FunctionDefinition<'a>, sbmlcxx::FunctionDefinition);
+
+impl<'a> FunctionDefinition<'a> {
+    /// Creates a new FunctionDefinition instance within the given Model.
+    ///
+    /// The function definition has no math yet, use [`FunctionDefinition::set_formula`]
+    /// to assign its lambda expression.
+    ///
+    /// # Arguments
+    /// * `model` - The parent Model that will contain this function definition
+    /// * `id` - The identifier for this function definition
+    ///
+    /// # Returns
+    /// A new FunctionDefinition instance
+    pub fn new(model: &Model<'a>, id: &str) -> Self {
+        let function_definition_ptr = model
+            .inner()
+            .borrow_mut()
+            .as_mut()
+            .createFunctionDefinition();
+        let mut function_definition =
+            pin_ptr!(function_definition_ptr, sbmlcxx::FunctionDefinition);
+
+        // Set the id of the function definition
+        let_cxx_string!(id = id);
+        function_definition.as_mut().setId(&id);
+
+        Self {
+            inner: RefCell::new(function_definition),
+        }
+    }
+
+    // Getter and setter for id
+    required_property!(FunctionDefinition<'a>, id, String, getId, setId);
+
+    // Getter and setter for name
+    optional_property!(
+        FunctionDefinition<'a>,
+        name,
+        String,
+        getName,
+        setName,
+        isSetName
+    );
+
+    /// Returns the lambda expression of this function definition.
+    ///
+    /// # Returns
+    /// The formula as a String (e.g. "lambda(x, y, x + y)"), or None if no math is set
+    pub fn formula(&self) -> Option<String> {
+        formula_from_ast(self.inner.borrow().getMath())
+    }
+
+    /// Sets the lambda expression of this function definition.
+    ///
+    /// # Arguments
+    /// * `formula` - The lambda expression (e.g. "lambda(x, y, x + y)")
+    ///
+    /// # Returns
+    /// An error if the formula could not be parsed or is not a lambda expression
+    pub fn set_formula(&self, formula: &str) -> Result<(), LibSBMLError> {
+        let math = parse_lambda(formula)?;
+        unsafe { self.inner.borrow_mut().as_mut().setMath(&*math) };
+        Ok(())
+    }
+
+    /// Returns the names of the arguments of this function definition.
+    ///
+    /// # Returns
+    /// A vector of argument names in declaration order
+    pub fn arguments(&self) -> Vec<String> {
+        let inner = self.inner.borrow();
+        let n_arguments = inner.getNumArguments().0;
+        (0..n_arguments)
+            .filter_map(|i| formula_from_ast(inner.getArgument(i.into())))
+            .collect()
+    }
+
+    /// Returns the body of the lambda expression of this function definition.
+    ///
+    /// # Returns
+    /// The body formula as a String (e.g. "x + y"), or None if no math is set
+    pub fn body(&self) -> Option<String> {
+        formula_from_ast(self.inner.borrow().getBody())
+    }
+
+    // SBO Term Methods generated by the `sbo_term` macro
+    sbo_term!(sbmlcxx::FunctionDefinition, sbmlcxx::SBase);
+}
+
+/// Parses a formula and ensures that it is a lambda expression.
+pub(crate) fn parse_lambda(formula: &str) -> Result<UniquePtr<sbmlcxx::ASTNode>, LibSBMLError> {
+    let math = parse_formula(formula)?;
+    if !math.isLambda() {
+        return Err(LibSBMLError::InvalidArgument(format!(
+            "Function definitions require a lambda expression: {formula}"
+        )));
+    }
+    Ok(math)
+}
+
+impl std::fmt::Debug for FunctionDefinition<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut ds = f.debug_struct("FunctionDefinition");
+        ds.field("id", &self.id());
+        ds.field("name", &self.name());
+        ds.field("formula", &self.formula());
+        ds.finish()
+    }
+}
+
+impl FromPtr<sbmlcxx::FunctionDefinition> for FunctionDefinition<'_> {
+    /// Creates a new FunctionDefinition instance from a unique pointer to a libSBML FunctionDefinition.
+    ///
+    /// This method is primarily used internally by the Model class to create
+    /// FunctionDefinition instances from libSBML FunctionDefinition pointers.
+    ///
+    /// # Arguments
+    /// * `ptr` - A unique pointer to a libSBML FunctionDefinition
+    ///
+    /// # Returns
+    /// A new FunctionDefinition instance
+    fn from_ptr(ptr: *mut sbmlcxx::FunctionDefinition) -> Self {
+        let function_definition = pin_ptr!(ptr, sbmlcxx::FunctionDefinition);
+        Self {
+            inner: RefCell::new(function_definition),
+        }
+    }
+}
+
+/// A builder for creating FunctionDefinition instances with a fluent interface.
+pub struct FunctionDefinitionBuilder<'a> {
+    function_definition: Rc<FunctionDefinition<'a>>,
+}
+
+impl<'a> FunctionDefinitionBuilder<'a> {
+    /// Creates a new FunctionDefinitionBuilder instance.
+    ///
+    /// # Arguments
+    /// * `model` - The parent Model that will contain the function definition
+    /// * `id` - The identifier for the function definition
+    /// * `formula` - The lambda expression (e.g. "lambda(x, y, x + y)")
+    ///
+    /// # Returns
+    /// A new FunctionDefinitionBuilder instance, or an error if the formula is invalid
+    pub fn new(model: &Model<'a>, id: &str, formula: &str) -> Result<Self, LibSBMLError> {
+        let function_definition = model.create_function_definition(id, formula)?;
+        Ok(Self {
+            function_definition,
+        })
+    }
+
+    /// Sets the name of the function definition.
+    ///
+    /// # Arguments
+    /// * `name` - The name to set
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    pub fn name(self, name: &str) -> Self {
+        self.function_definition.set_name(name);
+        self
+    }
+
+    /// Sets the annotation for this function definition.
+    ///
+    /// # Arguments
+    /// * `annotation` - The XML annotation string to set
+    ///
+    /// # Returns
+    /// Result containing the builder instance or an error if the annotation is invalid
+    pub fn annotation(self, annotation: &str) -> Result<Self, SeError> {
+        self.function_definition
+            .set_annotation(annotation)
+            .map_err(|e| SeError::Custom(e.to_string()))?;
+        Ok(self)
+    }
+
+    /// Sets the annotation using a serializable type.
+    ///
+    /// # Arguments
+    /// * `annotation` - The annotation data to serialize and set
+    ///
+    /// # Returns
+    /// Result containing the builder instance or a serialization error
+    pub fn annotation_serde<T: Serialize>(self, annotation: &T) -> Result<Self, SeError> {
+        self.function_definition.set_annotation_serde(annotation)?;
+        Ok(self)
+    }
+
+    /// Builds and returns the configured FunctionDefinition.
+    ///
+    /// # Returns
+    /// The constructed FunctionDefinition instance wrapped in an Rc
+    pub fn build(self) -> Rc<FunctionDefinition<'a>> {
+        self.function_definition
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_function_definition_new() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        let function_definition = FunctionDefinition::new(&model, "f");
+
+        assert_eq!(function_definition.id(), "f");
+        assert_eq!(function_definition.formula(), None);
+
+        function_definition
+            .set_formula("lambda(x, y, x + y)")
+            .expect("Failed to set formula");
+
+        assert_eq!(
+            function_definition.formula(),
+            Some("lambda(x, y, x + y)".to_string())
+        );
+        assert_eq!(function_definition.arguments(), vec!["x", "y"]);
+        assert_eq!(function_definition.body(), Some("x + y".to_string()));
+    }
+
+    #[test]
+    fn test_function_definition_requires_lambda() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+
+        assert!(model.create_function_definition("f", "x + y").is_err());
+        assert!(model.list_of_function_definitions().is_empty());
+    }
+
+    #[test]
+    fn test_function_definition_builder() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        let function_definition = model
+            .build_function_definition("f", "lambda(x, y, x + y)")
+            .unwrap()
+            .name("Addition")
+            .build();
+
+        assert_eq!(function_definition.name(), Some("Addition".to_string()));
+        assert!(model.get_function_definition("f").is_some());
+    }
+
+    #[test]
+    fn test_function_definition_roundtrip() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        model
+            .create_function_definition("f", "lambda(x, y, x + y)")
+            .expect("Failed to create function definition");
+
+        let xml = doc.to_xml_string();
+        assert!(xml.contains(r#"<functionDefinition id="f">"#));
+        assert!(xml.contains("<lambda>"));
+
+        let doc = SBMLReader::from_xml_string(&xml);
+        let model = doc.model().expect("Model not found");
+        let function_definition = model
+            .get_function_definition("f")
+            .expect("Function definition not found");
+
+        assert_eq!(model.list_of_function_definitions().len(), 1);
+        assert_eq!(
+            function_definition.formula(),
+            Some("lambda(x, y, x + y)".to_string())
+        );
+    }
+}
@@ -0,0 +1,202 @@
+//! This module provides a safe Rust interface to the libSBML Group class.
+//!
+//! The Group class represents a group of model elements in the SBML Groups package.
+//! Groups are commonly used to annotate subsystems of metabolic models, e.g. all
+//! reactions belonging to glycolysis. A group does not change the mathematical meaning
+//! of a model, it only adds structure to it.
+//!
+//! This wrapper provides safe access to the underlying C++ libSBML Group class while
+//! maintaining Rust's safety guarantees through the use of RefCell and Pin.
+
+use std::{cell::RefCell, pin::Pin, rc::Rc};
+
+use cxx::let_cxx_string;
+
+use crate::{
+    clone, errors::LibSBMLError, inner, model::Model, optional_property, pin_ptr,
+    plugin::get_plugin, prelude::IntoId, required_property, sbase, sbmlcxx,
+    traits::fromptr::FromPtr, upcast_annotation, upcast_notes,
+};
+
+use super::{groupkind::GroupKind, member::Member};
+
+/// A safe wrapper around the libSBML Group class.
+///
+/// Group represents a collection of model elements in the SBML Groups package.
+/// It consists of:
+/// - An identifier (optional)
+/// - A name (optional)
+/// - A kind describing the relationship between the members
+/// - A collection of members referencing other model elements
+///
+/// This struct maintains a reference to the underlying C++ Group object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+/// It also maintains a collection of Member instances associated with this group.
+pub struct Group<'a> {
+    inner: RefCell<Pin<&'a mut sbmlcxx::Group>>,
+    list_of_members: RefCell<Vec<Rc<Member<'a>>>>,
+}
+
+// Set the inner trait for the Group struct
+inner!(sbmlcxx::Group, Group<'a>);
+
+// Set the sbase trait for the Group struct
+sbase!(Group<'a>, sbmlcxx::Group);
+
+// Set the annotation trait for the Group struct
+upcast_annotation!(Group<'a>, sbmlcxx::Group, sbmlcxx::SBase);
+
+// Set the notes trait for the Group struct
+upcast_notes!(Group<'a>, sbmlcxx::Group, sbmlcxx::SBase);
+
+// Implement the Clone trait for the Group struct
+clone!(Group<'a>, sbmlcxx::Group, list_of_members);
+
+impl<'a> Group<'a> {
+    /// Creates a new Group instance within the given Model.
+    ///
+    /// The group is created with the kind [`GroupKind::Collection`], which can be
+    /// changed using [`Group::set_kind`].
+    ///
+    /// # Arguments
+    /// * `model` - The parent Model that will contain this group
+    /// * `id` - The identifier for this group (must be unique within the model)
+    ///
+    /// # Returns
+    /// A new Group instance added to the model
+    ///
+    /// # Errors
+    /// Returns `LibSBMLError` if the Groups plugin is not available or enabled in the model
+    pub fn new(model: &Model<'a>, id: &str) -> Result<Self, LibSBMLError> {
+        let mut groups_plugin =
+            get_plugin::<sbmlcxx::GroupsModelPlugin, Model<'a>, sbmlcxx::Model>(model, "groups")?;
+
+        let group_ptr = groups_plugin.as_mut().createGroup();
+        let mut group = pin_ptr!(group_ptr, sbmlcxx::Group);
+
+        // Set the id of the group
+        let_cxx_string!(id = id);
+        group.as_mut().setId(&id);
+
+        // The kind attribute is required
+        group.as_mut().setKind(GroupKind::Collection.into());
+
+        Ok(Self {
+            inner: RefCell::new(group),
+            list_of_members: RefCell::new(vec![]),
+        })
+    }
+
+    // Getter and setter for id
+    optional_property!(Group<'a>, id, String, getId, setId, isSetId);
+
+    // Getter and setter for name
+    optional_property!(Group<'a>, name, String, getName, setName, isSetName);
+
+    // Getter and setter for kind
+    required_property!(Group<'a>, kind, GroupKind, getKind, setKind);
+
+    /// Adds a member referencing the given element to this group.
+    ///
+    /// # Arguments
+    /// * `id_ref` - The identifier of the element to add (e.g. a reaction id)
+    ///
+    /// # Returns
+    /// A new Member instance wrapped in an Rc
+    pub fn add_member(&self, id_ref: impl IntoId) -> Rc<Member<'a>> {
+        let member = Rc::new(Member::new(self, id_ref));
+
+        self.list_of_members.borrow_mut().push(Rc::clone(&member));
+        member
+    }
+
+    /// Returns a list of all members of this group.
+    ///
+    /// # Returns
+    /// A vector containing Rc references to all Members in this group
+    pub fn members(&self) -> Vec<Rc<Member<'a>>> {
+        self.list_of_members.borrow().to_vec()
+    }
+}
+
+impl<'a> FromPtr<sbmlcxx::Group> for Group<'a> {
+    fn from_ptr(ptr: *mut sbmlcxx::Group) -> Self {
+        let mut group = pin_ptr!(ptr, sbmlcxx::Group);
+        let n_members = group.as_mut().getNumMembers().0;
+        let list_of_members: Vec<_> = (0..n_members)
+            .map(|i| {
+                let member = group.as_mut().getMember(i.into());
+                Rc::new(Member::from_ptr(member))
+            })
+            .collect();
+
+        Self {
+            inner: RefCell::new(group),
+            list_of_members: RefCell::new(list_of_members),
+        }
+    }
+}
+
+impl<'a> std::fmt::Debug for Group<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut ds = f.debug_struct("Group");
+        ds.field("id", &self.id());
+        ds.field("name", &self.name());
+        ds.field("kind", &self.kind());
+        ds.field("members", &self.members());
+        ds.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{packages::Package, sbmldoc::SBMLDocument};
+
+    #[test]
+    fn test_group_new() {
+        let doc = SBMLDocument::new(3, 2, vec![Package::Groups(1).into()]);
+        let model = Model::new(&doc, "test_model");
+
+        let group = Group::new(&model, "glycolysis").expect("Failed to create group");
+
+        assert_eq!(group.id(), Some("glycolysis".to_string()));
+        assert_eq!(group.kind(), GroupKind::Collection);
+        assert!(group.members().is_empty());
+    }
+
+    #[test]
+    fn test_group_kind() {
+        let doc = SBMLDocument::new(3, 2, vec![Package::Groups(1).into()]);
+        let model = Model::new(&doc, "test_model");
+
+        let group = Group::new(&model, "kinases").expect("Failed to create group");
+        group.set_kind(GroupKind::Classification);
+
+        assert_eq!(group.kind(), GroupKind::Classification);
+    }
+
+    #[test]
+    fn test_group_without_plugin() {
+        let doc = SBMLDocument::new(3, 2, None);
+        let model = Model::new(&doc, "test_model");
+
+        let result = Group::new(&model, "glycolysis");
+        assert!(matches!(result, Err(LibSBMLError::PluginNotFound(_))));
+    }
+
+    #[test]
+    fn test_group_members() {
+        let doc = SBMLDocument::new(3, 2, vec![Package::Groups(1).into()]);
+        let model = Model::new(&doc, "test_model");
+
+        let group = Group::new(&model, "glycolysis").expect("Failed to create group");
+        group.add_member("r1");
+        group.add_member("r2");
+
+        let members = group.members();
+        assert_eq!(members.len(), 2);
+        assert_eq!(members[0].id_ref(), Some("r1".to_string()));
+        assert_eq!(members[1].id_ref(), Some("r2".to_string()));
+    }
+}
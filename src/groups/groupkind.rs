@@ -0,0 +1,103 @@
+use std::str::FromStr;
+
+use crate::{errors::LibSBMLError, sbmlcxx};
+
+/// Represents the kind of relationship between the members of a group.
+///
+/// The Groups package distinguishes between groups whose members are instances of a
+/// common class (e.g. all kinases), parts of a larger whole (e.g. the subunits of a
+/// complex) and arbitrary collections (e.g. the reactions of a pathway).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GroupKind {
+    /// Members are instances of a common class ("is-a" relationship)
+    Classification,
+    /// Members are parts of a larger whole ("part-of" relationship)
+    Partonomy,
+    /// Members form an arbitrary collection
+    Collection,
+    /// Unknown group kind or not specified
+    Unknown,
+}
+
+impl From<GroupKind> for sbmlcxx::GroupKind_t {
+    /// Converts a Rust GroupKind enum to the corresponding C++ SBML enum value
+    fn from(value: GroupKind) -> Self {
+        match value {
+            GroupKind::Classification => sbmlcxx::GroupKind_t::GROUP_KIND_CLASSIFICATION,
+            GroupKind::Partonomy => sbmlcxx::GroupKind_t::GROUP_KIND_PARTONOMY,
+            GroupKind::Collection => sbmlcxx::GroupKind_t::GROUP_KIND_COLLECTION,
+            GroupKind::Unknown => sbmlcxx::GroupKind_t::GROUP_KIND_UNKNOWN,
+        }
+    }
+}
+
+impl From<sbmlcxx::GroupKind_t> for GroupKind {
+    /// Converts a C++ SBML GroupKind_t enum to the Rust equivalent
+    fn from(value: sbmlcxx::GroupKind_t) -> Self {
+        match value {
+            sbmlcxx::GroupKind_t::GROUP_KIND_CLASSIFICATION => GroupKind::Classification,
+            sbmlcxx::GroupKind_t::GROUP_KIND_PARTONOMY => GroupKind::Partonomy,
+            sbmlcxx::GroupKind_t::GROUP_KIND_COLLECTION => GroupKind::Collection,
+            sbmlcxx::GroupKind_t::GROUP_KIND_UNKNOWN => GroupKind::Unknown,
+        }
+    }
+}
+
+impl FromStr for GroupKind {
+    type Err = LibSBMLError;
+
+    /// Parses a string into a GroupKind.
+    ///
+    /// Accepts "classification", "partonomy" or "collection".
+    ///
+    /// # Errors
+    ///
+    /// Returns a LibSBMLError if the string cannot be parsed into a valid GroupKind.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "classification" => Ok(GroupKind::Classification),
+            "partonomy" => Ok(GroupKind::Partonomy),
+            "collection" => Ok(GroupKind::Collection),
+            _ => Err(LibSBMLError::InvalidArgument(format!(
+                "Invalid group kind: {s}. Only 'classification', 'partonomy' and 'collection' are supported."
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_group_kind_from_str() {
+        assert_eq!(
+            "classification".parse::<GroupKind>().unwrap(),
+            GroupKind::Classification
+        );
+        assert_eq!(
+            "partonomy".parse::<GroupKind>().unwrap(),
+            GroupKind::Partonomy
+        );
+        assert_eq!(
+            "collection".parse::<GroupKind>().unwrap(),
+            GroupKind::Collection
+        );
+        assert!("pathway".parse::<GroupKind>().is_err());
+    }
+
+    #[test]
+    fn test_group_kind_cxx_roundtrip() {
+        let kinds = [
+            GroupKind::Classification,
+            GroupKind::Partonomy,
+            GroupKind::Collection,
+            GroupKind::Unknown,
+        ];
+
+        for kind in kinds {
+            let cxx_kind: sbmlcxx::GroupKind_t = kind.into();
+            assert_eq!(GroupKind::from(cxx_kind), kind);
+        }
+    }
+}
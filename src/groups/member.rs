@@ -0,0 +1,155 @@
+//! This module provides a safe Rust interface to the libSBML Member class.
+//!
+//! The Member class represents a single member of a group in the SBML Groups package.
+//! A member references another model element by its SId (`idRef`) or meta identifier
+//! (`metaIdRef`), e.g. a reaction that is part of a metabolic subsystem.
+//!
+//! This wrapper provides safe access to the underlying C++ libSBML Member class while
+//! maintaining Rust's safety guarantees through the use of RefCell and Pin.
+
+use std::{cell::RefCell, pin::Pin};
+
+use cxx::let_cxx_string;
+
+use crate::{
+    clone, inner, optional_property, pin_ptr, sbase, sbmlcxx,
+    traits::{fromptr::FromPtr, intoid::IntoId},
+    upcast_annotation, upcast_notes,
+};
+
+use super::group::Group;
+
+/// A safe wrapper around the libSBML Member class.
+///
+/// Member represents an element that belongs to a group. It consists of:
+/// - An identifier (optional)
+/// - A name (optional)
+/// - A reference to the SId of the member element (optional)
+/// - A reference to the meta identifier of the member element (optional)
+///
+/// This struct maintains a reference to the underlying C++ Member object
+/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
+pub struct Member<'a> {
+    inner: RefCell<Pin<&'a mut sbmlcxx::Member>>,
+}
+
+// Set the inner trait for the Member struct
+inner!(sbmlcxx::Member, Member<'a>);
+
+// Set the sbase trait for the Member struct
+sbase!(Member<'a>, sbmlcxx::Member);
+
+// Set the annotation trait for the Member struct
+upcast_annotation!(Member<'a>, sbmlcxx::Member, sbmlcxx::SBase);
+
+// Set the notes trait for the Member struct
+upcast_notes!(Member<'a>, sbmlcxx::Member, sbmlcxx::SBase);
+
+// Implement the Clone trait for the Member struct
+clone!(Member<'a>, sbmlcxx::Member);
+
+impl<'a> Member<'a> {
+    /// Creates a new Member instance within the given Group.
+    ///
+    /// # Arguments
+    /// * `group` - The parent Group that will contain this member
+    /// * `id_ref` - The identifier of the element this member refers to
+    ///
+    /// # Returns
+    /// A new Member instance referencing the given element and added to the group
+    pub fn new(group: &Group<'a>, id_ref: impl IntoId) -> Self {
+        let member_ptr = group.inner().borrow_mut().as_mut().createMember();
+        let mut member = pin_ptr!(member_ptr, sbmlcxx::Member);
+
+        let_cxx_string!(id_ref = id_ref.into_id());
+        member.as_mut().setIdRef(&id_ref);
+
+        Self {
+            inner: RefCell::new(member),
+        }
+    }
+
+    // Getter and setter for id
+    optional_property!(Member<'a>, id, String, getId, setId, isSetId);
+
+    // Getter and setter for name
+    optional_property!(Member<'a>, name, String, getName, setName, isSetName);
+
+    // Getter and setter for id_ref
+    optional_property!(
+        Member<'a>,
+        id_ref,
+        String,
+        getIdRef,
+        setIdRef,
+        isSetIdRef,
+        impl IntoId
+    );
+
+    // Getter and setter for meta_id_ref
+    optional_property!(
+        Member<'a>,
+        meta_id_ref,
+        String,
+        getMetaIdRef,
+        setMetaIdRef,
+        isSetMetaIdRef
+    );
+}
+
+impl<'a> FromPtr<sbmlcxx::Member> for Member<'a> {
+    fn from_ptr(ptr: *mut sbmlcxx::Member) -> Self {
+        let member = pin_ptr!(ptr, sbmlcxx::Member);
+
+        Self {
+            inner: RefCell::new(member),
+        }
+    }
+}
+
+impl<'a> std::fmt::Debug for Member<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut ds = f.debug_struct("Member");
+        ds.field("id", &self.id());
+        ds.field("name", &self.name());
+        ds.field("id_ref", &self.id_ref());
+        ds.field("meta_id_ref", &self.meta_id_ref());
+        ds.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{model::Model, packages::Package, sbmldoc::SBMLDocument};
+
+    #[test]
+    fn test_member_new() {
+        let doc = SBMLDocument::new(3, 2, vec![Package::Groups(1).into()]);
+        let model = Model::new(&doc, "test_model");
+        let group = Group::new(&model, "g1").expect("Failed to create group");
+
+        let member = Member::new(&group, "r1");
+        assert_eq!(member.id_ref(), Some("r1".to_string()));
+        assert_eq!(member.meta_id_ref(), None);
+        assert_eq!(member.id(), None);
+    }
+
+    #[test]
+    fn test_member_properties() {
+        let doc = SBMLDocument::new(3, 2, vec![Package::Groups(1).into()]);
+        let model = Model::new(&doc, "test_model");
+        let group = Group::new(&model, "g1").expect("Failed to create group");
+
+        let member = Member::new(&group, "r1");
+        member.set_id("m1");
+        member.set_name("First member");
+        member.set_id_ref("r2");
+        member.set_meta_id_ref("meta_r2");
+
+        assert_eq!(member.id(), Some("m1".to_string()));
+        assert_eq!(member.name(), Some("First member".to_string()));
+        assert_eq!(member.id_ref(), Some("r2".to_string()));
+        assert_eq!(member.meta_id_ref(), Some("meta_r2".to_string()));
+    }
+}
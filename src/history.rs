@@ -0,0 +1,248 @@
+//! Model history for recording the provenance of SBML models.
+//!
+//! SBML stores the creators of a model together with its creation and modification
+//! dates as RDF within the annotation of the model (`dc:creator`, `dcterms:created`
+//! and `dcterms:modified`). This module provides owned Rust types describing this
+//! history, which are written to and read from a model through
+//! [`Model::set_history`](crate::model::Model::set_history) and
+//! [`Model::history`](crate::model::Model::history).
+
+use std::pin::Pin;
+
+use autocxx::WithinUniquePtr;
+use cxx::{let_cxx_string, UniquePtr};
+
+use crate::{cvterm::ensure_meta_id, errors::LibSBMLError, pin_ptr, sbmlcxx};
+
+/// The history of a model, consisting of its creators and relevant dates.
+///
+/// Dates are ISO-8601 strings in the form `YYYY-MM-DDThh:mm:ssZ` or
+/// `YYYY-MM-DDThh:mm:ss+hh:mm`. A plain date (`YYYY-MM-DD`) is interpreted as
+/// midnight UTC.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ModelHistory {
+    /// The people who created the model
+    pub creators: Vec<ModelCreator>,
+    /// The date the model was created
+    pub created: Option<String>,
+    /// The dates the model was modified
+    pub modified: Vec<String>,
+}
+
+/// A creator of a model.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ModelCreator {
+    /// The given name of the creator
+    pub given_name: Option<String>,
+    /// The family name of the creator
+    pub family_name: Option<String>,
+    /// The email address of the creator
+    pub email: Option<String>,
+    /// The organization the creator belongs to
+    pub organization: Option<String>,
+}
+
+impl ModelHistory {
+    /// Converts this history into a libSBML ModelHistory.
+    ///
+    /// # Returns
+    /// The libSBML ModelHistory, or an error if a date is not a valid ISO-8601 string
+    pub(crate) fn to_cxx(&self) -> Result<UniquePtr<sbmlcxx::ModelHistory>, LibSBMLError> {
+        let mut history = sbmlcxx::ModelHistory::new().within_unique_ptr();
+
+        // libSBML copies creators and dates, so the local ones are dropped afterwards
+        for creator in &self.creators {
+            let mut creator = creator.to_cxx();
+            unsafe { history.pin_mut().addCreator(creator.as_mut_ptr()) };
+        }
+
+        if let Some(created) = &self.created {
+            let mut date = date_to_cxx(created)?;
+            unsafe { history.pin_mut().setCreatedDate(date.as_mut_ptr()) };
+        }
+
+        for modified in &self.modified {
+            let mut date = date_to_cxx(modified)?;
+            unsafe { history.pin_mut().addModifiedDate(date.as_mut_ptr()) };
+        }
+
+        Ok(history)
+    }
+
+    /// Creates a history from a libSBML ModelHistory.
+    ///
+    /// # Arguments
+    /// * `ptr` - Pointer to the libSBML ModelHistory
+    pub(crate) fn from_ptr(ptr: *mut sbmlcxx::ModelHistory) -> Self {
+        let mut history = pin_ptr!(ptr, sbmlcxx::ModelHistory);
+
+        let n_creators = history.as_mut().getNumCreators().0;
+        let creators = (0..n_creators)
+            .map(|i| ModelCreator::from_ptr(history.as_mut().getCreator(i.into())))
+            .collect();
+
+        let created = if history.as_mut().isSetCreatedDate() {
+            Some(date_from_ptr(history.as_mut().getCreatedDate()))
+        } else {
+            None
+        };
+
+        let n_modified = history.as_mut().getNumModifiedDates().0;
+        let modified = (0..n_modified)
+            .map(|i| date_from_ptr(history.as_mut().getModifiedDate(i.into())))
+            .collect();
+
+        Self {
+            creators,
+            created,
+            modified,
+        }
+    }
+}
+
+impl ModelCreator {
+    /// Converts this creator into a libSBML ModelCreator.
+    fn to_cxx(&self) -> UniquePtr<sbmlcxx::ModelCreator> {
+        let mut creator = sbmlcxx::ModelCreator::new().within_unique_ptr();
+
+        if let Some(given_name) = &self.given_name {
+            let_cxx_string!(given_name = given_name);
+            creator.pin_mut().setGivenName(&given_name);
+        }
+
+        if let Some(family_name) = &self.family_name {
+            let_cxx_string!(family_name = family_name);
+            creator.pin_mut().setFamilyName(&family_name);
+        }
+
+        if let Some(email) = &self.email {
+            let_cxx_string!(email = email);
+            creator.pin_mut().setEmail(&email);
+        }
+
+        if let Some(organization) = &self.organization {
+            let_cxx_string!(organization = organization);
+            creator.pin_mut().setOrganization(&organization);
+        }
+
+        creator
+    }
+
+    /// Creates a creator from a libSBML ModelCreator.
+    fn from_ptr(ptr: *mut sbmlcxx::ModelCreator) -> Self {
+        let creator = pin_ptr!(ptr, sbmlcxx::ModelCreator);
+
+        let non_empty = |value: &cxx::CxxString| {
+            let value = value.to_str().unwrap();
+            (!value.is_empty()).then(|| value.to_string())
+        };
+
+        Self {
+            given_name: non_empty(creator.getGivenName()),
+            family_name: non_empty(creator.getFamilyName()),
+            email: non_empty(creator.getEmail()),
+            organization: non_empty(creator.getOrganization()),
+        }
+    }
+}
+
+/// Sets the history of the given SBML element.
+///
+/// Model histories refer to the element by its meta identifier, so `meta_<id>` is
+/// assigned if the element has none.
+///
+/// # Arguments
+/// * `base` - The element to set the history for
+/// * `history` - The history to set
+pub(crate) fn set_history(
+    mut base: Pin<&mut sbmlcxx::SBase>,
+    history: &ModelHistory,
+) -> Result<(), LibSBMLError> {
+    ensure_meta_id(base.as_mut())?;
+
+    let mut history = history.to_cxx()?;
+    let result = unsafe { base.as_mut().setModelHistory(history.as_mut_ptr()) };
+
+    // LIBSBML_OPERATION_SUCCESS
+    if result.0 != 0 {
+        return Err(LibSBMLError::InvalidArgument(format!(
+            "Failed to set model history (libSBML code {})",
+            result.0
+        )));
+    }
+
+    Ok(())
+}
+
+/// Gets the history of the given SBML element.
+///
+/// # Arguments
+/// * `base` - The element to get the history from
+///
+/// # Returns
+/// The history of the element, or None if it has none
+pub(crate) fn history(mut base: Pin<&mut sbmlcxx::SBase>) -> Option<ModelHistory> {
+    if !base.as_mut().isSetModelHistory() {
+        return None;
+    }
+
+    Some(ModelHistory::from_ptr(base.as_mut().getModelHistory()))
+}
+
+/// Converts an ISO-8601 string into a libSBML Date.
+///
+/// # Arguments
+/// * `date` - The date (e.g. "2024-01-15T10:30:00Z" or "2024-01-15")
+///
+/// # Returns
+/// The libSBML Date, or an error if the string is not a valid date
+fn date_to_cxx(date: &str) -> Result<UniquePtr<sbmlcxx::Date>, LibSBMLError> {
+    // libSBML only understands full timestamps
+    let timestamp = if date.len() == 10 {
+        format!("{date}T00:00:00Z")
+    } else {
+        date.to_string()
+    };
+
+    let_cxx_string!(timestamp_cxx = &timestamp);
+    let cxx_date = sbmlcxx::Date::new1(&timestamp_cxx).within_unique_ptr();
+
+    // Unparseable strings fall back to a default date instead of failing
+    if !cxx_date.representsValidDate()
+        || cxx_date.getDateAsString().to_str() != Ok(timestamp.as_str())
+    {
+        return Err(LibSBMLError::InvalidArgument(format!(
+            "Invalid ISO-8601 date: {date}"
+        )));
+    }
+
+    Ok(cxx_date)
+}
+
+/// Converts a libSBML Date into an ISO-8601 string.
+fn date_from_ptr(ptr: *mut sbmlcxx::Date) -> String {
+    let date = pin_ptr!(ptr, sbmlcxx::Date);
+    date.getDateAsString().to_str().unwrap().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_date_to_cxx() {
+        let date = date_to_cxx("2024-01-15T10:30:00Z").expect("Failed to parse date");
+        assert_eq!(
+            date.getDateAsString().to_str().unwrap(),
+            "2024-01-15T10:30:00Z"
+        );
+
+        let date = date_to_cxx("2024-01-15").expect("Failed to parse date");
+        assert_eq!(
+            date.getDateAsString().to_str().unwrap(),
+            "2024-01-15T00:00:00Z"
+        );
+
+        assert!(date_to_cxx("15.01.2024").is_err());
+    }
+}
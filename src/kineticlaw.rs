@@ -15,14 +15,18 @@
 
 use std::{cell::RefCell, pin::Pin, rc::Rc};
 
-use cxx::let_cxx_string;
+use cxx::{let_cxx_string, UniquePtr};
 
 use crate::{
-    clone, inner, pin_ptr,
+    clone, derived_units,
+    errors::LibSBMLError,
+    inner,
+    math::{formula_from_ast, MathNode},
+    pin_ptr,
     prelude::{LocalParameter, LocalParameterBuilder, Reaction},
     required_property, sbase, sbmlcxx, sbo_term,
     traits::fromptr::FromPtr,
-    upcast_annotation,
+    upcast_annotation, upcast_notes, upcast_pin,
 };
 
 /// A safe wrapper around the libSBML KineticLaw class.
@@ -47,6 +51,9 @@ sbase!(KineticLaw<'a>, sbmlcxx::KineticLaw);
 // Set the annotation trait for the KineticLaw struct
 upcast_annotation!(KineticLaw<'a>, sbmlcxx::KineticLaw, sbmlcxx::SBase);
 
+// Set the notes trait for the KineticLaw struct
+upcast_notes!(KineticLaw<'a>, sbmlcxx::KineticLaw, sbmlcxx::SBase);
+
 // Implement the Clone trait for the KineticLaw struct
 clone!(KineticLaw<'a>, sbmlcxx::KineticLaw, local_parameters);
 
@@ -77,6 +84,33 @@ impl<'a> KineticLaw<'a> {
         }
     }
 
+    /// Creates a new KineticLaw instance from an expression tree.
+    ///
+    /// The tree is converted into libSBML math directly, without going through a
+    /// formula string.
+    ///
+    /// # Arguments
+    /// * `reaction` - The parent Reaction that will contain this kinetic law
+    /// * `math` - The rate expression (e.g. built with [`MathNode::times`])
+    ///
+    /// # Returns
+    /// A new KineticLaw instance added to the reaction, or an error if the tree cannot
+    /// be converted. The reaction is left untouched in case of an error.
+    pub fn from_math(reaction: &Reaction<'a>, math: &MathNode) -> Result<Self, LibSBMLError> {
+        let ast = math.to_ast()?;
+
+        let kinetic_law_ptr = reaction.inner().borrow_mut().as_mut().createKineticLaw();
+        let mut kinetic_law = pin_ptr!(kinetic_law_ptr, sbmlcxx::KineticLaw);
+
+        // libSBML stores a copy of the AST node
+        unsafe { kinetic_law.as_mut().setMath(&*ast) };
+
+        Ok(Self {
+            inner: RefCell::new(kinetic_law),
+            local_parameters: RefCell::new(vec![]),
+        })
+    }
+
     // Getter and setter for formula
     required_property!(KineticLaw<'a>, formula, String, getFormula, setFormula);
 
@@ -104,12 +138,25 @@ impl<'a> KineticLaw<'a> {
     /// # Returns
     /// A new LocalParameter instance representing the added local parameter
     pub fn add_local_parameter(&self, id: &str, value: Option<f64>) -> Rc<LocalParameter<'a>> {
-        let local_parameter = Rc::new(LocalParameter::new(self, id));
+        let local_parameter = self.create_local_parameter(id);
 
         if let Some(value) = value {
             local_parameter.set_value(value);
         }
 
+        local_parameter
+    }
+
+    /// Creates a new local parameter within this kinetic law.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier of the local parameter
+    ///
+    /// # Returns
+    /// A new LocalParameter instance scoped to this kinetic law
+    pub fn create_local_parameter(&self, id: &str) -> Rc<LocalParameter<'a>> {
+        let local_parameter = Rc::new(LocalParameter::new(self, id));
+
         self.local_parameters
             .borrow_mut()
             .push(Rc::clone(&local_parameter));
@@ -117,6 +164,29 @@ impl<'a> KineticLaw<'a> {
         local_parameter
     }
 
+    /// Returns a vector of all local parameters of the kinetic law.
+    ///
+    /// # Returns
+    /// A vector containing Rc references to all local parameters
+    pub fn list_of_local_parameters(&self) -> Vec<Rc<LocalParameter<'a>>> {
+        self.local_parameters.borrow().to_vec()
+    }
+
+    /// Retrieves a local parameter by its identifier.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier of the local parameter to retrieve
+    ///
+    /// # Returns
+    /// Some(`Rc<LocalParameter>`) if found, None if not found
+    pub fn get_local_parameter(&self, id: &str) -> Option<Rc<LocalParameter<'a>>> {
+        self.local_parameters
+            .borrow()
+            .iter()
+            .find(|local_parameter| (*local_parameter).id() == id)
+            .map(Rc::clone)
+    }
+
     /// Creates a LocalParameterBuilder for constructing a LocalParameter with a fluent API.
     ///
     /// This method provides a builder pattern interface for creating and configuring
@@ -132,6 +202,72 @@ impl<'a> KineticLaw<'a> {
         LocalParameterBuilder::new(self, id)
     }
 
+    /// Returns the rate expression as a tree of [`MathNode`]s.
+    ///
+    /// # Returns
+    /// The expression tree, or None if no math is set
+    pub fn math(&self) -> Option<MathNode> {
+        MathNode::from_ast(self.inner.borrow().getMath())
+    }
+
+    /// Returns the names of all symbols referenced in the rate expression.
+    ///
+    /// This includes species, global and local parameters as well as compartments.
+    /// Each name is listed once, in the order of its first occurrence.
+    ///
+    /// # Returns
+    /// A vector of the referenced symbol names
+    pub fn referenced_symbols(&self) -> Vec<String> {
+        self.math()
+            .map(|math| math.referenced_symbols())
+            .unwrap_or_default()
+    }
+
+    /// Returns the rate expression with all local parameters replaced by their values.
+    ///
+    /// Local parameters shadow global symbols of the same name and are only visible
+    /// within this kinetic law. Substituting their values yields a formula that can be
+    /// evaluated in the global scope of the model.
+    ///
+    /// # Returns
+    /// The substituted formula in L3 infix syntax (e.g. "2 * S" for "k * S" with k = 2)
+    ///
+    /// # Errors
+    /// Returns [`LibSBMLError::MissingValue`] if a local parameter referenced by the
+    /// rate expression has no value, as it would otherwise resolve to a global symbol
+    /// of the same name
+    pub fn formula_with_locals_substituted(&self) -> Result<String, LibSBMLError> {
+        let math = self.inner.borrow().getMath();
+        if math.is_null() {
+            return Ok(self.formula());
+        }
+
+        // Substitute within a copy, the math of the kinetic law stays untouched
+        let mut ast = unsafe { UniquePtr::from_raw((*math).deepCopy()) };
+        let referenced_symbols = self.referenced_symbols();
+
+        for local_parameter in self.local_parameters.borrow().iter() {
+            let id = local_parameter.id();
+            if !referenced_symbols.contains(&id) {
+                continue;
+            }
+
+            let value = local_parameter.value().ok_or_else(|| {
+                LibSBMLError::MissingValue(format!("Local parameter {id} has no value"))
+            })?;
+            let mut value_ast = MathNode::number(value).to_ast()?;
+
+            // libSBML inserts deep copies of the argument, the local one is dropped afterwards
+            let_cxx_string!(id = id);
+            unsafe { ast.pin_mut().replaceArgument(&id, value_ast.as_mut_ptr()) };
+        }
+
+        Ok(formula_from_ast(ast.as_ptr()).unwrap_or_default())
+    }
+
+    // Derived units of the rate generated by the `derived_units` macro
+    derived_units!();
+
     // SBO Term Methods generated by the `sbo_term` macro
     sbo_term!(sbmlcxx::KineticLaw, sbmlcxx::SBase);
 }
@@ -149,13 +285,23 @@ impl FromPtr<sbmlcxx::KineticLaw> for KineticLaw<'_> {
     /// A new KineticLaw instance wrapping the provided pointer
     fn from_ptr(ptr: *mut sbmlcxx::KineticLaw) -> Self {
         let mut kinetic_law = pin_ptr!(ptr, sbmlcxx::KineticLaw);
-        let n_local_parameters = kinetic_law.as_mut().getNumLocalParameters().0;
-        let local_parameters: Vec<_> = (0..n_local_parameters)
-            .map(|i| {
-                let local_parameter = kinetic_law.as_mut().getLocalParameter1(i.into());
-                Rc::new(LocalParameter::from_ptr(local_parameter))
-            })
-            .collect();
+        let local_parameters: Vec<_> = if uses_parameter_list(&mut kinetic_law) {
+            let n_parameters = kinetic_law.as_mut().getNumParameters().0;
+            (0..n_parameters)
+                .map(|i| {
+                    let parameter = kinetic_law.as_mut().getParameter1(i.into());
+                    Rc::new(LocalParameter::from_ptr(parameter))
+                })
+                .collect()
+        } else {
+            let n_local_parameters = kinetic_law.as_mut().getNumLocalParameters().0;
+            (0..n_local_parameters)
+                .map(|i| {
+                    let local_parameter = kinetic_law.as_mut().getLocalParameter1(i.into());
+                    Rc::new(LocalParameter::from_ptr(local_parameter))
+                })
+                .collect()
+        };
 
         Self {
             inner: RefCell::new(kinetic_law),
@@ -164,6 +310,19 @@ impl FromPtr<sbmlcxx::KineticLaw> for KineticLaw<'_> {
     }
 }
 
+/// Returns whether the kinetic law keeps its parameters in `listOfParameters`.
+///
+/// Before Level 3, reaction-local parameters are plain `<parameter>` elements. libSBML
+/// stores them apart from the Level 3 `<localParameter>` list and only writes the list
+/// matching the level of the document.
+///
+/// # Arguments
+/// * `kinetic_law` - The kinetic law to check
+pub(crate) fn uses_parameter_list(kinetic_law: &mut Pin<&mut sbmlcxx::KineticLaw>) -> bool {
+    let base = upcast_pin!(kinetic_law, sbmlcxx::KineticLaw, sbmlcxx::SBase);
+    base.getLevel().0 < 3
+}
+
 impl std::fmt::Debug for KineticLaw<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut ds = f.debug_struct("KineticLaw");
@@ -177,7 +336,9 @@ impl std::fmt::Debug for KineticLaw<'_> {
 mod tests {
     use super::*;
 
-    use crate::{model::Model, reaction::Reaction, SBMLDocument};
+    use crate::{
+        model::Model, reaction::Reaction, reader::SBMLReader, unit::UnitKind, SBMLDocument,
+    };
     use serde::{Deserialize, Serialize};
 
     #[test]
@@ -294,4 +455,218 @@ mod tests {
         assert_eq!(kinetic_law.formula(), "k1 * S1");
         assert_eq!(cloned_kinetic_law.formula(), "k2 * S2");
     }
+
+    #[test]
+    fn test_kinetic_law_get_local_parameter() {
+        let doc = SBMLDocument::default();
+        let model = Model::new(&doc, "test");
+        let reaction = Reaction::new(&model, "r1");
+        let kinetic_law = KineticLaw::new(&reaction, "k1 * S1");
+        kinetic_law.create_local_parameter("k1");
+        kinetic_law.build_local_parameter("k2").value(2.0).build();
+
+        assert_eq!(kinetic_law.list_of_local_parameters().len(), 2);
+        assert_eq!(
+            kinetic_law.get_local_parameter("k2").unwrap().value(),
+            Some(2.0)
+        );
+        assert!(kinetic_law.get_local_parameter("k3").is_none());
+    }
+
+    #[test]
+    fn test_kinetic_law_local_parameters_roundtrip() {
+        let doc = SBMLDocument::default();
+        let model = Model::new(&doc, "test");
+        model.create_species("S");
+        let reaction = model.build_reaction("r1").reactant("S", 1.0).build();
+        let kinetic_law = reaction.create_kinetic_law("Vmax * S / (Km + S)");
+        kinetic_law.build_local_parameter("Km").value(0.5).build();
+        kinetic_law
+            .build_local_parameter("Vmax")
+            .value(10.0)
+            .build();
+
+        let doc = SBMLReader::from_xml_string(&doc.to_xml_string());
+        let model = doc.model().unwrap();
+        let kinetic_law = model.get_reaction("r1").unwrap().kinetic_law().unwrap();
+
+        assert_eq!(kinetic_law.formula(), "Vmax * S / (Km + S)");
+        assert_eq!(kinetic_law.list_of_local_parameters().len(), 2);
+        assert_eq!(
+            kinetic_law.get_local_parameter("Km").unwrap().value(),
+            Some(0.5)
+        );
+        assert_eq!(
+            kinetic_law.get_local_parameter("Vmax").unwrap().value(),
+            Some(10.0)
+        );
+    }
+
+    #[test]
+    fn test_formula_with_locals_substituted() {
+        let doc = SBMLDocument::default();
+        let model = Model::new(&doc, "test");
+        let reaction = Reaction::new(&model, "r1");
+        let kinetic_law = KineticLaw::new(&reaction, "k * S");
+        kinetic_law.add_local_parameter("k", Some(-2.5));
+        kinetic_law.create_local_parameter("Km");
+
+        // Unreferenced local parameters do not need a value
+        assert_eq!(
+            kinetic_law.formula_with_locals_substituted().unwrap(),
+            "-2.5 * S"
+        );
+        assert_eq!(kinetic_law.formula(), "k * S");
+
+        // A referenced local parameter without a value would resolve globally
+        kinetic_law.set_formula("k * S / (Km + S)");
+        assert!(matches!(
+            kinetic_law.formula_with_locals_substituted(),
+            Err(LibSBMLError::MissingValue(_))
+        ));
+    }
+
+    #[test]
+    fn test_kinetic_law_math() {
+        let doc = SBMLDocument::default();
+        let model = Model::new(&doc, "test");
+        let reaction = Reaction::new(&model, "r1");
+        let kinetic_law = KineticLaw::new(&reaction, "Vmax * S / (Km + S)");
+
+        assert!(matches!(
+            kinetic_law.math(),
+            Some(MathNode::Operator('/', _))
+        ));
+        assert_eq!(kinetic_law.referenced_symbols(), vec!["Vmax", "S", "Km"]);
+    }
+
+    #[test]
+    fn test_kinetic_law_from_math() {
+        let doc = SBMLDocument::default();
+        let model = Model::new(&doc, "test");
+        let reaction = Reaction::new(&model, "r1");
+
+        let math = MathNode::times([
+            MathNode::name("k"),
+            MathNode::name("A"),
+            MathNode::name("B"),
+        ]);
+        let kinetic_law = reaction
+            .create_kinetic_law_from_ast(&math)
+            .expect("Failed to create kinetic law");
+
+        assert_eq!(kinetic_law.formula(), "k * A * B");
+        assert_eq!(kinetic_law.math(), Some(math));
+        assert!(reaction.kinetic_law().is_some());
+    }
+
+    #[test]
+    fn test_kinetic_law_derived_units() {
+        let doc = SBMLDocument::default();
+        let model = Model::new(&doc, "test");
+
+        model
+            .build_unit_definition("per_second", "per second")
+            .unit(UnitKind::Second, Some(-1), None, None, None)
+            .build();
+        model
+            .build_compartment("c")
+            .unit("litre")
+            .size(1.0)
+            .constant(true)
+            .build();
+        model
+            .build_species("S")
+            .compartment("c")
+            .unit("mole")
+            .has_only_substance_units(false)
+            .initial_concentration(1.0)
+            .build();
+        model
+            .build_parameter("k")
+            .units("per_second")
+            .value(0.1)
+            .constant(true)
+            .build();
+
+        let reaction = Reaction::new(&model, "r1");
+        let kinetic_law = KineticLaw::new(&reaction, "k * S");
+
+        let derived = kinetic_law.derived_units().expect("Failed to derive units");
+        let mut units: Vec<_> = derived
+            .units()
+            .iter()
+            .map(|unit| (format!("{:?}", unit.kind()), unit.exponent()))
+            .collect();
+        units.sort();
+
+        assert_eq!(
+            units,
+            vec![
+                ("Litre".to_string(), -1),
+                ("Mole".to_string(), 1),
+                ("Second".to_string(), -1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_kinetic_law_level2_parameters() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<sbml xmlns="http://www.sbml.org/sbml/level2/version4" level="2" version="4">
+  <model id="test">
+    <listOfCompartments>
+      <compartment id="c" size="1"/>
+    </listOfCompartments>
+    <listOfSpecies>
+      <species id="S" compartment="c" initialConcentration="1"/>
+    </listOfSpecies>
+    <listOfReactions>
+      <reaction id="r1" reversible="false">
+        <listOfReactants>
+          <speciesReference species="S"/>
+        </listOfReactants>
+        <kineticLaw>
+          <math xmlns="http://www.w3.org/1998/Math/MathML">
+            <apply>
+              <times/>
+              <ci> k </ci>
+              <ci> S </ci>
+            </apply>
+          </math>
+          <listOfParameters>
+            <parameter id="k" value="0.1"/>
+          </listOfParameters>
+        </kineticLaw>
+      </reaction>
+    </listOfReactions>
+  </model>
+</sbml>"#;
+
+        let doc = SBMLReader::from_xml_string(xml);
+        let model = doc.model().unwrap();
+        let kinetic_law = model.get_reaction("r1").unwrap().kinetic_law().unwrap();
+
+        assert_eq!(kinetic_law.local_parameters().len(), 1);
+        assert_eq!(
+            kinetic_law.get_local_parameter("k").unwrap().value(),
+            Some(0.1)
+        );
+
+        // Parameters added to a Level 2 kinetic law are written as <parameter>
+        kinetic_law.build_local_parameter("k2").value(2.0).build();
+
+        let xml = doc.to_xml_string();
+        assert!(xml.contains("<listOfParameters>"));
+        assert!(!xml.contains("<listOfLocalParameters>"));
+
+        let doc = SBMLReader::from_xml_string(&xml);
+        let model = doc.model().unwrap();
+        let kinetic_law = model.get_reaction("r1").unwrap().kinetic_law().unwrap();
+        assert_eq!(kinetic_law.local_parameters().len(), 2);
+        assert_eq!(
+            kinetic_law.get_local_parameter("k2").unwrap().value(),
+            Some(2.0)
+        );
+    }
 }
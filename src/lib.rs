@@ -30,9 +30,15 @@
 //! - **Unit** (`unit`): Base units for quantities in the model
 //! - **UnitDefinition** (`unitdef`): Composite units of measurement
 //! - **Rule** (`rule`): Mathematical expressions that define model behavior
+//! - **FunctionDefinition** (`functiondefinition`): Reusable lambda functions
+//! - **Constraint** (`constraint`): Conditions expected to hold during simulation
+//! - **Event** (`event`): Discontinuous changes triggered by conditions
+//! - **EventAssignment** (`eventassignment`): Assignments carried out when an event fires
 //! - **SpeciesReference** (`speciesref`): References to species as reactants or products
 //! - **ModifierSpeciesReference** (`modref`): Species references for catalysts and regulators
 //! - **FluxObjective** (`fluxobjective`): Objectives for flux balance analysis
+//! - **CVTerm** (`cvterm`): Controlled vocabulary terms for MIRIAM annotations
+//! - **ModelHistory** (`history`): Creators and dates recording the provenance of a model
 //!
 //! ## FBC Package
 //!
@@ -40,6 +46,26 @@
 //! - **ListOfObjectives** (`listofobjectives`): List of objectives
 //! - **ListOfFluxObjectives** (`listoffluxobjectives`): List of flux objectives
 //! - **FluxObjective** (`fluxobjective`): Objectives for flux balance analysis
+//! - **GeneProduct** (`geneproduct`): Genes and gene products for GPR rules
+//! - **GeneProductAssociation** (`geneproductassociation`): GPR rules of reactions
+//!
+//! ## Groups Package
+//!
+//! - **Group** (`group`): Groups of model elements, e.g. subsystems
+//! - **Member** (`member`): Members of a group referencing other elements
+//! - **GroupKind** (`groupkind`): Relationship between the members of a group
+//!
+//! ## Comp Package
+//!
+//! - **Submodel** (`submodel`): Instances of other models within a model
+//! - **ExternalModelDefinition** (`externalmodeldefinition`): Models defined in other documents
+//!
+//! ## Comparing Components
+//!
+//! Wrappers such as [`Species`](species::Species) or [`Reaction`](reaction::Reaction)
+//! implement `PartialEq` by comparing their SBML content rather than the identity of the
+//! underlying libSBML objects. Floating point attributes are compared exactly, so values
+//! that differ only by rounding are not equal.
 
 /// Traits providing common functionality across SBML components
 pub mod traits {
@@ -47,6 +73,7 @@ pub mod traits {
     pub mod fromptr;
     pub mod inner;
     pub mod intoid;
+    pub mod notes;
     pub mod sbase;
 }
 
@@ -54,6 +81,18 @@ pub mod traits {
 pub mod cast;
 /// Compartments representing physical containers in the model
 pub mod compartment;
+/// Constraints describing conditions that should hold in the model
+pub mod constraint;
+/// Controlled vocabulary terms for MIRIAM annotations
+pub mod cvterm;
+/// Events describing discontinuous changes in the model
+pub mod event;
+/// Assignments carried out when an event fires
+pub mod eventassignment;
+/// Function definitions providing reusable math
+pub mod functiondefinition;
+/// Model history with creators and creation/modification dates
+pub mod history;
 /// Kinetic laws that define reaction rates and mathematics
 pub mod kineticlaw;
 /// Local parameters scoped to specific reactions or expressions
@@ -68,6 +107,10 @@ pub mod namespaces;
 pub mod parameter;
 /// Reactions describing biochemical transformations between species
 pub mod reaction;
+/// Ownership of elements that have been removed from a model
+pub mod removed;
+/// Common roles of model elements expressed as SBO terms
+pub mod role;
 /// Rules for mathematical constraints and assignments within models
 pub mod rule;
 /// Core document handling for SBML files and model containers
@@ -90,9 +133,11 @@ pub mod sbmlerror;
 
 /// FBC package types
 pub mod fbc {
-    pub use crate::fbc::fluxbound::FluxBound;
+    pub use crate::fbc::fluxbound::{FluxBound, FluxBoundBuilder};
     pub use crate::fbc::fluxboundop::FluxBoundOperation;
-    pub use crate::fbc::objective::Objective;
+    pub use crate::fbc::geneproduct::GeneProduct;
+    pub use crate::fbc::geneproductassociation::GeneProductAssociation;
+    pub use crate::fbc::objective::{Objective, ObjectiveBuilder};
     pub use crate::fbc::objectivetype::ObjectiveType;
 
     /// Flux bound
@@ -101,12 +146,41 @@ pub mod fbc {
     pub mod fluxboundop;
     /// A flux objective
     pub mod fluxobjective;
+    /// Gene products referenced by GPR rules
+    pub mod geneproduct;
+    /// Gene-protein-reaction associations of reactions
+    pub mod geneproductassociation;
     /// A general objective
     pub mod objective;
     /// Objective types
     pub mod objectivetype;
 }
 
+/// Groups package types
+pub mod groups {
+    pub use crate::groups::group::Group;
+    pub use crate::groups::groupkind::GroupKind;
+    pub use crate::groups::member::Member;
+
+    /// A group of model elements
+    pub mod group;
+    /// Group kinds
+    pub mod groupkind;
+    /// A member of a group
+    pub mod member;
+}
+
+/// Comp package types
+pub mod comp {
+    pub use crate::comp::externalmodeldefinition::ExternalModelDefinition;
+    pub use crate::comp::submodel::Submodel;
+
+    /// A model defined outside of the document
+    pub mod externalmodeldefinition;
+    /// An instance of another model
+    pub mod submodel;
+}
+
 /// Helper macros for working with SBML components
 pub mod macros;
 
@@ -119,6 +193,9 @@ pub mod reader;
 /// Internal module containing the wrapper types for annotations
 pub(crate) mod wrapper;
 
+/// Internal module for converting between formulas and math expressions
+pub(crate) mod math;
+
 /// Error handling for SBML models
 pub mod errors;
 
@@ -126,6 +203,8 @@ pub mod errors;
 pub(crate) mod collections {
     pub(crate) use crate::collections::compartments::*;
     pub(crate) use crate::collections::parameters::*;
+    pub(crate) use crate::collections::products::*;
+    pub(crate) use crate::collections::reactants::*;
     pub(crate) use crate::collections::reactions::*;
     pub(crate) use crate::collections::rules::*;
     pub(crate) use crate::collections::species::*;
@@ -133,6 +212,8 @@ pub(crate) mod collections {
 
     pub(crate) mod compartments;
     pub(crate) mod parameters;
+    pub(crate) mod products;
+    pub(crate) mod reactants;
     pub(crate) mod reactions;
     pub(crate) mod rules;
     pub(crate) mod species;
@@ -140,21 +221,34 @@ pub(crate) mod collections {
 }
 
 // Re-export commonly used types
+pub use math::MathNode;
 pub use sbmldoc::SBMLDocument;
 pub use traits::annotation::Annotation;
+pub use traits::notes::Notes;
 
 /// Prelude module providing convenient imports of commonly used types
 pub mod prelude {
     pub use crate::combine::combinearchive::*;
+    pub use crate::comp::*;
     pub use crate::compartment::Compartment;
+    pub use crate::constraint::*;
+    pub use crate::cvterm::*;
+    pub use crate::event::*;
+    pub use crate::eventassignment::*;
     pub use crate::fbc::*;
+    pub use crate::functiondefinition::*;
+    pub use crate::groups::*;
+    pub use crate::history::*;
     pub use crate::kineticlaw::*;
     pub use crate::localparameter::*;
+    pub use crate::math::MathNode;
     pub use crate::model::*;
     pub use crate::modref::*;
     pub use crate::parameter::*;
     pub use crate::reaction::*;
     pub use crate::reader::*;
+    pub use crate::removed::Removed;
+    pub use crate::role::Role;
     pub use crate::rule::*;
     pub use crate::sbmldoc::*;
     pub use crate::sbmlerror::*;
@@ -162,6 +256,7 @@ pub mod prelude {
     pub use crate::speciesref::*;
     pub use crate::traits::annotation::*;
     pub use crate::traits::intoid::*;
+    pub use crate::traits::notes::*;
     pub use crate::unit::*;
     pub use crate::unitdef::*;
 }
@@ -169,9 +264,11 @@ pub mod prelude {
 pub mod combine {
     pub use crate::combine::combinearchive::*;
     pub use crate::combine::manifest::KnownFormats;
+    pub use crate::combine::metadata::OmexMetadata;
     pub mod combinearchive;
     pub mod error;
     pub mod manifest;
+    pub mod metadata;
 }
 
 /// Internal module containing the raw FFI bindings to libSBML.
@@ -186,6 +283,18 @@ pub(crate) mod sbmlcxx {
         // Includes //
         #include "sbml/SBMLTypes.h"
         #include "sbml/packages/fbc/common/FbcExtensionTypes.h"
+        #include "sbml/packages/groups/common/GroupsExtensionTypes.h"
+        #include "sbml/packages/comp/common/CompExtensionTypes.h"
+        #include "sbml/util/memory.h"
+        #include "sbml/util/List.h"
+        #include "sbml/util/ElementFilter.h"
+        #include "sbml/math/L3Parser.h"
+        #include "sbml/math/L3FormulaFormatter.h"
+        #include "sbml/SBMLTransforms.h"
+        #include "sbml/annotation/CVTerm.h"
+        #include "sbml/annotation/ModelHistory.h"
+        #include "sbml/annotation/ModelCreator.h"
+        #include "sbml/annotation/Date.h"
         safety!(unsafe_ffi)
 
         // Base types
@@ -193,6 +302,8 @@ pub(crate) mod sbmlcxx {
         generate!("SBasePlugin")
         generate!("SBMLNamespaces")
         generate!("XMLNamespaces")
+        generate!("List")
+        generate!("ElementFilter")
 
         // Root types
         generate!("SBMLDocument")
@@ -213,8 +324,23 @@ pub(crate) mod sbmlcxx {
         generate!("InitialAssignment")
         generate!("RateRule")
         generate!("AssignmentRule")
+        generate!("AlgebraicRule")
         generate!("Rule")
         generate!("KineticLaw")
+        generate!("Event")
+        generate!("EventAssignment")
+        generate!("Trigger")
+        generate!("Delay")
+        generate!("Priority")
+        generate!("Constraint")
+        generate!("FunctionDefinition")
+
+        // Math types
+        generate!("ASTNode")
+        generate!("ASTNodeType_t")
+        generate!("SBML_parseL3Formula")
+        generate!("SBML_formulaToL3String")
+        generate!("SBMLTransforms")
 
         // FBC types
         generate!("FbcModelPlugin")
@@ -225,17 +351,50 @@ pub(crate) mod sbmlcxx {
         generate!("ListOfObjectives")
         generate!("ObjectiveType_t")
         generate!("FluxBoundOperation_t")
+        generate!("FbcReactionPlugin")
+        generate!("GeneProduct")
+        generate!("GeneProductAssociation")
+        generate!("FbcAssociation")
+
+        // Groups types
+        generate!("GroupsModelPlugin")
+        generate!("Group")
+        generate!("Member")
+        generate!("GroupKind_t")
+
+        // Comp types
+        generate!("CompSBMLDocumentPlugin")
+        generate!("CompModelPlugin")
+        generate!("Submodel")
+        generate!("ExternalModelDefinition")
+
+        // Annotation types
+        generate!("CVTerm")
+        generate!("QualifierType_t")
+        generate!("BiolQualifierType_t")
+        generate!("ModelQualifierType_t")
+        generate!("ModelHistory")
+        generate!("ModelCreator")
+        generate!("Date")
 
         // IO types
         generate!("SBMLWriter")
         generate!("SBMLReader")
 
+        // Conversion types
+        generate!("ConversionProperties")
+
         // Validation types
         generate!("SBMLValidator")
         generate!("SBMLInternalValidator")
         generate!("SBMLError")
         generate!("SBMLErrorLog")
         generate!("XMLError")
+        generate!("SBMLErrorCategory_t")
+        generate!("XMLErrorCategory_t")
+
+        // Memory utilities
+        generate!("safe_free")
 
         // Container types
         generate!("ListOfParameters")
@@ -243,6 +402,7 @@ pub(crate) mod sbmlcxx {
         generate!("ListOfCompartments")
         generate!("ListOfSpecies")
         generate!("ListOfReactions")
+        generate!("ListOfSpeciesReferences")
         generate!("ListOfUnitDefinitions")
     }
 
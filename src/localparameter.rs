@@ -20,36 +20,45 @@ use std::{cell::RefCell, pin::Pin, rc::Rc};
 use cxx::let_cxx_string;
 
 use crate::{
-    clone, get_unit_definition, inner, into_id, pin_ptr,
+    clone, get_unit_definition, inner, into_id,
+    kineticlaw::uses_parameter_list,
+    optional_property, pin_ptr,
     prelude::KineticLaw,
-    sbase,
+    required_property, sbase,
     sbmlcxx::{self},
     sbo_term,
     traits::{fromptr::FromPtr, sbase::SBase},
-    upcast, upcast_annotation, upcast_optional_property, upcast_pin, upcast_required_property,
+    upcast_annotation, upcast_notes, upcast_pin,
 };
 
 /// A safe wrapper around the libSBML LocalParameter class.
 ///
-/// This struct maintains a reference to the underlying C++ LocalParameter object
-/// through a RefCell and Pin to ensure memory safety while allowing interior mutability.
-/// LocalParameters are scoped to a specific KineticLaw and cannot be referenced outside
-/// of that context.
+/// This struct maintains a reference to the underlying C++ object through a RefCell and
+/// Pin to ensure memory safety while allowing interior mutability. LocalParameters are
+/// scoped to a specific KineticLaw and cannot be referenced outside of that context.
+///
+/// The object is held through its `Parameter` base class. Level 3 kinetic laws contain
+/// actual `LocalParameter` objects, whereas Level 2 kinetic laws contain plain `Parameter`
+/// objects in their `listOfParameters`. Virtual dispatch selects the right behaviour for
+/// both, so neither is ever treated as the other type.
 pub struct LocalParameter<'a> {
-    inner: RefCell<Pin<&'a mut sbmlcxx::LocalParameter>>,
+    inner: RefCell<Pin<&'a mut sbmlcxx::Parameter>>,
 }
 
 // Set the inner trait for the LocalParameter struct
-inner!(sbmlcxx::LocalParameter, LocalParameter<'a>);
+inner!(sbmlcxx::Parameter, LocalParameter<'a>);
 
 // Set the sbase trait for the LocalParameter struct
-sbase!(LocalParameter<'a>, sbmlcxx::LocalParameter);
+sbase!(LocalParameter<'a>, sbmlcxx::Parameter);
 
 // Set the annotation trait for the LocalParameter struct
-upcast_annotation!(LocalParameter<'a>, sbmlcxx::LocalParameter, sbmlcxx::SBase);
+upcast_annotation!(LocalParameter<'a>, sbmlcxx::Parameter, sbmlcxx::SBase);
+
+// Set the notes trait for the LocalParameter struct
+upcast_notes!(LocalParameter<'a>, sbmlcxx::Parameter, sbmlcxx::SBase);
 
 // Implement the Clone trait for the LocalParameter struct
-clone!(LocalParameter<'a>, sbmlcxx::LocalParameter);
+clone!(LocalParameter<'a>, sbmlcxx::Parameter);
 
 // Set the into_id trait for the LocalParameter struct
 into_id!(&Rc<LocalParameter<'_>>, id);
@@ -57,6 +66,9 @@ into_id!(&Rc<LocalParameter<'_>>, id);
 impl<'a> LocalParameter<'a> {
     /// Creates a new LocalParameter instance within the given KineticLaw.
     ///
+    /// Level 2 kinetic laws only serialize their plain parameter list, so a
+    /// `<parameter>` is created for them instead of a `<localParameter>`.
+    ///
     /// # Arguments
     /// * `kinetic_law` - The parent KineticLaw that will contain this local parameter
     /// * `id` - The identifier for this local parameter
@@ -64,14 +76,18 @@ impl<'a> LocalParameter<'a> {
     /// # Returns
     /// A new LocalParameter instance
     pub fn new(kinetic_law: &KineticLaw<'a>, id: &str) -> Self {
-        let parameter_ptr = kinetic_law
-            .inner()
-            .borrow_mut()
-            .as_mut()
-            .createLocalParameter();
-        let mut local_parameter = pin_ptr!(parameter_ptr, sbmlcxx::LocalParameter);
-        let mut parameter =
-            upcast_pin!(local_parameter, sbmlcxx::LocalParameter, sbmlcxx::Parameter);
+        let mut parameter = {
+            let mut kinetic_law = kinetic_law.inner().borrow_mut();
+
+            if uses_parameter_list(&mut kinetic_law) {
+                let parameter_ptr = kinetic_law.as_mut().createParameter();
+                pin_ptr!(parameter_ptr, sbmlcxx::Parameter)
+            } else {
+                let local_parameter_ptr = kinetic_law.as_mut().createLocalParameter();
+                let mut local_parameter = pin_ptr!(local_parameter_ptr, sbmlcxx::LocalParameter);
+                upcast_pin!(local_parameter, sbmlcxx::LocalParameter, sbmlcxx::Parameter)
+            }
+        };
 
         // Set the default values for the parameter
         parameter.as_mut().initDefaults();
@@ -81,7 +97,7 @@ impl<'a> LocalParameter<'a> {
         parameter.as_mut().setId(&id);
 
         Self {
-            inner: RefCell::new(local_parameter),
+            inner: RefCell::new(parameter),
         }
     }
 
@@ -89,85 +105,84 @@ impl<'a> LocalParameter<'a> {
     get_unit_definition!(units);
 
     // Getter and setter for id
-    upcast_required_property!(
-        LocalParameter<'a>,
-        id,
-        String,
-        getId,
-        setId,
-        sbmlcxx::LocalParameter,
-        sbmlcxx::Parameter
-    );
+    required_property!(LocalParameter<'a>, id, String, getId, setId);
 
     // Getter and setter for name
-    upcast_required_property!(
-        LocalParameter<'a>,
-        name,
-        String,
-        getName,
-        setName,
-        sbmlcxx::LocalParameter,
-        sbmlcxx::Parameter
-    );
+    required_property!(LocalParameter<'a>, name, String, getName, setName);
 
     // Getter and setter for value
-    upcast_optional_property!(
+    optional_property!(
         LocalParameter<'a>,
         value,
         f64,
         getValue,
         setValue,
-        isSetValue,
-        sbmlcxx::LocalParameter,
-        sbmlcxx::Parameter
+        isSetValue
     );
 
     // Getter and setter for units
-    upcast_optional_property!(
+    optional_property!(
         LocalParameter<'a>,
         units,
         String,
         getUnits,
         setUnits,
-        isSetUnits,
-        sbmlcxx::LocalParameter,
-        sbmlcxx::Parameter
+        isSetUnits
     );
 
     // Getter and setter for constant
-    upcast_optional_property!(
+    optional_property!(
         LocalParameter<'a>,
         constant,
         bool,
         getConstant,
         setConstant,
-        isSetConstant,
-        sbmlcxx::LocalParameter,
-        sbmlcxx::Parameter
+        isSetConstant
     );
 
     // SBO Term Methods generated by the `sbo_term` macro
-    sbo_term!(sbmlcxx::LocalParameter, sbmlcxx::SBase);
+    sbo_term!(sbmlcxx::Parameter, sbmlcxx::SBase);
 }
 
 impl FromPtr<sbmlcxx::LocalParameter> for LocalParameter<'_> {
-    /// Creates a new LocalParameter instance from a unique pointer to a libSBML LocalParameter.
+    /// Creates a new LocalParameter instance from a pointer to a libSBML LocalParameter.
     ///
     /// This method is primarily used internally by the KineticLaw class to create
-    /// LocalParameter instances from libSBML LocalParameter pointers.
+    /// LocalParameter instances from the `listOfLocalParameters` of Level 3 kinetic laws.
     ///
     /// # Arguments
-    /// * `ptr` - A unique pointer to a libSBML LocalParameter
+    /// * `ptr` - A pointer to a libSBML LocalParameter
     ///
     /// # Returns
     /// A new LocalParameter instance
     fn from_ptr(ptr: *mut sbmlcxx::LocalParameter) -> Self {
-        let local_parameter = pin_ptr!(ptr, sbmlcxx::LocalParameter);
+        let mut local_parameter = pin_ptr!(ptr, sbmlcxx::LocalParameter);
+        let parameter = upcast_pin!(local_parameter, sbmlcxx::LocalParameter, sbmlcxx::Parameter);
         Self {
-            inner: RefCell::new(local_parameter),
+            inner: RefCell::new(parameter),
         }
     }
 }
+
+impl FromPtr<sbmlcxx::Parameter> for LocalParameter<'_> {
+    /// Creates a new LocalParameter instance from a pointer to a libSBML Parameter.
+    ///
+    /// This method is primarily used internally by the KineticLaw class to create
+    /// LocalParameter instances from the `listOfParameters` of Level 2 kinetic laws.
+    ///
+    /// # Arguments
+    /// * `ptr` - A pointer to a libSBML Parameter
+    ///
+    /// # Returns
+    /// A new LocalParameter instance
+    fn from_ptr(ptr: *mut sbmlcxx::Parameter) -> Self {
+        let parameter = pin_ptr!(ptr, sbmlcxx::Parameter);
+        Self {
+            inner: RefCell::new(parameter),
+        }
+    }
+}
+
 /// A builder for constructing LocalParameter instances with a fluent API.
 ///
 /// This struct provides a builder pattern interface for creating and configuring
@@ -187,10 +202,8 @@ impl<'a> LocalParameterBuilder<'a> {
     /// # Returns
     /// A new LocalParameterBuilder instance
     pub fn new(kinetic_law: &KineticLaw<'a>, id: &str) -> Self {
-        let local_parameter = LocalParameter::new(kinetic_law, id);
-        Self {
-            local_parameter: Rc::new(local_parameter),
-        }
+        let local_parameter = kinetic_law.create_local_parameter(id);
+        Self { local_parameter }
     }
 
     /// Sets the name for this local parameter.
@@ -190,8 +190,19 @@ macro_rules! upcast_annotation {
             /// Result indicating success or containing an error if the annotation is invalid
             fn set_annotation(&self, annotation: &str) -> Result<(), Box<dyn Error>> {
                 let mut base = $crate::upcast!(self, $cxx_type, $cxx_upcast);
-                cxx::let_cxx_string!(annotation = annotation);
-                base.as_mut().setAnnotation1(&annotation);
+                cxx::let_cxx_string!(cxx_annotation = annotation);
+                let result = base.as_mut().setAnnotation1(&cxx_annotation);
+
+                // LIBSBML_OPERATION_SUCCESS
+                if result.0 != 0 {
+                    return Err(Box::new($crate::errors::LibSBMLError::InvalidArgument(
+                        format!(
+                            "Invalid annotation (libSBML code {}): {annotation}",
+                            result.0
+                        ),
+                    )));
+                }
+
                 Ok(())
             }
 
@@ -221,6 +232,83 @@ macro_rules! upcast_annotation {
                 let parsed: Wrapper<T> = from_str(&annotation)?;
                 Ok(parsed.annotation)
             }
+
+            /// Adds a controlled vocabulary (CV) term to this element.
+            ///
+            /// We are using upcasting to access the base class's addCVTerm method.
+            ///
+            /// # Arguments
+            /// * `qualifier` - The qualifier of the term
+            /// * `resources` - The URIs of the referenced resources
+            ///
+            /// # Returns
+            /// Result indicating success or containing an error if the term was rejected
+            fn add_cv_term(
+                &self,
+                qualifier: impl Into<$crate::cvterm::Qualifier>,
+                resources: &[&str],
+            ) -> Result<(), $crate::errors::LibSBMLError> {
+                let base = $crate::upcast!(self, $cxx_type, $cxx_upcast);
+                $crate::cvterm::add_cv_term(base, qualifier.into(), resources)
+            }
+
+            /// Gets all controlled vocabulary (CV) terms of this element.
+            ///
+            /// We are using upcasting to access the base class's getCVTerm method.
+            ///
+            /// # Returns
+            /// The CV terms of this element
+            fn cv_terms(&self) -> Vec<$crate::cvterm::CVTerm> {
+                let base = $crate::upcast!(self, $cxx_type, $cxx_upcast);
+                $crate::cvterm::cv_terms(base)
+            }
+        }
+    };
+}
+
+/// Implements the Notes trait for a wrapper type.
+///
+/// This macro generates an implementation of the Notes trait for a wrapper type that contains
+/// a pinned reference to a C++ object, using upcasting to access the notes methods of the
+/// base class. Unlike `upcast_annotation!`, it does not import anything into the calling
+/// module and can be combined freely with other macros.
+///
+/// # Arguments
+/// * `$type` - The Rust wrapper type (e.g. Species<'a>)
+/// * `$cxx_type` - The C++ type that is being wrapped (e.g. sbmlcxx::Species)
+/// * `$cxx_upcast` - The C++ base type to upcast to (e.g. sbmlcxx::SBase)
+#[macro_export]
+macro_rules! upcast_notes {
+    ($type:ty, $cxx_type:ty, $cxx_upcast:ty) => {
+        impl<'a> $crate::traits::notes::Notes for $type {
+            fn notes(&self) -> String {
+                let base = $crate::upcast!(self, $cxx_type, $cxx_upcast);
+                base.getNotesString().to_str().unwrap().to_string()
+            }
+
+            fn set_notes(&self, notes: &str) -> Result<(), $crate::errors::LibSBMLError> {
+                let mut base = $crate::upcast!(self, $cxx_type, $cxx_upcast);
+
+                // Plain text needs to be wrapped in XHTML markup by libSBML
+                let add_xhtml_markup = !notes.trim_start().starts_with('<');
+                cxx::let_cxx_string!(cxx_notes = notes);
+                let result = base.as_mut().setNotes1(&cxx_notes, add_xhtml_markup);
+
+                // LIBSBML_OPERATION_SUCCESS
+                if result.0 != 0 {
+                    return Err($crate::errors::LibSBMLError::InvalidArgument(format!(
+                        "Invalid notes (libSBML code {}): {notes}",
+                        result.0
+                    )));
+                }
+
+                Ok(())
+            }
+
+            fn is_set_notes(&self) -> bool {
+                let base = $crate::upcast!(self, $cxx_type, $cxx_upcast);
+                base.isSetNotes()
+            }
         }
     };
 }
@@ -276,6 +364,45 @@ macro_rules! sbo_term {
             cxx::let_cxx_string!(id = id);
             base.as_mut().setSBOTerm1(&id);
         }
+
+        /// Sets the SBO term corresponding to a common role.
+        ///
+        /// # Arguments
+        /// * `role` - The role to set (e.g. [`Role::Catalysis`](crate::role::Role::Catalysis))
+        pub fn set_role(&self, role: $crate::role::Role) {
+            self.set_sbo_term(role.sbo_term());
+        }
+
+        /// Gets the role corresponding to the SBO term.
+        ///
+        /// # Returns
+        /// The role, or None if no SBO term is set or it has no named role
+        pub fn role(&self) -> Option<$crate::role::Role> {
+            $crate::role::Role::from_sbo_term(&self.sbo_term_id())
+        }
+    };
+}
+
+/// A macro for generating a method that checks whether an element's id is set.
+///
+/// Some SBML elements (e.g. species references or flux objectives) have an optional
+/// id. This macro generates an `id_is_set` method that wraps libSBML's `isSetId`,
+/// which makes it possible to distinguish explicitly set ids from missing ones.
+///
+/// # Arguments
+/// * `$cxx_type` - The C++ type that is being wrapped (e.g. sbmlcxx::FluxObjective)
+/// * `$cxx_upcast` - The C++ base type to upcast to (e.g. sbmlcxx::SBase)
+#[macro_export]
+macro_rules! id_is_set {
+    ($cxx_type:ty, $cxx_upcast:ty) => {
+        /// Returns whether the id of this element has been set.
+        ///
+        /// # Returns
+        /// `true` if an id is set, `false` otherwise
+        pub fn id_is_set(&self) -> bool {
+            let base = $crate::upcast!(self, $cxx_type, $cxx_upcast);
+            base.isSetId()
+        }
     };
 }
 
@@ -417,7 +544,8 @@ macro_rules! set_collection_annotation {
 /// * `$cxx_type` - The C++ type that is being wrapped (e.g. sbmlcxx::Species)
 ///
 /// This will generate an implementation of the SBase trait for the wrapper type,
-/// allowing the wrapper type to be converted into an SBase.
+/// allowing the wrapper type to be converted into an SBase. Additionally, a public
+/// `to_xml_fragment` method is generated to serialize the element in isolation.
 #[macro_export]
 macro_rules! sbase {
     ($type:ty, $cxx_type:ty) => {
@@ -427,6 +555,48 @@ macro_rules! sbase {
                 $crate::upcast_pin!(inner, $cxx_type, sbmlcxx::SBase)
             }
         }
+
+        impl<'a> $type {
+            /// Serializes this element in isolation to an XML string.
+            ///
+            /// Useful for logging or comparing single elements without
+            /// serializing the whole document.
+            ///
+            /// # Returns
+            /// The XML representation of this element
+            pub fn to_xml_fragment(&self) -> String {
+                $crate::traits::sbase::SBase::to_xml_fragment(self)
+            }
+
+            /// Gets the meta identifier of this element.
+            ///
+            /// RDF annotations such as CV terms reference elements by their metaid.
+            ///
+            /// # Returns
+            /// The metaid, or None if it is not set
+            pub fn metaid(&self) -> Option<String> {
+                $crate::traits::sbase::SBase::metaid(self)
+            }
+
+            /// Sets the meta identifier of this element.
+            ///
+            /// # Arguments
+            /// * `metaid` - The metaid to set (must be a valid XML ID, e.g. "meta_glucose")
+            ///
+            /// # Returns
+            /// Result indicating success or an error if the metaid is invalid
+            pub fn set_metaid(&self, metaid: &str) -> Result<(), $crate::errors::LibSBMLError> {
+                $crate::traits::sbase::SBase::set_metaid(self, metaid)
+            }
+
+            /// Returns whether the meta identifier of this element is set.
+            ///
+            /// # Returns
+            /// `true` if a metaid is set, `false` otherwise
+            pub fn is_set_metaid(&self) -> bool {
+                $crate::traits::sbase::SBase::is_set_metaid(self)
+            }
+        }
     };
 }
 
@@ -453,3 +623,35 @@ macro_rules! get_unit_definition {
         }
     };
 }
+
+/// A macro to implement the derived units method for a type.
+///
+/// This macro generates a method that infers the units of an element from its math
+/// and the units of the referenced model components, as provided by libSBML's
+/// `getDerivedUnitDefinition`.
+///
+/// The returned unit definition is owned by the libSBML element (respectively the unit
+/// cache of its model) and must not be freed, so it is wrapped as a borrowed element
+/// that lives as long as the model.
+#[macro_export]
+macro_rules! derived_units {
+    () => {
+        /// Returns the units derived from this element and the model it belongs to.
+        ///
+        /// # Returns
+        /// The derived unit definition, or None if libSBML cannot derive any units
+        pub fn derived_units(&self) -> Option<$crate::unitdef::UnitDefinition<'a>> {
+            let ptr = self.inner.borrow_mut().as_mut().getDerivedUnitDefinition();
+
+            if ptr.is_null() {
+                return None;
+            }
+
+            Some(
+                <$crate::unitdef::UnitDefinition<'a> as $crate::traits::fromptr::FromPtr<
+                    $crate::sbmlcxx::UnitDefinition,
+                >>::from_ptr(ptr),
+            )
+        }
+    };
+}
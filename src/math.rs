@@ -0,0 +1,411 @@
+//! Helpers for converting between infix formulas and libSBML math expressions.
+//!
+//! Several SBML elements (e.g. triggers, delays and event assignments) only
+//! expose their math as an `ASTNode`. These helpers parse formulas using the
+//! SBML Level 3 infix syntax into AST nodes and render AST nodes back to
+//! formula strings. [`MathNode`] mirrors an AST as an owned Rust tree for
+//! programmatic inspection.
+
+use std::ffi::{CStr, CString};
+
+use autocxx::{c_int, WithinUniquePtr};
+use cxx::UniquePtr;
+
+use crate::{errors::LibSBMLError, sbmlcxx};
+
+/// A mathematical expression as an owned tree mirroring the libSBML `ASTNode`.
+///
+/// Built by [`KineticLaw::math`](crate::kineticlaw::KineticLaw::math) and allows
+/// inspecting an expression without going through its string representation, e.g.
+/// to find the species a rate law depends on.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MathNode {
+    /// A numeric literal (integer, real, rational or e-notation)
+    Number(f64),
+    /// A reference to a symbol of the model (e.g. a species or parameter)
+    Name(String),
+    /// A csymbol such as `time` or `avogadro`, identified by its name
+    Csymbol(String),
+    /// A predefined constant (`pi`, `exponentiale`, `true` or `false`)
+    Constant(String),
+    /// An arithmetic operator (`+`, `-`, `*`, `/` or `^`) and its operands
+    Operator(char, Vec<MathNode>),
+    /// A function call, relational or logical operator (e.g. `exp`, `lt`, `and` or a
+    /// function definition id) and its arguments
+    Function(String, Vec<MathNode>),
+}
+
+impl MathNode {
+    /// Builds a tree by recursively walking a libSBML AST node.
+    ///
+    /// # Arguments
+    /// * `ast` - Pointer to the AST node to convert
+    ///
+    /// # Returns
+    /// The tree, or None if the pointer is null
+    pub(crate) fn from_ast(ast: *const sbmlcxx::ASTNode) -> Option<Self> {
+        if ast.is_null() {
+            return None;
+        }
+
+        let node = unsafe { &*ast };
+        let name = || {
+            let ptr = node.getName();
+            if ptr.is_null() {
+                return String::new();
+            }
+            unsafe { CStr::from_ptr(ptr) }
+                .to_string_lossy()
+                .into_owned()
+        };
+        let children = || {
+            (0..node.getNumChildren().0)
+                .filter_map(|i| Self::from_ast(node.getChild(i.into())))
+                .collect::<Vec<_>>()
+        };
+
+        let math = if node.isNumber() {
+            MathNode::Number(node.getValue())
+        } else if node.isName() {
+            if matches!(node.getType(), sbmlcxx::ASTNodeType_t::AST_NAME) {
+                MathNode::Name(name())
+            } else {
+                MathNode::Csymbol(name())
+            }
+        } else if node.isConstant() {
+            MathNode::Constant(name())
+        } else if node.isOperator() {
+            MathNode::Operator(node.getCharacter() as u8 as char, children())
+        } else {
+            MathNode::Function(name(), children())
+        };
+
+        Some(math)
+    }
+
+    /// Creates a numeric literal.
+    pub fn number(value: f64) -> Self {
+        MathNode::Number(value)
+    }
+
+    /// Creates a reference to a model symbol (e.g. a species or parameter).
+    pub fn name(name: impl Into<String>) -> Self {
+        MathNode::Name(name.into())
+    }
+
+    /// Creates the sum of the given terms.
+    pub fn plus(terms: impl IntoIterator<Item = MathNode>) -> Self {
+        MathNode::Operator('+', terms.into_iter().collect())
+    }
+
+    /// Creates the difference `left - right`.
+    pub fn minus(left: MathNode, right: MathNode) -> Self {
+        MathNode::Operator('-', vec![left, right])
+    }
+
+    /// Creates the product of the given factors.
+    pub fn times(factors: impl IntoIterator<Item = MathNode>) -> Self {
+        MathNode::Operator('*', factors.into_iter().collect())
+    }
+
+    /// Creates the quotient `numerator / denominator`.
+    pub fn divide(numerator: MathNode, denominator: MathNode) -> Self {
+        MathNode::Operator('/', vec![numerator, denominator])
+    }
+
+    /// Creates the power `base ^ exponent`.
+    pub fn power(base: MathNode, exponent: MathNode) -> Self {
+        MathNode::Operator('^', vec![base, exponent])
+    }
+
+    /// Creates a call of a built-in function (e.g. `exp`) or function definition.
+    pub fn function(name: impl Into<String>, args: impl IntoIterator<Item = MathNode>) -> Self {
+        MathNode::Function(name.into(), args.into_iter().collect())
+    }
+
+    /// Converts this tree into an owned libSBML AST node.
+    ///
+    /// # Returns
+    /// The AST node, or an error if the tree contains an unknown operator or constant
+    pub(crate) fn to_ast(&self) -> Result<UniquePtr<sbmlcxx::ASTNode>, LibSBMLError> {
+        use sbmlcxx::ASTNodeType_t as T;
+
+        let named_node = |node_type: T, name: &str| {
+            let c_name = CString::new(name)
+                .map_err(|_| LibSBMLError::InvalidArgument(format!("Invalid name: {name}")))?;
+            let mut node = sbmlcxx::ASTNode::new(node_type).within_unique_ptr();
+            unsafe { node.pin_mut().setName(c_name.as_ptr()) };
+            Ok::<_, LibSBMLError>(node)
+        };
+
+        let mut node = match self {
+            MathNode::Number(value) => {
+                // Whole numbers are written as integers, everything else as reals. Setting
+                // the value directly keeps negative numbers as literals instead of unary minus.
+                let is_integer = value.fract() == 0.0 && value.abs() <= i32::MAX as f64;
+                let node_type = if is_integer {
+                    T::AST_INTEGER
+                } else {
+                    T::AST_REAL
+                };
+                let mut node = sbmlcxx::ASTNode::new(node_type).within_unique_ptr();
+                if is_integer {
+                    node.pin_mut().setValue(c_int(*value as i32));
+                } else {
+                    node.pin_mut().setValue3(*value);
+                }
+                return Ok(node);
+            }
+            MathNode::Name(name) => return named_node(T::AST_NAME, name),
+            MathNode::Csymbol(name) => {
+                let node_type = match name.as_str() {
+                    "avogadro" => T::AST_NAME_AVOGADRO,
+                    _ => T::AST_NAME_TIME,
+                };
+                return named_node(node_type, name);
+            }
+            MathNode::Constant(name) => {
+                let node_type = match name.as_str() {
+                    "pi" => T::AST_CONSTANT_PI,
+                    "exponentiale" => T::AST_CONSTANT_E,
+                    "true" => T::AST_CONSTANT_TRUE,
+                    "false" => T::AST_CONSTANT_FALSE,
+                    _ => {
+                        return Err(LibSBMLError::InvalidArgument(format!(
+                            "Unknown constant: {name}"
+                        )))
+                    }
+                };
+                return Ok(sbmlcxx::ASTNode::new(node_type).within_unique_ptr());
+            }
+            MathNode::Operator(operator, _) => {
+                let node_type = match operator {
+                    '+' => T::AST_PLUS,
+                    '-' => T::AST_MINUS,
+                    '*' => T::AST_TIMES,
+                    '/' => T::AST_DIVIDE,
+                    '^' => T::AST_POWER,
+                    _ => {
+                        return Err(LibSBMLError::InvalidArgument(format!(
+                            "Unknown operator: {operator}"
+                        )))
+                    }
+                };
+                sbmlcxx::ASTNode::new(node_type).within_unique_ptr()
+            }
+            MathNode::Function(name, _) => {
+                let mut node = named_node(T::AST_FUNCTION, name)?;
+                // Turns names of built-in functions (e.g. "exp" or "lt") into their node types
+                node.pin_mut().canonicalize();
+                node
+            }
+        };
+
+        if let MathNode::Operator(_, args) | MathNode::Function(_, args) = self {
+            for arg in args {
+                // The parent node takes ownership of its children
+                let child = arg.to_ast()?;
+                unsafe { node.pin_mut().addChild(child.into_raw(), false) };
+            }
+        }
+
+        Ok(node)
+    }
+
+    /// Returns the names of all model symbols referenced in this expression.
+    ///
+    /// Csymbols and constants are not included. Each name is listed once, in the
+    /// order of its first occurrence.
+    ///
+    /// # Returns
+    /// A vector of the referenced symbol names
+    pub fn referenced_symbols(&self) -> Vec<String> {
+        fn collect(node: &MathNode, symbols: &mut Vec<String>) {
+            match node {
+                MathNode::Name(name) => {
+                    if !symbols.contains(name) {
+                        symbols.push(name.clone());
+                    }
+                }
+                MathNode::Operator(_, args) | MathNode::Function(_, args) => {
+                    args.iter().for_each(|arg| collect(arg, symbols));
+                }
+                MathNode::Number(_) | MathNode::Csymbol(_) | MathNode::Constant(_) => {}
+            }
+        }
+
+        let mut symbols = Vec::new();
+        collect(self, &mut symbols);
+        symbols
+    }
+}
+
+/// Parses an infix formula into an owned libSBML AST node.
+///
+/// # Arguments
+/// * `formula` - The formula to parse (e.g. "time >= 10")
+///
+/// # Returns
+/// The parsed AST node, or an error if the formula is not valid L3 infix syntax
+pub(crate) fn parse_formula(formula: &str) -> Result<UniquePtr<sbmlcxx::ASTNode>, LibSBMLError> {
+    let c_formula = CString::new(formula)
+        .map_err(|_| LibSBMLError::InvalidArgument(format!("Invalid formula: {formula}")))?;
+
+    let ast = unsafe { sbmlcxx::SBML_parseL3Formula(c_formula.as_ptr()) };
+    if ast.is_null() {
+        return Err(LibSBMLError::InvalidArgument(format!(
+            "Invalid formula: {formula}"
+        )));
+    }
+
+    // The parser hands over ownership of the node to the caller
+    Ok(unsafe { UniquePtr::from_raw(ast) })
+}
+
+/// Renders a libSBML AST node as an infix formula.
+///
+/// # Arguments
+/// * `ast` - Pointer to the AST node to render
+///
+/// # Returns
+/// The formula as a String, or None if the pointer is null
+pub(crate) fn formula_from_ast(ast: *const sbmlcxx::ASTNode) -> Option<String> {
+    if ast.is_null() {
+        return None;
+    }
+
+    let ptr = unsafe { sbmlcxx::SBML_formulaToL3String(ast) };
+    if ptr.is_null() {
+        return None;
+    }
+
+    let formula = unsafe { CStr::from_ptr(ptr) }
+        .to_string_lossy()
+        .into_owned();
+
+    // The formatted string is owned by the caller and must be released
+    unsafe { sbmlcxx::safe_free(ptr as *mut autocxx::c_void) };
+
+    Some(formula)
+}
+
+/// Evaluates a libSBML AST node to a numeric value.
+///
+/// Identifiers in the expression are resolved against the values defined in the
+/// given model (e.g. parameter values or compartment sizes).
+///
+/// # Arguments
+/// * `ast` - Pointer to the AST node to evaluate
+/// * `model` - Pointer to the model used to resolve identifiers
+///
+/// # Returns
+/// The value of the expression, or None if the pointer is null or the expression
+/// could not be evaluated
+pub(crate) fn evaluate_ast(
+    ast: *const sbmlcxx::ASTNode,
+    model: *const sbmlcxx::Model,
+) -> Option<f64> {
+    if ast.is_null() {
+        return None;
+    }
+
+    let value = unsafe { sbmlcxx::SBMLTransforms::evaluateASTNode(ast, model) };
+
+    // libSBML signals expressions that cannot be evaluated with NaN
+    if value.is_nan() {
+        None
+    } else {
+        Some(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_formula_roundtrip() {
+        let ast = parse_formula("time >= 10").expect("Failed to parse formula");
+        let formula = formula_from_ast(&*ast as *const sbmlcxx::ASTNode);
+        assert_eq!(formula, Some("time >= 10".to_string()));
+    }
+
+    #[test]
+    fn test_evaluate_ast() {
+        let ast = parse_formula("2 * (3 + 4)").expect("Failed to parse formula");
+        let value = evaluate_ast(&*ast as *const sbmlcxx::ASTNode, std::ptr::null());
+        assert_eq!(value, Some(14.0));
+    }
+
+    #[test]
+    fn test_parse_formula_invalid() {
+        assert!(parse_formula("k1 * (S").is_err());
+    }
+
+    #[test]
+    fn test_math_node_from_ast() {
+        let ast = parse_formula("k * S / (Km + S) + exp(x) * pi").expect("Failed to parse");
+        let math = MathNode::from_ast(&*ast as *const sbmlcxx::ASTNode).unwrap();
+
+        let name = |name: &str| MathNode::Name(name.to_string());
+        assert_eq!(
+            math,
+            MathNode::Operator(
+                '+',
+                vec![
+                    MathNode::Operator(
+                        '/',
+                        vec![
+                            MathNode::Operator('*', vec![name("k"), name("S")]),
+                            MathNode::Operator('+', vec![name("Km"), name("S")]),
+                        ]
+                    ),
+                    MathNode::Operator(
+                        '*',
+                        vec![
+                            MathNode::Function("exp".to_string(), vec![name("x")]),
+                            MathNode::Constant("pi".to_string()),
+                        ]
+                    ),
+                ]
+            )
+        );
+        assert_eq!(math.referenced_symbols(), vec!["k", "S", "Km", "x"]);
+        assert_eq!(MathNode::from_ast(std::ptr::null()), None);
+    }
+
+    #[test]
+    fn test_math_node_to_ast() {
+        let math = MathNode::plus([
+            MathNode::times([MathNode::number(2.0), MathNode::name("k")]),
+            MathNode::function("exp", [MathNode::name("x")]),
+            MathNode::power(MathNode::name("S"), MathNode::number(0.5)),
+        ]);
+
+        let ast = math.to_ast().expect("Failed to convert math");
+        let formula = formula_from_ast(&*ast as *const sbmlcxx::ASTNode);
+        assert_eq!(formula, Some("2 * k + exp(x) + S^0.5".to_string()));
+
+        // Converting back yields the original tree
+        assert_eq!(
+            MathNode::from_ast(&*ast as *const sbmlcxx::ASTNode),
+            Some(math)
+        );
+
+        assert!(MathNode::Operator('%', vec![]).to_ast().is_err());
+    }
+
+    #[test]
+    fn test_math_node_to_ast_negative_numbers() {
+        for value in [-2.0, -0.5, 1e-12, 3.0e10] {
+            let math = MathNode::times([MathNode::number(value), MathNode::name("k")]);
+            let ast = math.to_ast().expect("Failed to convert math");
+
+            // Negative literals are kept as numbers instead of becoming a unary minus
+            assert_eq!(
+                MathNode::from_ast(&*ast as *const sbmlcxx::ASTNode),
+                Some(math)
+            );
+        }
+    }
+}
@@ -10,8 +10,9 @@
 use std::{cell::RefCell, pin::Pin};
 
 use crate::{
-    clone, inner, pin_ptr, prelude::IntoId, reaction::Reaction, sbase, sbmlcxx, sbo_term,
-    traits::fromptr::FromPtr, upcast, upcast_annotation, upcast_pin, upcast_required_property,
+    clone, id_is_set, inner, pin_ptr, prelude::IntoId, reaction::Reaction, sbase, sbmlcxx,
+    sbo_term, traits::fromptr::FromPtr, upcast, upcast_annotation, upcast_notes, upcast_pin,
+    upcast_required_property,
 };
 use cxx::let_cxx_string;
 
@@ -42,6 +43,13 @@ upcast_annotation!(
     sbmlcxx::SBase
 );
 
+// Set the notes trait for the ModifierSpeciesReference struct
+upcast_notes!(
+    ModifierSpeciesReference<'a>,
+    sbmlcxx::ModifierSpeciesReference,
+    sbmlcxx::SBase
+);
+
 // Implement the Clone trait for the ModifierSpeciesReference struct
 clone!(
     ModifierSpeciesReference<'a>,
@@ -89,6 +97,9 @@ impl<'a> ModifierSpeciesReference<'a> {
         sbmlcxx::SimpleSpeciesReference
     );
 
+    // Checks whether the id is set, generated by the `id_is_set` macro
+    id_is_set!(sbmlcxx::ModifierSpeciesReference, sbmlcxx::SBase);
+
     // SBO Term Methods generated by the `sbo_term` macro
     sbo_term!(sbmlcxx::ModifierSpeciesReference, sbmlcxx::SBase);
 }
@@ -266,4 +277,26 @@ mod tests {
             modifier_reference.get_annotation_serde().unwrap();
         assert_eq!(extracted_annotation.test, "test_annotation");
     }
+
+    /// Tests assigning a role to a modifier
+    ///
+    /// This test verifies that:
+    /// - The role is stored as the corresponding SBO term
+    /// - The role can be read back from the SBO term
+    #[test]
+    fn test_modifier_set_role() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test_model");
+        let reaction = model.create_reaction("test_reaction");
+        let modifier_reference = reaction.create_modifier("enzyme");
+        assert_eq!(modifier_reference.role(), None);
+
+        modifier_reference.set_role(crate::role::Role::Catalysis);
+
+        assert_eq!(modifier_reference.sbo_term_id(), "SBO:0000013");
+        assert_eq!(
+            modifier_reference.role(),
+            Some(crate::role::Role::Catalysis)
+        );
+    }
 }
@@ -10,16 +10,24 @@ use crate::namespaces::SBMLNamespaces;
 /// SBML packages extend the core SBML functionality with domain-specific features.
 /// Currently supported packages:
 /// - FBC (Flux Balance Constraints) - for constraint-based modeling
+/// - Groups - for grouping model elements, e.g. into subsystems
+/// - Comp (Hierarchical Model Composition) - for composing models from submodels
 #[derive(Debug, Clone, Copy)]
 pub enum Package {
     /// Flux Balance Constraints package with specified version
     Fbc(u32),
+    /// Groups package with specified version
+    Groups(u32),
+    /// Hierarchical Model Composition package with specified version
+    Comp(u32),
 }
 
 impl From<Package> for PackageSpec {
     fn from(package: Package) -> Self {
         match package {
             Package::Fbc(version) => PackageSpec::new("fbc", version, "fbc"),
+            Package::Groups(version) => PackageSpec::new("groups", version, "groups"),
+            Package::Comp(version) => PackageSpec::new("comp", version, "comp"),
         }
     }
 }
@@ -90,6 +98,14 @@ mod tests {
         assert_eq!(package_spec.version, 1);
     }
 
+    #[test]
+    fn test_groups_package_spec_into() {
+        let package_spec: PackageSpec = Package::Groups(1).into();
+        assert_eq!(package_spec.name, "groups");
+        assert_eq!(package_spec.version, 1);
+        assert_eq!(package_spec.prefix, "groups");
+    }
+
     #[test]
     fn test_package_spec_add_to_namespace() {
         let mut namespaces = SBMLNamespaces::new(3, 2);
@@ -97,4 +113,12 @@ mod tests {
         package.add_to_namespace(&mut namespaces);
         assert_eq!(namespaces.package_name(), "core");
     }
+
+    #[test]
+    fn test_comp_package_spec_into() {
+        let package_spec: PackageSpec = Package::Comp(1).into();
+        assert_eq!(package_spec.name, "comp");
+        assert_eq!(package_spec.version, 1);
+        assert_eq!(package_spec.prefix, "comp");
+    }
 }
@@ -13,13 +13,15 @@ use std::{cell::RefCell, pin::Pin, rc::Rc};
 use cxx::let_cxx_string;
 
 use crate::{
-    clone, get_unit_definition, inner, into_id,
+    clone, derived_units,
+    errors::LibSBMLError,
+    get_unit_definition, inner, into_id,
     model::Model,
     optional_property, pin_ptr, required_property, sbase,
     sbmlcxx::{self},
     sbo_term,
     traits::{fromptr::FromPtr, intoid::IntoId, sbase::SBase},
-    upcast_annotation,
+    upcast_annotation, upcast_notes,
 };
 
 /// A safe wrapper around the libSBML Parameter class.
@@ -39,6 +41,9 @@ sbase!(Parameter<'a>, sbmlcxx::Parameter);
 // Set the annotation trait for the Parameter struct
 upcast_annotation!(Parameter<'a>, sbmlcxx::Parameter, sbmlcxx::SBase);
 
+// Set the notes trait for the Parameter struct
+upcast_notes!(Parameter<'a>, sbmlcxx::Parameter, sbmlcxx::SBase);
+
 // Implement the Clone trait for the Parameter struct
 clone!(Parameter<'a>, sbmlcxx::Parameter);
 
@@ -73,6 +78,26 @@ impl<'a> Parameter<'a> {
     // Gets the unit definition for the parameter
     get_unit_definition!(units);
 
+    // Gets the units derived for the parameter
+    derived_units!();
+
+    /// Renames this parameter and updates all references to it within the model.
+    ///
+    /// This is a convenience wrapper around [`Model::rename_sid`].
+    ///
+    /// # Arguments
+    /// * `model` - The model containing this parameter
+    /// * `new_id` - The new identifier of the parameter
+    ///
+    /// # Returns
+    /// The number of references that have been updated
+    ///
+    /// # Errors
+    /// Returns [`LibSBMLError::DuplicateId`] if `new_id` is already in use
+    pub fn rename(&self, model: &Model<'_>, new_id: &str) -> Result<usize, LibSBMLError> {
+        model.rename_sid(&self.id(), new_id)
+    }
+
     // Getter and setter for id
     required_property!(Parameter<'a>, id, String, getId, setId);
 
@@ -119,6 +144,20 @@ impl std::fmt::Debug for Parameter<'_> {
     }
 }
 
+/// Compares two parameters by their SBML content.
+///
+/// Parameters are equal if their identifier, name, value, units and constant
+/// attributes are equal.
+impl PartialEq for Parameter<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id() == other.id()
+            && self.name() == other.name()
+            && self.value() == other.value()
+            && self.units() == other.units()
+            && self.constant() == other.constant()
+    }
+}
+
 impl FromPtr<sbmlcxx::Parameter> for Parameter<'_> {
     /// Creates a new Parameter instance from a unique pointer to a libSBML Parameter.
     ///
@@ -184,6 +223,21 @@ impl<'a> ParameterBuilder<'a> {
         self
     }
 
+    /// Sets the value and units of this parameter and marks it as constant.
+    ///
+    /// This is a shorthand for the common case of a fixed quantity such as a rate
+    /// constant, equivalent to calling `value`, `units` and `constant(true)`.
+    ///
+    /// # Arguments
+    /// * `value` - The value to set
+    /// * `units` - The units to set
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    pub fn value_with_units(self, value: f64, units: impl IntoId) -> Self {
+        self.value(value).units(units).constant(true)
+    }
+
     /// Sets whether this parameter is constant.
     ///
     /// # Arguments
@@ -393,4 +447,79 @@ mod tests {
         assert_eq!(unit_definition.units()[1].multiplier(), 1.0);
         assert_eq!(unit_definition.units()[1].offset(), 0.0);
     }
+
+    #[test]
+    fn test_parameter_eq() {
+        let doc = SBMLDocument::default();
+        let model = Model::new(&doc, "test");
+        let parameter = model
+            .build_parameter("k1")
+            .value(0.1)
+            .units("per_second")
+            .constant(true)
+            .build();
+
+        let clone = (*parameter).clone();
+        assert_eq!(*parameter, clone);
+
+        clone.set_name("Rate constant");
+        assert_ne!(*parameter, clone);
+    }
+
+    #[test]
+    fn test_parameter_derived_units() {
+        let doc = SBMLDocument::default();
+        let model = Model::new(&doc, "test");
+
+        model
+            .build_unit_definition("per_second", "per second")
+            .unit(UnitKind::Second, Some(-1), None, None, None)
+            .build();
+        let parameter = model
+            .build_parameter("k")
+            .units("per_second")
+            .value(0.1)
+            .build();
+
+        let derived = parameter.derived_units().expect("Failed to derive units");
+        let units = derived.units();
+
+        assert_eq!(units.len(), 1);
+        assert_eq!(units[0].kind(), UnitKind::Second);
+        assert_eq!(units[0].exponent(), -1);
+    }
+
+    #[test]
+    fn test_parameter_builder_value_with_units() {
+        let doc = SBMLDocument::default();
+        let model = Model::new(&doc, "test");
+        let parameter = model
+            .build_parameter("k")
+            .value_with_units(0.1, "per_second")
+            .build();
+
+        assert_eq!(parameter.value(), Some(0.1));
+        assert_eq!(parameter.units(), Some("per_second".to_string()));
+        assert_eq!(parameter.constant(), Some(true));
+    }
+
+    #[test]
+    fn test_parameter_serialized_attributes() {
+        let doc = SBMLDocument::default();
+        let model = Model::new(&doc, "test");
+        model
+            .build_parameter("k")
+            .value(0.1)
+            .units("per_second")
+            .constant(true)
+            .build();
+
+        let xml = doc.to_xml_string();
+        assert!(xml.contains(r#"id="k""#));
+        assert!(xml.contains(r#"value="0.1""#));
+        assert!(xml.contains(r#"units="per_second""#));
+        assert!(xml.contains(r#"constant="true""#));
+
+        assert_eq!(model.get_parameter("k").unwrap().value(), Some(0.1));
+    }
 }
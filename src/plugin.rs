@@ -7,7 +7,10 @@ use std::pin::Pin;
 
 use cxx::let_cxx_string;
 
-use crate::{errors::LibSBMLError, pin_ptr, sbmlcxx, traits::sbase::SBase, upcast_pin};
+use crate::{
+    cast::upcast, errors::LibSBMLError, pin_ptr, sbmlcxx, sbmldoc::SBMLDocument,
+    traits::sbase::SBase, upcast_pin,
+};
 
 /// Retrieves a plugin from an SBML object by name and casts it to the specified type.
 ///
@@ -50,3 +53,47 @@ where
     // Downcast the plugin to the desired type
     Ok(upcast_pin!(plugin, sbmlcxx::SBasePlugin, T))
 }
+
+/// Returns the package version of the given plugin (e.g. 2 for FBC version 2).
+///
+/// # Type Parameters
+/// * `T` - The plugin type, deriving from `SBasePlugin`
+///
+/// # Arguments
+/// * `plugin` - The plugin to get the package version of
+pub(crate) fn package_version<T>(plugin: &mut Pin<&mut T>) -> u32 {
+    let base = upcast_pin!(plugin, T, sbmlcxx::SBasePlugin);
+    base.getPackageVersion().0
+}
+
+/// Retrieves a plugin from an SBML document by name and casts it to the specified type.
+///
+/// Documents are not wrapped like the other SBML elements, so [`get_plugin`] cannot be
+/// used for document plugins such as `CompSBMLDocumentPlugin`.
+///
+/// # Arguments
+/// * `document` - The SBML document to get the plugin from
+/// * `plugin_name` - The name of the plugin to retrieve (e.g., "comp")
+///
+/// # Errors
+/// * `LibSBMLError::PluginNotFound` - If the requested plugin is not available
+pub(crate) fn get_document_plugin<'a, T>(
+    document: &'a SBMLDocument,
+    plugin_name: &str,
+) -> Result<Pin<&'a mut T>, LibSBMLError> {
+    let_cxx_string!(pkg = plugin_name);
+
+    let base = unsafe {
+        upcast::<sbmlcxx::SBMLDocument, sbmlcxx::SBase>(document.inner().borrow_mut().as_mut_ptr())
+    };
+
+    let plugin_ptr = base.getPlugin(&pkg);
+
+    if plugin_ptr.is_null() {
+        return Err(LibSBMLError::PluginNotFound(plugin_name.to_string()));
+    }
+
+    let mut plugin = pin_ptr!(plugin_ptr, sbmlcxx::SBasePlugin);
+
+    Ok(upcast_pin!(plugin, sbmlcxx::SBasePlugin, T))
+}
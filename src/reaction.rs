@@ -8,24 +8,114 @@
 //! This wrapper provides safe access to the underlying C++ libSBML Reaction class while
 //! maintaining Rust's safety guarantees through the use of RefCell and Pin.
 
-use std::{cell::RefCell, pin::Pin, rc::Rc};
+use std::{cell::RefCell, collections::HashMap, pin::Pin, rc::Rc};
 
 use cxx::let_cxx_string;
 
 use crate::{
-    clone, inner, into_id,
+    clone,
+    collections::{ListOfProducts, ListOfReactants},
+    errors::LibSBMLError,
+    fbc::geneproductassociation::GeneProductAssociation,
+    inner, into_id,
+    math::MathNode,
     model::Model,
     modref::{ModifierSpeciesReference, ModifierSpeciesReferenceBuilder},
     optional_property, pin_ptr,
+    plugin::get_plugin,
     prelude::{IntoId, KineticLaw},
-    required_property, sbase,
+    required_property,
+    role::Role,
+    sbase,
     sbmlcxx::{self},
-    sbo_term,
+    sbo_term, set_collection_annotation,
     speciesref::{SpeciesReference, SpeciesReferenceBuilder, SpeciesReferenceType},
-    traits::fromptr::FromPtr,
-    upcast_annotation,
+    traits::{fromptr::FromPtr, sbase::SBase},
+    upcast_annotation, upcast_notes,
 };
 
+/// A participant of a reaction, tagged by its role.
+///
+/// Provides a uniform view over reactants, products and modifiers, see
+/// [`Reaction::participants`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Participant {
+    /// A species consumed by the reaction
+    Reactant { species: String, stoichiometry: f64 },
+    /// A species produced by the reaction
+    Product { species: String, stoichiometry: f64 },
+    /// A species influencing the reaction without being consumed or produced
+    Modifier { species: String },
+}
+
+impl Participant {
+    /// Returns the id of the participating species.
+    pub fn species(&self) -> &str {
+        match self {
+            Participant::Reactant { species, .. }
+            | Participant::Product { species, .. }
+            | Participant::Modifier { species } => species,
+        }
+    }
+
+    /// Returns the stoichiometry of the participant, or None for modifiers.
+    pub fn stoichiometry(&self) -> Option<f64> {
+        match self {
+            Participant::Reactant { stoichiometry, .. }
+            | Participant::Product { stoichiometry, .. } => Some(*stoichiometry),
+            Participant::Modifier { .. } => None,
+        }
+    }
+}
+
+/// The class of an arc connecting a species to a process in SBGN process descriptions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ArcClass {
+    /// The species is consumed by the process
+    Consumption,
+    /// The species is produced by the process
+    Production,
+    /// The species influences the process in an unspecified way
+    Modulation,
+    /// The species catalyses the process
+    Catalysis,
+    /// The species inhibits the process
+    Inhibition,
+    /// The species stimulates the process
+    Stimulation,
+}
+
+/// An arc connecting a species to a process node.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProcessArc {
+    /// The id of the connected species
+    pub species: String,
+    /// The class of the arc
+    pub class: ArcClass,
+    /// The stoichiometry of the species, or None for modulating arcs
+    pub stoichiometry: Option<f64>,
+}
+
+/// A reaction viewed as a process node of an SBGN process description.
+///
+/// This is a plain Rust structure for diagram renderers, created by
+/// [`Reaction::to_process_node`]. It does not add anything to the SBML model.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProcessNode {
+    /// The id of the reaction
+    pub id: String,
+    /// The label of the process, taken from the reaction name
+    pub label: Option<String>,
+    /// Whether the process is reversible
+    pub reversible: bool,
+    /// Consumption arcs of the reactants
+    pub inputs: Vec<ProcessArc>,
+    /// Production arcs of the products
+    pub outputs: Vec<ProcessArc>,
+    /// Modulating arcs of the modifiers
+    pub modifiers: Vec<ProcessArc>,
+}
+
 /// A safe wrapper around the libSBML Reaction class.
 ///
 /// This struct maintains a reference to the underlying C++ Reaction object
@@ -47,6 +137,9 @@ sbase!(Reaction<'a>, sbmlcxx::Reaction);
 // Set the annotation trait for the Reaction struct
 upcast_annotation!(Reaction<'a>, sbmlcxx::Reaction, sbmlcxx::SBase);
 
+// Set the notes trait for the Reaction struct
+upcast_notes!(Reaction<'a>, sbmlcxx::Reaction, sbmlcxx::SBase);
+
 // Set the into_id trait for the Reaction struct
 into_id!(&Rc<Reaction<'_>>, id);
 
@@ -266,6 +359,27 @@ impl<'a> Reaction<'a> {
             .map(Rc::clone)
     }
 
+    /// Returns the number of reactants of this reaction.
+    ///
+    /// The count is taken from libSBML directly and does not borrow the reactant list.
+    pub fn num_reactants(&self) -> u32 {
+        self.inner.borrow().getNumReactants().0
+    }
+
+    /// Returns the number of products of this reaction.
+    ///
+    /// The count is taken from libSBML directly and does not borrow the product list.
+    pub fn num_products(&self) -> u32 {
+        self.inner.borrow().getNumProducts().0
+    }
+
+    /// Returns the number of modifiers of this reaction.
+    ///
+    /// The count is taken from libSBML directly and does not borrow the modifier list.
+    pub fn num_modifiers(&self) -> u32 {
+        self.inner.borrow().getNumModifiers().0
+    }
+
     /// Creates a new kinetic law for this reaction.
     ///
     /// # Arguments
@@ -277,6 +391,21 @@ impl<'a> Reaction<'a> {
         Rc::new(KineticLaw::new(self, formula))
     }
 
+    /// Creates a new kinetic law for this reaction from an expression tree.
+    ///
+    /// # Arguments
+    /// * `math` - The rate expression (e.g. `MathNode::times([...])`)
+    ///
+    /// # Returns
+    /// A reference-counted pointer to the new KineticLaw, or an error if the
+    /// expression cannot be converted
+    pub fn create_kinetic_law_from_ast(
+        &self,
+        math: &MathNode,
+    ) -> Result<Rc<KineticLaw<'a>>, LibSBMLError> {
+        Ok(Rc::new(KineticLaw::from_math(self, math)?))
+    }
+
     /// Returns a reference to the kinetic law of this reaction.
     ///
     /// # Returns
@@ -291,6 +420,315 @@ impl<'a> Reaction<'a> {
         }
     }
 
+    /// Creates a new gene product association (GPR rule) for this reaction.
+    ///
+    /// The association is stored in the FBC reaction plugin and replaces any
+    /// existing association. This requires version 2 of the FBC package.
+    ///
+    /// # Arguments
+    /// * `association` - The GPR rule referencing gene products by id (e.g. "g1 and g2")
+    ///
+    /// # Returns
+    /// A reference-counted pointer to the new GeneProductAssociation, or an error if
+    /// the FBC plugin is unavailable or the association could not be parsed
+    pub fn create_gene_product_association(
+        &self,
+        association: &str,
+    ) -> Result<Rc<GeneProductAssociation<'a>>, LibSBMLError> {
+        Ok(Rc::new(GeneProductAssociation::new(self, association)?))
+    }
+
+    /// Returns the gene product association (GPR rule) of this reaction.
+    ///
+    /// # Returns
+    /// The GeneProductAssociation if the FBC plugin is available and an association
+    /// is set, otherwise None
+    pub fn gene_product_association(&self) -> Option<Rc<GeneProductAssociation<'a>>> {
+        let mut fbc_plugin =
+            get_plugin::<sbmlcxx::FbcReactionPlugin, Reaction<'a>, sbmlcxx::Reaction>(self, "fbc")
+                .ok()?;
+
+        if fbc_plugin.isSetGeneProductAssociation() {
+            let gpa = fbc_plugin.as_mut().getGeneProductAssociation1();
+            Some(Rc::new(GeneProductAssociation::from_ptr(gpa)))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the identifier of the parameter holding the lower flux bound.
+    ///
+    /// # Returns
+    /// The parameter id, or None if not set or the FBC plugin is not available
+    pub fn lower_flux_bound(&self) -> Option<String> {
+        let fbc_plugin =
+            get_plugin::<sbmlcxx::FbcReactionPlugin, Reaction<'a>, sbmlcxx::Reaction>(self, "fbc")
+                .ok()?;
+
+        if fbc_plugin.isSetLowerFluxBound() {
+            Some(fbc_plugin.getLowerFluxBound().to_str().unwrap().to_string())
+        } else {
+            None
+        }
+    }
+
+    /// Sets the parameter holding the lower flux bound of this reaction.
+    ///
+    /// # Arguments
+    /// * `parameter` - The identifier of the bound parameter
+    ///
+    /// # Returns
+    /// `Ok(())` if the bound has been set, or an error if the FBC plugin is not available
+    pub fn set_lower_flux_bound(&self, parameter: impl IntoId) -> Result<(), LibSBMLError> {
+        let mut fbc_plugin =
+            get_plugin::<sbmlcxx::FbcReactionPlugin, Reaction<'a>, sbmlcxx::Reaction>(self, "fbc")?;
+
+        let_cxx_string!(parameter = parameter.into_id());
+        fbc_plugin.as_mut().setLowerFluxBound(&parameter);
+        Ok(())
+    }
+
+    /// Returns the identifier of the parameter holding the upper flux bound.
+    ///
+    /// # Returns
+    /// The parameter id, or None if not set or the FBC plugin is not available
+    pub fn upper_flux_bound(&self) -> Option<String> {
+        let fbc_plugin =
+            get_plugin::<sbmlcxx::FbcReactionPlugin, Reaction<'a>, sbmlcxx::Reaction>(self, "fbc")
+                .ok()?;
+
+        if fbc_plugin.isSetUpperFluxBound() {
+            Some(fbc_plugin.getUpperFluxBound().to_str().unwrap().to_string())
+        } else {
+            None
+        }
+    }
+
+    /// Sets the parameter holding the upper flux bound of this reaction.
+    ///
+    /// # Arguments
+    /// * `parameter` - The identifier of the bound parameter
+    ///
+    /// # Returns
+    /// `Ok(())` if the bound has been set, or an error if the FBC plugin is not available
+    pub fn set_upper_flux_bound(&self, parameter: impl IntoId) -> Result<(), LibSBMLError> {
+        let mut fbc_plugin =
+            get_plugin::<sbmlcxx::FbcReactionPlugin, Reaction<'a>, sbmlcxx::Reaction>(self, "fbc")?;
+
+        let_cxx_string!(parameter = parameter.into_id());
+        fbc_plugin.as_mut().setUpperFluxBound(&parameter);
+        Ok(())
+    }
+
+    /// Resolves the lower and upper flux bounds of this reaction.
+    ///
+    /// In FBC version 2, flux bounds are stored as references to parameters on the
+    /// reaction. This method looks up these parameters in the containing model and
+    /// returns their values.
+    ///
+    /// # Returns
+    /// A `(lower, upper)` pair, where each bound is None if it is not set, the
+    /// referenced parameter is missing or has no value, or the reaction is not part
+    /// of a model
+    pub fn flux_bounds(&self) -> (Option<f64>, Option<f64>) {
+        let model_ptr = self.base().getModel();
+        if model_ptr.is_null() {
+            return (None, None);
+        }
+
+        let mut model = pin_ptr!(model_ptr as *mut sbmlcxx::Model, sbmlcxx::Model);
+        let mut parameter_value = |id: String| {
+            let_cxx_string!(sid = id);
+            let parameter_ptr = model.as_mut().getParameter3(&sid);
+            if parameter_ptr.is_null() {
+                return None;
+            }
+
+            let parameter = pin_ptr!(parameter_ptr, sbmlcxx::Parameter);
+            parameter.isSetValue().then(|| parameter.getValue())
+        };
+
+        let lower = self.lower_flux_bound().and_then(&mut parameter_value);
+        let upper = self.upper_flux_bound().and_then(&mut parameter_value);
+
+        (lower, upper)
+    }
+
+    /// Returns all participants of this reaction in a unified view.
+    ///
+    /// Reactants are listed first, followed by products and modifiers, each in
+    /// the order they are defined in the reaction.
+    ///
+    /// # Returns
+    /// A vector of participants tagged by their role
+    pub fn participants(&self) -> Vec<Participant> {
+        let reactants = self
+            .reactants
+            .borrow()
+            .iter()
+            .map(|reactant| Participant::Reactant {
+                species: reactant.species(),
+                stoichiometry: reactant.stoichiometry(),
+            })
+            .collect::<Vec<_>>();
+
+        let products = self
+            .products
+            .borrow()
+            .iter()
+            .map(|product| Participant::Product {
+                species: product.species(),
+                stoichiometry: product.stoichiometry(),
+            })
+            .collect::<Vec<_>>();
+
+        let modifiers = self
+            .modifiers
+            .borrow()
+            .iter()
+            .map(|modifier| Participant::Modifier {
+                species: modifier.species(),
+            })
+            .collect::<Vec<_>>();
+
+        reactants
+            .into_iter()
+            .chain(products)
+            .chain(modifiers)
+            .collect()
+    }
+
+    /// Converts this reaction into an SBGN process node.
+    ///
+    /// Reactants become consumption arcs and products production arcs. The arc class
+    /// of a modifier is derived from its role (e.g. a modifier with the role
+    /// [`Role::Inhibition`] becomes an inhibition arc), falling back to a generic
+    /// modulation. A reaction without a `reversible` attribute is treated as irreversible.
+    ///
+    /// # Returns
+    /// The process node describing this reaction
+    pub fn to_process_node(&self) -> ProcessNode {
+        let inputs = self
+            .reactants
+            .borrow()
+            .iter()
+            .map(|reactant| ProcessArc {
+                species: reactant.species(),
+                class: ArcClass::Consumption,
+                stoichiometry: Some(reactant.stoichiometry()),
+            })
+            .collect();
+
+        let outputs = self
+            .products
+            .borrow()
+            .iter()
+            .map(|product| ProcessArc {
+                species: product.species(),
+                class: ArcClass::Production,
+                stoichiometry: Some(product.stoichiometry()),
+            })
+            .collect();
+
+        let modifiers = self
+            .modifiers
+            .borrow()
+            .iter()
+            .map(|modifier| ProcessArc {
+                species: modifier.species(),
+                class: match modifier.role() {
+                    Some(Role::Catalysis) => ArcClass::Catalysis,
+                    Some(Role::Inhibition) => ArcClass::Inhibition,
+                    Some(Role::Stimulation) => ArcClass::Stimulation,
+                    _ => ArcClass::Modulation,
+                },
+                stoichiometry: None,
+            })
+            .collect();
+
+        ProcessNode {
+            id: self.id(),
+            label: self.name(),
+            reversible: self.reversible().unwrap_or(false),
+            inputs,
+            outputs,
+            modifiers,
+        }
+    }
+
+    /// Returns the net stoichiometry of each species participating in this reaction.
+    ///
+    /// Products contribute positively and reactants negatively. Species that appear
+    /// on both sides (e.g. a regenerated cofactor) are netted out, so balanced species
+    /// are reported with a net stoichiometry of zero.
+    ///
+    /// # Returns
+    /// A map from species identifiers to their net stoichiometric coefficient
+    pub fn net_stoichiometry(&self) -> HashMap<String, f64> {
+        let mut net = HashMap::new();
+
+        for reactant in self.reactants.borrow().iter() {
+            *net.entry(reactant.species()).or_insert(0.0) -= reactant.stoichiometry();
+        }
+
+        for product in self.products.borrow().iter() {
+            *net.entry(product.species()).or_insert(0.0) += product.stoichiometry();
+        }
+
+        net
+    }
+
+    /// Deep-copies this reaction into another model.
+    ///
+    /// The copy includes all reactants, products, modifiers and the kinetic law
+    /// of this reaction. Referenced species and parameters are not copied and
+    /// have to be present in the target model for it to be valid.
+    ///
+    /// # Arguments
+    /// * `target` - The model to copy the reaction into
+    ///
+    /// # Returns
+    /// A reference-counted pointer to the copied Reaction within the target model
+    ///
+    /// # Errors
+    /// Returns `LibSBMLError` if libSBML rejects the copy, e.g. because the target
+    /// model already contains an element with the same id or uses a different SBML
+    /// level, version or namespaces
+    pub fn clone_into<'b>(&self, target: &Model<'b>) -> Result<Rc<Reaction<'b>>, LibSBMLError> {
+        let id = self.id();
+        let source: *const sbmlcxx::Reaction = &**self.inner.borrow();
+        let result = unsafe { target.inner().borrow_mut().as_mut().addReaction(source) };
+
+        // LIBSBML_OPERATION_SUCCESS
+        if result.0 != 0 {
+            return Err(LibSBMLError::InvalidArgument(format!(
+                "Failed to copy reaction '{id}' (libSBML code {})",
+                result.0
+            )));
+        }
+
+        let_cxx_string!(sid = &id);
+        let reaction_ptr = target.inner().borrow_mut().as_mut().getReaction3(&sid);
+
+        if reaction_ptr.is_null() {
+            return Err(LibSBMLError::InvalidArgument(format!(
+                "Copied reaction '{id}' not found in the target model"
+            )));
+        }
+
+        let reaction = Rc::new(Reaction::from_ptr(reaction_ptr));
+        target
+            .list_of_reactions
+            .borrow_mut()
+            .push(Rc::clone(&reaction));
+
+        Ok(reaction)
+    }
+
+    // Implement the set_annotation method for the reactant and product lists
+    set_collection_annotation!(Reaction<'a>, "reactants", ListOfReactants);
+    set_collection_annotation!(Reaction<'a>, "products", ListOfProducts);
+
     // SBO Term Methods generated by the `sbo_term` macro
     sbo_term!(sbmlcxx::Reaction, sbmlcxx::SBase);
 }
@@ -355,10 +793,60 @@ impl std::fmt::Debug for Reaction<'_> {
         ds.field("reactants", &self.reactants());
         ds.field("products", &self.products());
         ds.field("modifiers", &self.modifiers());
+        ds.field(
+            "kinetic_law",
+            &self.kinetic_law().map(|kinetic_law| kinetic_law.formula()),
+        );
         ds.finish()
     }
 }
 
+/// Compares two reactions by their SBML content.
+///
+/// Reactions are equal if their identifier, name, reversible and compartment
+/// attributes and their kinetic law formulas are equal, and they have the same
+/// reactants, products and modifiers. Participants are compared independent of
+/// their order, using their species and stoichiometry.
+impl PartialEq for Reaction<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        let sorted_references = |references: &RefCell<Vec<Rc<SpeciesReference>>>| {
+            let mut references = references
+                .borrow()
+                .iter()
+                .map(|reference| (reference.species(), reference.stoichiometry()))
+                .collect::<Vec<_>>();
+            references.sort_by(|a, b| a.0.cmp(&b.0).then(a.1.total_cmp(&b.1)));
+            references
+        };
+
+        let sorted_modifiers = |reaction: &Reaction| {
+            let mut modifiers = reaction
+                .modifiers()
+                .borrow()
+                .iter()
+                .map(|modifier| modifier.species())
+                .collect::<Vec<_>>();
+            modifiers.sort();
+            modifiers
+        };
+
+        let formula = |reaction: &Reaction| {
+            reaction
+                .kinetic_law()
+                .map(|kinetic_law| kinetic_law.formula())
+        };
+
+        self.id() == other.id()
+            && self.name() == other.name()
+            && self.reversible() == other.reversible()
+            && self.compartment() == other.compartment()
+            && sorted_references(self.reactants()) == sorted_references(other.reactants())
+            && sorted_references(self.products()) == sorted_references(other.products())
+            && sorted_modifiers(self) == sorted_modifiers(other)
+            && formula(self) == formula(other)
+    }
+}
+
 /// A builder for creating Reaction instances with a fluent interface.
 pub struct ReactionBuilder<'a> {
     reaction: Rc<Reaction<'a>>,
@@ -683,4 +1171,339 @@ mod tests {
         let annotation = reaction.get_annotation_serde::<TestAnnotation>().unwrap();
         assert_eq!(annotation.test, "test");
     }
+
+    #[test]
+    fn test_reaction_clone_into() {
+        let source_doc = SBMLDocument::default();
+        let source = Model::new(&source_doc, "source");
+        let reaction = source
+            .build_reaction("r1")
+            .name("Reaction 1")
+            .reactant("s1", 1.0)
+            .product("s2", 2.0)
+            .build();
+        reaction.create_kinetic_law("k1 * s1");
+
+        let target_doc = SBMLDocument::default();
+        let target = Model::new(&target_doc, "target");
+        let copy = reaction
+            .clone_into(&target)
+            .expect("Failed to copy reaction");
+
+        assert_eq!(copy.id(), "r1");
+        assert_eq!(copy.name(), Some("Reaction 1".to_string()));
+        assert_eq!(copy.reactants().borrow().len(), 1);
+        assert_eq!(copy.get_product("s2").unwrap().stoichiometry(), 2.0);
+        assert_eq!(copy.kinetic_law().unwrap().formula(), "k1 * s1");
+
+        // The copy is registered in the target model
+        assert!(target.get_reaction("r1").is_some());
+
+        // Modifying the copy does not affect the original
+        copy.set_name("Copy");
+        assert_eq!(reaction.name(), Some("Reaction 1".to_string()));
+    }
+
+    #[test]
+    fn test_reaction_clone_into_rejects_duplicates() {
+        let source_doc = SBMLDocument::default();
+        let source = Model::new(&source_doc, "source");
+        let reaction = source.create_reaction("r1");
+
+        let target_doc = SBMLDocument::default();
+        let target = Model::new(&target_doc, "target");
+        target.create_reaction("r1");
+
+        assert!(reaction.clone_into(&target).is_err());
+        assert_eq!(target.list_of_reactions().len(), 1);
+    }
+
+    #[test]
+    fn test_reaction_clone_into_version_mismatch() {
+        let source_doc = SBMLDocument::default();
+        let source = Model::new(&source_doc, "source");
+        let reaction = source.create_reaction("r1");
+
+        let target_doc = SBMLDocument::new(3, 1, None);
+        let target = Model::new(&target_doc, "target");
+
+        assert!(reaction.clone_into(&target).is_err());
+        assert!(target.get_reaction("r1").is_none());
+    }
+
+    #[test]
+    fn test_reaction_to_xml_fragment() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        let reaction = model.create_reaction("reaction");
+        reaction.set_name("Reaction");
+
+        let fragment = reaction.to_xml_fragment();
+        assert!(fragment.starts_with("<reaction"));
+        assert!(fragment.contains(r#"id="reaction""#));
+        assert!(!fragment.contains("<model"));
+    }
+
+    #[test]
+    fn test_reaction_net_stoichiometry() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        let reaction = model
+            .build_reaction("reaction")
+            .reactant("substrate", 1.0)
+            .reactant("atp", 1.0)
+            .product("product", 2.0)
+            .product("atp", 1.0)
+            .build();
+
+        let net = reaction.net_stoichiometry();
+
+        assert_eq!(net.len(), 3);
+        assert_eq!(net["substrate"], -1.0);
+        assert_eq!(net["product"], 2.0);
+        assert_eq!(net["atp"], 0.0);
+    }
+
+    #[test]
+    fn test_reaction_debug_kinetic_law() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        let reaction = model.create_reaction("reaction");
+
+        let debug = format!("{reaction:?}");
+        assert!(debug.contains("kinetic_law: None"));
+
+        reaction.create_kinetic_law("k1 * S");
+        let debug = format!("{reaction:?}");
+        assert!(debug.contains(r#"kinetic_law: Some("k1 * S")"#));
+    }
+
+    #[test]
+    fn test_reaction_participants() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        let reaction = model
+            .build_reaction("reaction")
+            .reactant("substrate", 1.0)
+            .product("product", 2.0)
+            .modifier("enzyme")
+            .build();
+
+        let participants = reaction.participants();
+
+        assert_eq!(
+            participants,
+            vec![
+                Participant::Reactant {
+                    species: "substrate".to_string(),
+                    stoichiometry: 1.0
+                },
+                Participant::Product {
+                    species: "product".to_string(),
+                    stoichiometry: 2.0
+                },
+                Participant::Modifier {
+                    species: "enzyme".to_string()
+                },
+            ]
+        );
+        assert_eq!(participants[2].species(), "enzyme");
+        assert_eq!(participants[2].stoichiometry(), None);
+        assert_eq!(participants[1].stoichiometry(), Some(2.0));
+    }
+
+    #[test]
+    fn test_reaction_flux_bounds() {
+        let doc = SBMLDocument::with_profile(Profile::Cobra);
+        let model = doc.create_model("test");
+        model
+            .build_parameter("lb")
+            .value(-10.0)
+            .constant(true)
+            .build();
+        model
+            .build_parameter("ub")
+            .value(1000.0)
+            .constant(true)
+            .build();
+
+        let reaction = model.create_reaction("r1");
+        assert_eq!(reaction.flux_bounds(), (None, None));
+
+        reaction.set_lower_flux_bound("lb").unwrap();
+        reaction.set_upper_flux_bound("ub").unwrap();
+
+        assert_eq!(reaction.lower_flux_bound(), Some("lb".to_string()));
+        assert_eq!(reaction.upper_flux_bound(), Some("ub".to_string()));
+        assert_eq!(reaction.flux_bounds(), (Some(-10.0), Some(1000.0)));
+
+        let doc = SBMLReader::from_xml_string(&doc.to_xml_string());
+        let reaction = doc.model().unwrap().get_reaction("r1").unwrap();
+        assert_eq!(reaction.flux_bounds(), (Some(-10.0), Some(1000.0)));
+
+        // A detached copy has no model to resolve the parameters in
+        let detached = reaction.clone();
+        assert_eq!(detached.lower_flux_bound(), Some("lb".to_string()));
+        assert_eq!(detached.flux_bounds(), (None, None));
+    }
+
+    #[test]
+    fn test_reaction_eq() {
+        fn build<'a>(doc: &'a SBMLDocument, reactants: &[(&str, f64)]) -> Rc<Reaction<'a>> {
+            let model = doc.create_model("test");
+            let mut builder = model.build_reaction("r1").product("p", 1.0).modifier("e");
+            for (species, stoichiometry) in reactants {
+                builder = builder.reactant(*species, *stoichiometry);
+            }
+            let reaction = builder.build();
+            reaction.create_kinetic_law("k1 * s1 * s2");
+            reaction
+        }
+
+        let (doc, reordered_doc, changed_doc) = (
+            SBMLDocument::default(),
+            SBMLDocument::default(),
+            SBMLDocument::default(),
+        );
+
+        // Participants are compared independent of their order
+        let reaction = build(&doc, &[("s1", 1.0), ("s2", 2.0)]);
+        let reordered = build(&reordered_doc, &[("s2", 2.0), ("s1", 1.0)]);
+        assert_eq!(reaction, reordered);
+
+        let changed = build(&changed_doc, &[("s1", 1.0), ("s2", 3.0)]);
+        assert_ne!(reaction, changed);
+
+        let clone = (*reaction).clone();
+        assert_eq!(*reaction, clone);
+        clone.set_reversible(true);
+        assert_ne!(*reaction, clone);
+    }
+
+    #[test]
+    fn test_reaction_set_role() {
+        let doc = SBMLDocument::default();
+        let model = Model::new(&doc, "test");
+        let reaction = Reaction::new(&model, "glc_transport");
+        reaction.set_role(Role::TransportReaction);
+
+        assert_eq!(reaction.sbo_term_id(), "SBO:0000185");
+        assert_eq!(reaction.role(), Some(Role::TransportReaction));
+    }
+
+    #[test]
+    fn test_reaction_notes() {
+        let doc = SBMLDocument::default();
+        let model = Model::new(&doc, "test");
+        let reaction = Reaction::new(&model, "hexokinase");
+        assert!(!reaction.is_set_notes());
+        assert!(reaction.notes().is_empty());
+
+        reaction
+            .set_notes("<p>Irreversible under physiological conditions</p>")
+            .unwrap();
+
+        assert!(reaction.is_set_notes());
+        let notes = reaction.notes();
+        assert!(notes.starts_with("<notes>"));
+        assert!(notes.contains("http://www.w3.org/1999/xhtml"));
+        assert!(notes.contains("Irreversible under physiological conditions"));
+
+        // Plain text is wrapped in a paragraph
+        reaction.set_notes("Catalysed by hexokinase").unwrap();
+        assert!(reaction.notes().contains("<p"));
+        assert!(reaction.notes().contains("Catalysed by hexokinase"));
+
+        // Notes survive a roundtrip through XML
+        let doc = SBMLReader::from_xml_string(&doc.to_xml_string());
+        let reaction = doc.model().unwrap().get_reaction("hexokinase").unwrap();
+        assert!(reaction.notes().contains("Catalysed by hexokinase"));
+    }
+
+    #[test]
+    fn test_reaction_participant_counts() {
+        let doc = SBMLDocument::default();
+        let model = Model::new(&doc, "test");
+        let reaction = model
+            .build_reaction("r1")
+            .reactant("atp", 1.0)
+            .reactant("glc", 1.0)
+            .product("adp", 1.0)
+            .product("g6p", 1.0)
+            .product("h", 1.0)
+            .modifier("hk")
+            .build();
+
+        assert_eq!(reaction.num_reactants(), 2);
+        assert_eq!(reaction.num_products(), 3);
+        assert_eq!(reaction.num_modifiers(), 1);
+        assert_eq!(
+            reaction.num_reactants() as usize,
+            reaction.reactants().borrow().len()
+        );
+        assert_eq!(
+            reaction.num_products() as usize,
+            reaction.products().borrow().len()
+        );
+        assert_eq!(
+            reaction.num_modifiers() as usize,
+            reaction.modifiers().borrow().len()
+        );
+    }
+
+    #[test]
+    fn test_reaction_to_process_node() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        let reaction = model
+            .build_reaction("hk")
+            .name("Hexokinase")
+            .reversible(false)
+            .reactant("glc", 1.0)
+            .reactant("atp", 1.0)
+            .product("g6p", 1.0)
+            .product("adp", 1.0)
+            .modifier("hexokinase")
+            .modifier("g6p_inhibitor")
+            .modifier("effector")
+            .build();
+
+        let modifiers = reaction.modifiers().borrow().clone();
+        modifiers[0].set_role(Role::Catalysis);
+        modifiers[1].set_role(Role::Inhibition);
+
+        let node = reaction.to_process_node();
+
+        assert_eq!(node.id, "hk");
+        assert_eq!(node.label, Some("Hexokinase".to_string()));
+        assert!(!node.reversible);
+
+        let inputs: Vec<_> = node.inputs.iter().map(|arc| arc.species.as_str()).collect();
+        let outputs: Vec<_> = node
+            .outputs
+            .iter()
+            .map(|arc| arc.species.as_str())
+            .collect();
+        assert_eq!(inputs, vec!["glc", "atp"]);
+        assert_eq!(outputs, vec!["g6p", "adp"]);
+        assert!(node
+            .inputs
+            .iter()
+            .all(|arc| arc.class == ArcClass::Consumption && arc.stoichiometry == Some(1.0)));
+        assert!(node
+            .outputs
+            .iter()
+            .all(|arc| arc.class == ArcClass::Production));
+
+        let classes: Vec<_> = node.modifiers.iter().map(|arc| arc.class).collect();
+        assert_eq!(
+            classes,
+            vec![
+                ArcClass::Catalysis,
+                ArcClass::Inhibition,
+                ArcClass::Modulation
+            ]
+        );
+        assert!(node.modifiers.iter().all(|arc| arc.stoichiometry.is_none()));
+    }
 }
@@ -7,12 +7,40 @@
 //! This wrapper provides safe access to the underlying C++ libSBML SBMLReader class while
 //! maintaining Rust's safety guarantees through the use of RefCell and Pin.
 
-use std::{cell::RefCell, pin::Pin};
+use std::{borrow::Cow, cell::RefCell, io::Read, path::Path, pin::Pin};
 
 use autocxx::WithinBox;
 use cxx::{let_cxx_string, UniquePtr};
+use flate2::read::GzDecoder;
 
-use crate::{sbmlcxx, sbmldoc::SBMLDocument};
+use crate::{
+    cast::upcast, errors::SBMLReadError, pin_const_ptr, pin_ptr, sbmlcxx, sbmldoc::SBMLDocument,
+    sbmlerror::SBMLErrorLog,
+};
+
+/// The magic number at the start of every gzip stream
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// The XML declaration assumed for content that does not declare itself
+const DEFAULT_XML_DECLARATION: &str = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n";
+
+/// Summary information about an SBML document.
+///
+/// Returned by [`SBMLReader::peek_metadata`] to catalog documents without
+/// wrapping their model components.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModelMetadata {
+    /// The id of the model, if the document contains a model with an id
+    pub id: Option<String>,
+    /// The name of the model, if the document contains a model with a name
+    pub name: Option<String>,
+    /// The SBML level of the document
+    pub level: u32,
+    /// The SBML version of the document
+    pub version: u32,
+    /// The names of the packages enabled in the document (e.g. "fbc")
+    pub packages: Vec<String>,
+}
 
 /// A safe wrapper around the libSBML SBMLReader class.
 ///
@@ -33,6 +61,10 @@ impl SBMLReader {
 
     /// Reads an SBML document from an XML string.
     ///
+    /// Content without an XML declaration (`<?xml ...?>`) is read as UTF-8.
+    /// Parse errors are not reported; malformed input yields a document without a
+    /// model. Use [`SBMLDocument::from_xml_string`] to receive them as an error.
+    ///
     /// # Arguments
     /// * `xml` - A string containing valid SBML XML
     ///
@@ -41,13 +73,256 @@ impl SBMLReader {
     pub fn from_xml_string(xml: &str) -> SBMLDocument {
         let reader = Self::new();
         // Create an owned String to ensure the data persists
-        let owned_xml = xml.to_string();
+        let owned_xml = with_xml_declaration(xml).into_owned();
         let_cxx_string!(xml_cxx = owned_xml);
         let ptr = unsafe {
             UniquePtr::from_raw(reader.0.borrow_mut().as_mut().readSBMLFromString(&xml_cxx))
         };
         SBMLDocument::from_unique_ptr(ptr)
     }
+
+    /// Reads the model id, name, level, version and packages of an SBML document.
+    ///
+    /// The document is parsed by libSBML, but none of its components are wrapped,
+    /// which keeps cataloging many documents cheap compared to [`SBMLDocument::model`].
+    ///
+    /// # Arguments
+    /// * `xml` - A string containing valid SBML XML
+    ///
+    /// # Returns
+    /// The [`ModelMetadata`] of the document
+    pub fn peek_metadata(xml: &str) -> ModelMetadata {
+        let doc = Self::from_xml_string(xml);
+
+        let (id, name) = {
+            let mut document = doc.inner().borrow_mut();
+            let mut document = document.pin_mut();
+
+            if document.isSetModel() {
+                let model_ptr = document.as_mut().getModel1();
+                let model = pin_ptr!(model_ptr, sbmlcxx::Model);
+
+                let non_empty = |value: &cxx::CxxString| {
+                    let value = value.to_str().unwrap();
+                    (!value.is_empty()).then(|| value.to_string())
+                };
+
+                (non_empty(model.getId()), non_empty(model.getName()))
+            } else {
+                (None, None)
+            }
+        };
+
+        ModelMetadata {
+            id,
+            name,
+            level: doc.level(),
+            version: doc.version(),
+            packages: doc.plugins(),
+        }
+    }
+
+    /// Reads an SBML document from a file.
+    ///
+    /// Gzip-compressed files (e.g. `model.xml.gz`) are detected by their `.gz`
+    /// extension or the gzip magic number and decompressed transparently.
+    ///
+    /// # Arguments
+    /// * `path` - Path to the SBML file to read
+    ///
+    /// # Returns
+    /// The parsed SBMLDocument, or an [`SBMLReadError`] if the file could not be read
+    /// or the document contains errors of severity Error or Fatal
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<SBMLDocument, SBMLReadError> {
+        let path = path.as_ref();
+        let bytes = std::fs::read(path)?;
+
+        let is_gzip = bytes.starts_with(&GZIP_MAGIC)
+            || path.extension().is_some_and(|extension| extension == "gz");
+
+        Self::read_bytes(bytes, is_gzip)
+    }
+
+    /// Reads an SBML document from raw bytes.
+    ///
+    /// The bytes are decoded as UTF-8. Gzip-compressed content is detected by the
+    /// gzip magic number and decompressed transparently.
+    ///
+    /// # Arguments
+    /// * `bytes` - The SBML content, e.g. the data of a COMBINE archive entry
+    ///
+    /// # Returns
+    /// The parsed SBMLDocument, or an [`SBMLReadError`] if the content is not valid
+    /// UTF-8 or the document contains errors of severity Error or Fatal
+    pub fn from_bytes(bytes: &[u8]) -> Result<SBMLDocument, SBMLReadError> {
+        Self::read_bytes(bytes.to_vec(), bytes.starts_with(&GZIP_MAGIC))
+    }
+
+    /// Reads an SBML document from any source that implements `Read`.
+    ///
+    /// The source is read to its end and decoded like [`SBMLReader::from_bytes`].
+    ///
+    /// # Arguments
+    /// * `reader` - The source of the SBML content
+    ///
+    /// # Returns
+    /// The parsed SBMLDocument, or an [`SBMLReadError`] if the source could not be
+    /// read, is not valid UTF-8 or the document contains errors of severity Error or Fatal
+    pub fn from_reader(mut reader: impl Read) -> Result<SBMLDocument, SBMLReadError> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+        Self::from_bytes(&bytes)
+    }
+
+    /// Decodes and parses the given bytes into a validated SBMLDocument.
+    ///
+    /// # Arguments
+    /// * `bytes` - The raw SBML content
+    /// * `is_gzip` - Whether the content is gzip-compressed
+    fn read_bytes(bytes: Vec<u8>, is_gzip: bool) -> Result<SBMLDocument, SBMLReadError> {
+        let bytes = if is_gzip {
+            let mut decompressed = Vec::new();
+            GzDecoder::new(bytes.as_slice()).read_to_end(&mut decompressed)?;
+            decompressed
+        } else {
+            bytes
+        };
+
+        let xml = String::from_utf8(bytes)?;
+        Self::from_xml_string_with_log(&xml).map(|(doc, _)| doc)
+    }
+
+    /// Reads an SBML document from an XML string and returns the parse-time log.
+    ///
+    /// Unlike [`SBMLReader::from_xml_string`], the messages libSBML reports while
+    /// reading are not discarded. Warnings (e.g. about unsupported packages) are
+    /// returned alongside the document, so importers can surface them to users.
+    ///
+    /// # Arguments
+    /// * `xml` - A string containing valid SBML XML
+    ///
+    /// # Returns
+    /// The parsed SBMLDocument together with its [`SBMLErrorLog`], or an
+    /// [`SBMLReadError`] if the document contains errors of severity Error or Fatal
+    pub fn from_xml_string_with_log(
+        xml: &str,
+    ) -> Result<(SBMLDocument, SBMLErrorLog), SBMLReadError> {
+        let doc = Self::from_xml_string(xml);
+
+        let log = SBMLErrorLog::new(&doc);
+        if log.valid {
+            Ok((doc, log))
+        } else {
+            Err(SBMLReadError::Invalid(log))
+        }
+    }
+
+    /// Reads an SBML document from an XML string and drops all package content.
+    ///
+    /// The document is parsed as usual, after which every package enabled in it
+    /// (e.g. fbc) is disabled on the document only. This yields a core-only document
+    /// when only core elements are of interest. The tradeoff is that package data is
+    /// not accessible on the returned document - for example,
+    /// [`Model::list_of_objectives`] is always empty.
+    ///
+    /// # Arguments
+    /// * `xml` - A string containing valid SBML XML
+    ///
+    /// # Returns
+    /// An SBMLDocument instance containing the parsed core model
+    ///
+    /// [`Model::list_of_objectives`]: crate::model::Model::list_of_objectives
+    pub fn from_xml_string_core_only(xml: &str) -> SBMLDocument {
+        let doc = Self::from_xml_string(xml);
+
+        {
+            let mut document = doc.inner().borrow_mut();
+            let mut base =
+                unsafe { upcast::<sbmlcxx::SBMLDocument, sbmlcxx::SBase>(document.as_mut_ptr()) };
+
+            // Disabling a package removes its plugin, so the packages are collected first
+            let n_plugins = base.getNumPlugins().0;
+            let packages = (0..n_plugins)
+                .map(|i| {
+                    let plugin_ptr = base.getPlugin3(i.into());
+                    let plugin = pin_const_ptr!(plugin_ptr, sbmlcxx::SBasePlugin);
+                    (plugin.getURI().to_string(), plugin.getPrefix().to_string())
+                })
+                .collect::<Vec<_>>();
+
+            // Only affects this document and its elements, not the extension registry
+            for (uri, prefix) in packages {
+                let_cxx_string!(uri = uri);
+                let_cxx_string!(prefix = prefix);
+                base.as_mut().disablePackage(&uri, &prefix);
+            }
+        }
+
+        doc
+    }
+
+    /// Reads multiple SBML documents from a concatenated XML string.
+    ///
+    /// While not standard, some pipelines concatenate several SBML documents into a
+    /// single stream. This method splits the input at every XML declaration and
+    /// parses each part as a separate document. Processing instructions such as
+    /// `<?xml-stylesheet ...?>` are not declarations and stay within their document.
+    ///
+    /// # Arguments
+    /// * `xml` - A string containing one or more concatenated SBML documents
+    ///
+    /// # Returns
+    /// A vector with one result per document, either the parsed SBMLDocument or the
+    /// [`SBMLErrorLog`] of the document if it contained read errors
+    pub fn from_multi_xml(xml: &str) -> Vec<Result<SBMLDocument, SBMLErrorLog>> {
+        // An XML declaration is `<?xml` followed by whitespace, unlike e.g. `<?xml-model`
+        let mut starts = xml
+            .match_indices("<?xml")
+            .map(|(i, _)| i)
+            .filter(|i| xml[i + "<?xml".len()..].starts_with(|c: char| c.is_ascii_whitespace()))
+            .collect::<Vec<_>>();
+
+        // Content without an XML declaration is treated as a single document
+        if starts.first() != Some(&0) {
+            starts.insert(0, 0);
+        }
+
+        starts
+            .iter()
+            .enumerate()
+            .map(|(i, start)| &xml[*start..starts.get(i + 1).copied().unwrap_or(xml.len())])
+            .filter(|part| !part.trim().is_empty())
+            .map(|part| {
+                let doc = Self::from_xml_string(part);
+                let log = SBMLErrorLog::new(&doc);
+                if log.valid {
+                    Ok(doc)
+                } else {
+                    Err(log)
+                }
+            })
+            .collect()
+    }
+}
+
+/// Prepends an XML declaration to content that lacks one.
+///
+/// A leading byte order mark and whitespace are removed, as neither is allowed
+/// before an XML declaration.
+///
+/// # Arguments
+/// * `xml` - The XML content
+///
+/// # Returns
+/// The content starting with an XML declaration
+fn with_xml_declaration(xml: &str) -> Cow<'_, str> {
+    let content = xml.trim_start_matches('\u{feff}').trim_start();
+
+    if content.starts_with("<?xml") {
+        Cow::Borrowed(content)
+    } else {
+        Cow::Owned(format!("{DEFAULT_XML_DECLARATION}{content}"))
+    }
 }
 
 impl Default for SBMLReader {
@@ -60,7 +335,7 @@ impl Default for SBMLReader {
 mod tests {
     use std::path::PathBuf;
 
-    use crate::errors::LibSBMLError;
+    use crate::{errors::LibSBMLError, fbc::ObjectiveType};
 
     use super::*;
 
@@ -93,6 +368,25 @@ mod tests {
         assert_eq!(list_of_parameters.len(), 0);
     }
 
+    #[test]
+    fn test_read_sbml_string_without_declaration() {
+        let xml = r#"
+<sbml xmlns="http://www.sbml.org/sbml/level3/version2/core" level="3" version="2">
+  <model id="no_declaration">
+    <listOfCompartments>
+      <compartment id="cytosol" constant="true"/>
+    </listOfCompartments>
+  </model>
+</sbml>"#;
+
+        let doc = SBMLReader::from_xml_string(xml);
+        assert!(SBMLErrorLog::new(&doc).valid);
+
+        let model = doc.model().expect("Model not found");
+        assert_eq!(model.id(), "no_declaration");
+        assert_eq!(model.list_of_compartments().len(), 1);
+    }
+
     #[test]
     fn test_read_sbml_file_rules_only() {
         // This test uses an "external" function to ensure that returning an SBMLDocument
@@ -130,8 +424,188 @@ mod tests {
         assert_eq!(list_of_assignment_rules.len(), 0);
     }
 
+    #[test]
+    fn test_read_multi_xml() {
+        let xml = format!(
+            "{}\n{}",
+            include_str!("../tests/data/example.xml"),
+            include_str!("../tests/data/odes_example_test.xml")
+        );
+
+        let docs = SBMLReader::from_multi_xml(&xml);
+        assert_eq!(docs.len(), 2);
+
+        let first = docs[0].as_ref().expect("First document should be valid");
+        assert_eq!(first.model().expect("Model not found").id(), "example");
+
+        let second = docs[1].as_ref().expect("Second document should be valid");
+        let model = second.model().expect("Model not found");
+        assert_eq!(model.list_of_species().len(), 4);
+    }
+
+    #[test]
+    fn test_read_multi_xml_processing_instructions() {
+        let first = include_str!("../tests/data/example.xml").replacen(
+            "?>",
+            "?>\n<?xml-stylesheet type=\"text/xsl\" href=\"sbml.xsl\"?>",
+            1,
+        );
+        let xml = format!(
+            "{}\n{}",
+            first,
+            include_str!("../tests/data/odes_example_test.xml")
+        );
+
+        let docs = SBMLReader::from_multi_xml(&xml);
+        assert_eq!(docs.len(), 2);
+
+        let first = docs[0].as_ref().expect("First document should be valid");
+        assert_eq!(first.model().expect("Model not found").id(), "example");
+        assert!(docs[1].is_ok());
+    }
+
     fn read_sbml_file(path: &PathBuf) -> Result<SBMLDocument, LibSBMLError> {
         let xml = std::fs::read_to_string(path).unwrap();
         Ok(SBMLReader::from_xml_string(&xml))
     }
+
+    #[test]
+    fn test_read_sbml_string_core_only() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("fbc_model");
+        model.create_reaction("r1");
+        let objective = model
+            .create_objective("obj", ObjectiveType::Maximize)
+            .unwrap();
+        objective.create_flux_objective("fo1", "r1", 1.0).unwrap();
+        let xml = doc.to_xml_string();
+
+        let full = SBMLReader::from_xml_string(&xml);
+        let model = full.model().expect("Model not found");
+        assert_eq!(model.list_of_objectives().len(), 1);
+
+        let core = SBMLReader::from_xml_string_core_only(&xml);
+        let model = core.model().expect("Model not found");
+        assert_eq!(model.list_of_reactions().len(), 1);
+        assert!(model.list_of_objectives().is_empty());
+        assert!(core.plugins().is_empty());
+        assert!(!core.to_xml_string().contains("fbc:"));
+
+        // Other documents keep their package support
+        let again = SBMLReader::from_xml_string(&xml);
+        let model = again.model().expect("Model not found");
+        assert_eq!(model.list_of_objectives().len(), 1);
+    }
+
+    #[test]
+    fn test_read_sbml_from_file() {
+        let doc = SBMLReader::from_file("tests/data/example.xml").unwrap();
+        assert_eq!(doc.model().expect("Model not found").id(), "example");
+    }
+
+    #[test]
+    fn test_read_sbml_from_missing_file() {
+        let result = SBMLReader::from_file("tests/data/does_not_exist.xml");
+        assert!(matches!(result, Err(SBMLReadError::Io(_))));
+    }
+
+    #[test]
+    fn test_read_sbml_from_invalid_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("invalid.xml");
+        std::fs::write(&path, "<sbml><notclosed></sbml>").unwrap();
+
+        let result = SBMLReader::from_file(&path);
+        assert!(matches!(result, Err(SBMLReadError::Invalid(_))));
+    }
+
+    #[test]
+    fn test_read_sbml_from_corrupt_gzip_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("corrupt.xml.gz");
+        std::fs::write(&path, [0x1f, 0x8b, 0x00, 0x01, 0x02]).unwrap();
+
+        let result = SBMLReader::from_file(&path);
+        assert!(matches!(result, Err(SBMLReadError::Io(_))));
+    }
+
+    #[test]
+    fn test_read_sbml_from_bytes() {
+        let bytes = std::fs::read("tests/data/example.xml").unwrap();
+        let doc = SBMLReader::from_bytes(&bytes).unwrap();
+        assert_eq!(doc.model().expect("Model not found").id(), "example");
+    }
+
+    #[test]
+    fn test_read_sbml_from_gzipped_bytes() {
+        let xml = std::fs::read("tests/data/example.xml").unwrap();
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        std::io::Write::write_all(&mut encoder, &xml).unwrap();
+        let bytes = encoder.finish().unwrap();
+
+        let doc = SBMLReader::from_bytes(&bytes).unwrap();
+        assert_eq!(doc.model().expect("Model not found").id(), "example");
+    }
+
+    #[test]
+    fn test_read_sbml_from_invalid_utf8() {
+        let result = SBMLReader::from_bytes(&[b'<', 0xff, 0xfe, b'>']);
+        assert!(matches!(result, Err(SBMLReadError::Utf8(_))));
+    }
+
+    #[test]
+    fn test_read_sbml_from_reader() {
+        let file = std::fs::File::open("tests/data/example.xml").unwrap();
+        let doc = SBMLReader::from_reader(file).unwrap();
+        assert_eq!(doc.model().expect("Model not found").id(), "example");
+    }
+
+    #[test]
+    fn test_peek_metadata() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("large_model");
+        model.set_name("Large model");
+        for i in 0..1000 {
+            model.create_species(&format!("s{i}"));
+        }
+        let xml = doc.to_xml_string();
+
+        let metadata = SBMLReader::peek_metadata(&xml);
+        assert_eq!(metadata.id, Some("large_model".to_string()));
+        assert_eq!(metadata.name, Some("Large model".to_string()));
+        assert_eq!(metadata.level, 3);
+        assert_eq!(metadata.version, 2);
+        assert!(metadata.packages.contains(&"fbc".to_string()));
+    }
+
+    #[test]
+    fn test_peek_metadata_without_model() {
+        let xml = SBMLDocument::default().to_xml_string();
+
+        let metadata = SBMLReader::peek_metadata(&xml);
+        assert_eq!(metadata.id, None);
+        assert_eq!(metadata.name, None);
+    }
+
+    #[test]
+    fn test_from_xml_string_with_log() {
+        // Packages unknown to libSBML that are not required only raise a warning
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<sbml xmlns="http://www.sbml.org/sbml/level3/version2/core" level="3" version="2"
+      xmlns:foo="http://www.example.org/sbml/foo/version1" foo:required="false">
+  <model id="with_warning">
+    <listOfCompartments>
+      <compartment id="cytosol" constant="true"/>
+    </listOfCompartments>
+  </model>
+</sbml>"#;
+
+        let (doc, log) = SBMLReader::from_xml_string_with_log(xml).expect("Failed to read");
+        assert_eq!(doc.model().expect("Model not found").id(), "with_warning");
+        assert!(log.valid);
+        assert!(log.num_warnings() > 0);
+
+        let invalid = SBMLReader::from_xml_string_with_log("<sbml>");
+        assert!(matches!(invalid, Err(SBMLReadError::Invalid(_))));
+    }
 }
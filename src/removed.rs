@@ -0,0 +1,87 @@
+//! This module provides ownership of elements that have been removed from a model.
+//!
+//! When an element is removed from a model, libSBML detaches it from its parent and
+//! hands ownership of the underlying C++ object to the caller. [`Removed`] takes over
+//! this ownership and frees the object once it is dropped.
+
+use std::{fmt::Debug, ops::Deref, rc::Rc};
+
+use cxx::UniquePtr;
+
+use crate::sbmlcxx;
+
+/// An element that has been removed from its model.
+///
+/// The handle owns the detached libSBML object and dereferences to its wrapper, so the
+/// removed element can still be inspected, e.g. to read its id or annotation. The object
+/// is freed when the handle is dropped.
+///
+/// Wrappers that refer to the removed element and were obtained before removing it
+/// (e.g. through [`Model::get_species`](crate::model::Model::get_species)) must not be
+/// used after the handle has been dropped. The same applies to wrappers of its children,
+/// such as the species references of a removed reaction.
+pub struct Removed<T> {
+    // Fields are dropped in declaration order, so the wrapper goes before the object
+    // it points to. The owner is only held to free the object on drop.
+    element: Rc<T>,
+    _owner: UniquePtr<sbmlcxx::SBase>,
+}
+
+impl<T> Removed<T> {
+    /// Takes ownership of a removed libSBML object.
+    ///
+    /// # Arguments
+    /// * `element` - The wrapper of the removed element
+    /// * `ptr` - The pointer to the removed object, as returned by libSBML
+    ///
+    /// # Safety
+    /// `ptr` must point to a detached object derived from `SBase` that is not owned by
+    /// anything else, and `element` must wrap this object.
+    pub(crate) unsafe fn new<X>(element: Rc<T>, ptr: *mut X) -> Self {
+        Self {
+            element,
+            _owner: UniquePtr::from_raw(ptr.cast::<sbmlcxx::SBase>()),
+        }
+    }
+}
+
+impl<T> Deref for Removed<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.element
+    }
+}
+
+impl<T: Debug> Debug for Removed<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Removed").field(&self.element).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_removed_element_is_freed_on_drop() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        model.create_species("s1");
+        model
+            .build_reaction("r1")
+            .reactant("s1", 1.0)
+            .product("s2", 1.0)
+            .build();
+
+        let removed = model.remove_reaction("r1").expect("Reaction not removed");
+        assert_eq!(removed.id(), "r1");
+        assert_eq!(removed.reactants().borrow().len(), 1);
+        assert!(format!("{removed:?}").starts_with("Removed("));
+        drop(removed);
+
+        // The model is unaffected by freeing the removed reaction
+        assert!(model.list_of_reactions().is_empty());
+        assert!(!doc.to_xml_string().contains(r#"id="r1""#));
+    }
+}
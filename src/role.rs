@@ -0,0 +1,128 @@
+use std::str::FromStr;
+
+use crate::errors::LibSBMLError;
+
+/// Common roles of model elements mapped to their Systems Biology Ontology (SBO) terms.
+///
+/// Roles cover the participants of a reaction (e.g. a modifier acting as catalyst),
+/// the kind of entity a species represents and the kind of process a reaction
+/// describes. They can be assigned to any element supporting SBO terms via
+/// `set_role` instead of spelling out the SBO identifier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Role {
+    /// A substance consumed by a reaction (SBO:0000010)
+    Reactant,
+    /// A substance produced by a reaction (SBO:0000011)
+    Product,
+    /// A substance that changes a reaction without being consumed (SBO:0000019)
+    Modifier,
+    /// A modifier that catalyses a reaction (catalyst, SBO:0000013)
+    Catalysis,
+    /// A modifier that decreases the rate of a reaction (inhibitor, SBO:0000020)
+    Inhibition,
+    /// A modifier that increases the rate of a reaction (stimulator, SBO:0000459)
+    Stimulation,
+    /// A small molecule such as a metabolite (SBO:0000247)
+    SimpleChemical,
+    /// A protein with catalytic activity (SBO:0000014)
+    Enzyme,
+    /// A polypeptide chain (SBO:0000252)
+    Protein,
+    /// A non-covalent complex of several entities (SBO:0000253)
+    Complex,
+    /// A chemical reaction in a biological system (SBO:0000176)
+    BiochemicalReaction,
+    /// A movement of entities between compartments (SBO:0000185)
+    TransportReaction,
+    /// An exchange of entities with the model boundary (SBO:0000627)
+    ExchangeReaction,
+    /// The production of biomass from its precursors (SBO:0000629)
+    BiomassProduction,
+}
+
+impl Role {
+    /// All roles, e.g. for reverse lookups.
+    const ALL: [Role; 14] = [
+        Role::Reactant,
+        Role::Product,
+        Role::Modifier,
+        Role::Catalysis,
+        Role::Inhibition,
+        Role::Stimulation,
+        Role::SimpleChemical,
+        Role::Enzyme,
+        Role::Protein,
+        Role::Complex,
+        Role::BiochemicalReaction,
+        Role::TransportReaction,
+        Role::ExchangeReaction,
+        Role::BiomassProduction,
+    ];
+
+    /// Returns the SBO term identifier of this role.
+    ///
+    /// # Returns
+    /// The SBO term ID (e.g. "SBO:0000013" for [`Role::Catalysis`])
+    pub fn sbo_term(&self) -> &'static str {
+        match self {
+            Role::Reactant => "SBO:0000010",
+            Role::Product => "SBO:0000011",
+            Role::Modifier => "SBO:0000019",
+            Role::Catalysis => "SBO:0000013",
+            Role::Inhibition => "SBO:0000020",
+            Role::Stimulation => "SBO:0000459",
+            Role::SimpleChemical => "SBO:0000247",
+            Role::Enzyme => "SBO:0000014",
+            Role::Protein => "SBO:0000252",
+            Role::Complex => "SBO:0000253",
+            Role::BiochemicalReaction => "SBO:0000176",
+            Role::TransportReaction => "SBO:0000185",
+            Role::ExchangeReaction => "SBO:0000627",
+            Role::BiomassProduction => "SBO:0000629",
+        }
+    }
+
+    /// Looks up the role belonging to an SBO term identifier.
+    ///
+    /// # Arguments
+    /// * `sbo_term` - The SBO term ID (e.g. "SBO:0000013")
+    ///
+    /// # Returns
+    /// The matching role, or None if the term has no named role
+    pub fn from_sbo_term(sbo_term: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|role| role.sbo_term() == sbo_term)
+    }
+}
+
+impl FromStr for Role {
+    type Err = LibSBMLError;
+
+    /// Parses a string into a Role.
+    ///
+    /// Accepts the SBO term identifier of a role (e.g. "SBO:0000013").
+    ///
+    /// # Errors
+    ///
+    /// Returns a LibSBMLError if the identifier does not belong to a known role.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_sbo_term(s)
+            .ok_or_else(|| LibSBMLError::InvalidArgument(format!("Unknown SBO role: {s}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_role_sbo_term_roundtrip() {
+        for role in Role::ALL {
+            assert_eq!(Role::from_sbo_term(role.sbo_term()), Some(role));
+        }
+
+        assert_eq!("SBO:0000020".parse::<Role>().unwrap(), Role::Inhibition);
+        assert!("SBO:0000000".parse::<Role>().is_err());
+    }
+}
@@ -12,14 +12,16 @@ use cxx::let_cxx_string;
 
 use crate::{
     clone, inner,
+    math::MathNode,
     model::Model,
     pin_ptr,
     prelude::IntoId,
+    reaction::Participant,
     required_property, sbase,
     sbmlcxx::{self},
     sbo_term,
     traits::fromptr::FromPtr,
-    upcast_annotation, upcast_pin,
+    upcast_annotation, upcast_notes, upcast_pin,
 };
 
 /// Enum representing the type of a rule
@@ -29,6 +31,8 @@ pub enum RuleType {
     RateRule,
     /// An assignment rule
     AssignmentRule,
+    /// An algebraic rule
+    AlgebraicRule,
 }
 
 /// A safe wrapper around the libSBML Species class.
@@ -48,6 +52,9 @@ sbase!(Rule<'a>, sbmlcxx::Rule);
 // Set the annotation trait for the Rule struct
 upcast_annotation!(Rule<'a>, sbmlcxx::Rule, sbmlcxx::SBase);
 
+// Set the notes trait for the Rule struct
+upcast_notes!(Rule<'a>, sbmlcxx::Rule, sbmlcxx::SBase);
+
 // Implement the Clone trait for the Rule struct
 clone!(Rule<'a>, sbmlcxx::Rule);
 
@@ -103,6 +110,29 @@ impl<'a> Rule<'a> {
         }
     }
 
+    /// Creates a new AlgebraicRule instance within the given Model.
+    ///
+    /// Algebraic rules have no variable and express the constraint `0 = formula`.
+    ///
+    /// # Arguments
+    /// * `model` - The parent Model that will contain this algebraic rule
+    /// * `formula` - The formula that is constrained to equal zero
+    ///
+    /// # Returns
+    /// A new Rule instance
+    pub fn new_algebraic_rule(model: &Model<'a>, formula: &str) -> Self {
+        let algebraic_rule_ptr = model.inner().borrow_mut().as_mut().createAlgebraicRule();
+        let mut algebraic_rule = pin_ptr!(algebraic_rule_ptr, sbmlcxx::AlgebraicRule);
+        let mut rule = upcast_pin!(algebraic_rule, sbmlcxx::AlgebraicRule, sbmlcxx::Rule);
+
+        let_cxx_string!(formula = formula);
+        rule.as_mut().setFormula(&formula);
+
+        Self {
+            inner: RefCell::new(rule),
+        }
+    }
+
     /// Returns a reference to the inner RefCell containing the RateRule pointer.
     ///
     /// This is primarily used internally by other parts of the library.
@@ -117,6 +147,14 @@ impl<'a> Rule<'a> {
     // Getter and setter for formula
     required_property!(Rule<'a>, formula, String, getFormula, setFormula);
 
+    /// Returns the math of the rule as a tree of [`MathNode`]s.
+    ///
+    /// # Returns
+    /// The expression tree, or None if no math is set
+    pub fn math(&self) -> Option<MathNode> {
+        MathNode::from_ast(self.inner.borrow().getMath())
+    }
+
     /// Returns the type of the rule.
     ///
     /// # Returns
@@ -127,11 +165,59 @@ impl<'a> Rule<'a> {
             Ok(RuleType::RateRule)
         } else if rule.isAssignment() {
             Ok(RuleType::AssignmentRule)
+        } else if rule.isAlgebraic() {
+            Ok(RuleType::AlgebraicRule)
         } else {
             Err("Unknown rule type".into())
         }
     }
 
+    /// Checks whether the variable of this rule is a legal target.
+    ///
+    /// Following the SBML specification, rate and assignment rules may only target
+    /// non-constant species, compartments and parameters. In addition, a species
+    /// that takes part in a reaction as a reactant or product must have its
+    /// `boundaryCondition` set to be determined by a rule. Algebraic rules have no
+    /// target and are always considered valid.
+    ///
+    /// # Arguments
+    /// * `model` - The model containing this rule
+    ///
+    /// # Returns
+    /// `true` if the rule targets a legal variable, `false` otherwise
+    pub fn target_valid(&self, model: &Model<'_>) -> bool {
+        if let Ok(RuleType::AlgebraicRule) = self.rule_type() {
+            return true;
+        }
+
+        let variable = self.variable();
+
+        if let Some(species) = model.get_species(&variable) {
+            if species.constant() {
+                return false;
+            }
+
+            let in_reaction = model.list_of_reactions().iter().any(|reaction| {
+                reaction.participants().iter().any(|participant| {
+                    !matches!(participant, Participant::Modifier { .. })
+                        && participant.species() == variable
+                })
+            });
+
+            return !in_reaction || species.boundary_condition() == Some(true);
+        }
+
+        if let Some(compartment) = model.get_compartment(&variable) {
+            return compartment.constant() != Some(true);
+        }
+
+        if let Some(parameter) = model.get_parameter(&variable) {
+            return parameter.constant() != Some(true);
+        }
+
+        false
+    }
+
     // SBO Term Methods generated by the `sbo_term` macro
     sbo_term!(sbmlcxx::Rule, sbmlcxx::SBase);
 }
@@ -400,4 +486,49 @@ mod tests {
             "<annotation><test>test</test></annotation>"
         );
     }
+
+    #[test]
+    fn test_algebraic_rule_new() {
+        let doc = SBMLDocument::default();
+        let model = Model::new(&doc, "test");
+        let algebraic_rule = Rule::new_algebraic_rule(&model, "s1 + s2 - total");
+
+        assert_eq!(algebraic_rule.rule_type().unwrap(), RuleType::AlgebraicRule);
+        assert_eq!(algebraic_rule.formula(), "s1 + s2 - total");
+        assert_eq!(algebraic_rule.variable(), "");
+    }
+
+    #[test]
+    fn test_rule_target_valid_parameter() {
+        let doc = SBMLDocument::default();
+        let model = Model::new(&doc, "test");
+        let constant = model.create_parameter("k1");
+        constant.set_constant(true);
+        let variable = model.create_parameter("k2");
+        variable.set_constant(false);
+
+        let invalid = Rule::new_rate_rule(&model, "k1", "1");
+        let valid = Rule::new_rate_rule(&model, "k2", "1");
+
+        assert!(!invalid.target_valid(&model));
+        assert!(valid.target_valid(&model));
+    }
+
+    #[test]
+    fn test_rule_target_valid_species() {
+        let doc = SBMLDocument::default();
+        let model = Model::new(&doc, "test");
+        let species = model.create_species("s1");
+        species.set_constant(false);
+        model.build_reaction("r1").reactant("s1", 1.0).build();
+
+        let rule = Rule::new_rate_rule(&model, "s1", "1");
+        assert!(!rule.target_valid(&model));
+
+        species.set_boundary_condition(true);
+        assert!(rule.target_valid(&model));
+
+        let missing = Rule::new_rate_rule(&model, "missing", "1");
+        assert!(!missing.target_valid(&model));
+    }
 }
@@ -5,23 +5,135 @@
 //! computational models in systems biology. An SBMLDocument is the root container
 //! for all SBML content.
 
-use std::{cell::RefCell, collections::HashMap, rc::Rc};
+use std::{cell::RefCell, collections::HashMap, io::Write, path::Path, rc::Rc};
 
 use autocxx::WithinUniquePtr;
 use cxx::{let_cxx_string, UniquePtr};
+use flate2::{write::GzEncoder, Compression};
 use std::pin::Pin;
 
 use crate::{
     cast::upcast,
+    combine::{combinearchive::CombineArchive, error::CombineArchiveError},
+    comp::ExternalModelDefinition,
+    errors::{LibSBMLError, SBMLReadError, SBMLWriteError},
     model::Model,
     namespaces::SBMLNamespaces,
     packages::{Package, PackageSpec},
     pin_const_ptr, pin_ptr,
+    plugin::get_document_plugin,
     prelude::SBMLErrorLog,
+    reader::SBMLReader,
     sbmlcxx,
     traits::fromptr::FromPtr,
 };
 
+/// Preconfigured document setups for common modeling use cases.
+///
+/// Used with [`SBMLDocument::with_profile`] to create documents with a matching
+/// level, version and set of packages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Profile {
+    /// Constraint-based models for COBRA tools: Level 3 Version 1 with FBC
+    /// version 2, the package being marked as not required
+    Cobra,
+    /// Kinetic models for ODE simulators: Level 3 Version 1 core, which is the
+    /// most widely supported configuration among simulation tools
+    Kinetic,
+    /// Core-only models using the latest Level 3 Version 2 without packages
+    Minimal,
+}
+
+/// Selects the validators run by [`SBMLDocument::check_consistency_with`].
+///
+/// Each flag toggles one of libSBML's consistency validators. Disabling expensive
+/// validators such as unit checking speeds up validation of large models. The
+/// default enables all validators, matching [`SBMLDocument::check_consistency`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConsistencyChecks {
+    /// General consistency of SBML components
+    pub general: bool,
+    /// Consistency of identifiers
+    pub identifier: bool,
+    /// Consistency of units
+    pub units: bool,
+    /// Consistency of MathML expressions
+    pub math: bool,
+    /// Consistency of SBO terms
+    pub sbo: bool,
+    /// Detection of overdetermined models
+    pub overdetermined: bool,
+    /// Recommended modeling practice
+    pub modeling_practice: bool,
+}
+
+impl ConsistencyChecks {
+    /// Enables all validators.
+    pub fn all() -> Self {
+        Self {
+            general: true,
+            identifier: true,
+            units: true,
+            math: true,
+            sbo: true,
+            overdetermined: true,
+            modeling_practice: true,
+        }
+    }
+
+    /// Disables all validators.
+    pub fn none() -> Self {
+        Self {
+            general: false,
+            identifier: false,
+            units: false,
+            math: false,
+            sbo: false,
+            overdetermined: false,
+            modeling_practice: false,
+        }
+    }
+
+    /// Enables only the structural validators (general and identifier consistency).
+    pub fn structural() -> Self {
+        Self {
+            general: true,
+            identifier: true,
+            ..Self::none()
+        }
+    }
+
+    /// Returns the libSBML validator categories together with their toggle state.
+    fn categories(&self) -> [(sbmlcxx::SBMLErrorCategory_t, bool); 7] {
+        use sbmlcxx::SBMLErrorCategory_t as Category;
+
+        [
+            (Category::LIBSBML_CAT_GENERAL_CONSISTENCY, self.general),
+            (
+                Category::LIBSBML_CAT_IDENTIFIER_CONSISTENCY,
+                self.identifier,
+            ),
+            (Category::LIBSBML_CAT_UNITS_CONSISTENCY, self.units),
+            (Category::LIBSBML_CAT_MATHML_CONSISTENCY, self.math),
+            (Category::LIBSBML_CAT_SBO_CONSISTENCY, self.sbo),
+            (
+                Category::LIBSBML_CAT_OVERDETERMINED_MODEL,
+                self.overdetermined,
+            ),
+            (
+                Category::LIBSBML_CAT_MODELING_PRACTICE,
+                self.modeling_practice,
+            ),
+        ]
+    }
+}
+
+impl Default for ConsistencyChecks {
+    fn default() -> Self {
+        Self::all()
+    }
+}
+
 /// A wrapper around libSBML's SBMLDocument class that provides a safe Rust interface.
 ///
 /// The SBMLDocument is the top-level container for an SBML model and associated data.
@@ -54,10 +166,18 @@ impl SBMLDocument {
             unsafe { sbmlcxx::SBMLDocument::new1(namespaces.inner().borrow_mut().as_mut_ptr()) }
                 .within_unique_ptr();
 
-        // Enable FBC
-        if let Some(doc) = document.as_mut() {
+        // Enable FBC, Groups and Comp
+        if let Some(mut doc) = document.as_mut() {
             let_cxx_string!(fbc = "fbc");
-            doc.setPackageRequired(&fbc, true);
+            doc.as_mut().setPackageRequired(&fbc, true);
+
+            // Groups never change the mathematical meaning of a model
+            let_cxx_string!(groups = "groups");
+            doc.as_mut().setPackageRequired(&groups, false);
+
+            // Submodels contribute to the mathematics of the containing model
+            let_cxx_string!(comp = "comp");
+            doc.as_mut().setPackageRequired(&comp, true);
         }
 
         Self {
@@ -65,6 +185,29 @@ impl SBMLDocument {
         }
     }
 
+    /// Creates a new SBMLDocument configured for the given profile.
+    ///
+    /// # Arguments
+    /// * `profile` - The use case to configure the document for
+    ///
+    /// # Returns
+    /// A new SBMLDocument instance with the level, version and packages of the profile
+    pub fn with_profile(profile: Profile) -> Self {
+        match profile {
+            Profile::Cobra => {
+                let doc = Self::new(3, 1, vec![Package::Fbc(2).into()]);
+                let_cxx_string!(fbc = "fbc");
+                doc.inner()
+                    .borrow_mut()
+                    .pin_mut()
+                    .setPackageRequired(&fbc, false);
+                doc
+            }
+            Profile::Kinetic => Self::new(3, 1, None),
+            Profile::Minimal => Self::new(3, 2, None),
+        }
+    }
+
     /// Creates a new SBMLDocument from a unique pointer to a libSBML document.
     ///
     /// This is mainly used internally to construct SBMLDocument instances from
@@ -83,6 +226,39 @@ impl SBMLDocument {
         SBMLDocument { document }
     }
 
+    /// Parses an SBML document from an XML string.
+    ///
+    /// In contrast to [`SBMLReader::from_xml_string`], which always returns a document,
+    /// malformed or empty input is reported as an error carrying the libSBML error log.
+    /// This distinguishes documents that failed to parse from documents without a model.
+    ///
+    /// # Arguments
+    /// * `xml` - A string containing SBML XML
+    ///
+    /// # Returns
+    /// The parsed SBMLDocument, or an [`SBMLReadError`] if the document contains errors
+    /// of severity Error or Fatal
+    pub fn from_xml_string(xml: &str) -> Result<Self, SBMLReadError> {
+        SBMLReader::from_xml_string_with_log(xml).map(|(doc, _)| doc)
+    }
+
+    /// Reads the master SBML document of a COMBINE archive (OMEX).
+    ///
+    /// This is a shortcut for opening the archive via [`CombineArchive::open`],
+    /// retrieving its master entry and parsing it into an `SBMLDocument`.
+    ///
+    /// # Arguments
+    /// * `path` - Path to the COMBINE archive
+    ///
+    /// # Returns
+    /// The parsed SBML document, or an error if the archive could not be opened or
+    /// has no readable master file
+    pub fn from_combine_archive<P: AsRef<Path>>(path: P) -> Result<Self, CombineArchiveError> {
+        let archive = CombineArchive::open(path)?;
+        let xml_string = archive.master()?.as_string()?;
+        Ok(SBMLReader::from_xml_string(&xml_string))
+    }
+
     /// Returns a reference to the underlying libSBML document.
     ///
     /// This is primarily for internal use by other parts of the API.
@@ -220,6 +396,50 @@ impl SBMLDocument {
         }
     }
 
+    /// Creates a new ExternalModelDefinition within this document.
+    ///
+    /// External model definitions declare models stored in other documents, which
+    /// can then be instantiated via [`Model::create_submodel`]. Requires the comp
+    /// package to be enabled (see [`Package::Comp`]).
+    ///
+    /// # Arguments
+    /// * `id` - The identifier for the definition
+    /// * `source` - The URI of the document containing the model (e.g. "enzyme.xml")
+    ///
+    /// # Returns
+    /// A new ExternalModelDefinition instance wrapped in an Rc, or an error if the
+    /// comp plugin is not available
+    pub fn create_external_model_definition<'a>(
+        &'a self,
+        id: &str,
+        source: &str,
+    ) -> Result<Rc<ExternalModelDefinition<'a>>, LibSBMLError> {
+        Ok(Rc::new(ExternalModelDefinition::new(self, id, source)?))
+    }
+
+    /// Returns all external model definitions of this document.
+    ///
+    /// # Returns
+    /// A vector containing Rc references to all ExternalModelDefinitions, which is
+    /// empty if the comp package is not enabled
+    pub fn list_of_external_model_definitions<'a>(
+        &'a self,
+    ) -> Vec<Rc<ExternalModelDefinition<'a>>> {
+        let Ok(mut comp_plugin) =
+            get_document_plugin::<sbmlcxx::CompSBMLDocumentPlugin>(self, "comp")
+        else {
+            return vec![];
+        };
+
+        let n_definitions = comp_plugin.as_mut().getNumExternalModelDefinitions().0;
+        (0..n_definitions)
+            .map(|i| {
+                let definition = comp_plugin.as_mut().getExternalModelDefinition(i.into());
+                Rc::new(ExternalModelDefinition::from_ptr(definition))
+            })
+            .collect()
+    }
+
     /// Converts the SBML document to an XML string representation.
     ///
     /// This function uses the SBMLWriter to serialize the current state of the
@@ -242,6 +462,271 @@ impl SBMLDocument {
         }
     }
 
+    /// Writes the SBML document to the given writer.
+    ///
+    /// The document is serialized as UTF-8 encoded XML. This allows writing to any
+    /// destination such as buffers, sockets or compressors.
+    ///
+    /// # Arguments
+    /// * `writer` - The destination to write the document to
+    ///
+    /// # Returns
+    /// `Ok(())` if the document has been written, or an [`SBMLWriteError`] otherwise
+    pub fn write_to(&self, mut writer: impl Write) -> Result<(), SBMLWriteError> {
+        writer.write_all(self.to_xml_string().as_bytes())?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Writes the SBML document to a file.
+    ///
+    /// The document is serialized as UTF-8 encoded XML. Parent directories are not
+    /// created - if they do not exist, an error is returned.
+    ///
+    /// # Arguments
+    /// * `path` - Path of the file to write
+    ///
+    /// # Returns
+    /// `Ok(())` if the file has been written, or an [`SBMLWriteError`] otherwise
+    pub fn write_to_file<P: AsRef<Path>>(&self, path: P) -> Result<(), SBMLWriteError> {
+        std::fs::write(path, self.to_xml_string())?;
+        Ok(())
+    }
+
+    /// Writes the SBML document to a gzip-compressed file.
+    ///
+    /// Compressed files can be read back with [`SBMLReader::from_file`]. Parent
+    /// directories are not created - if they do not exist, an error is returned.
+    ///
+    /// # Arguments
+    /// * `path` - Path of the file to write (e.g. `model.xml.gz`)
+    ///
+    /// # Returns
+    /// `Ok(())` if the file has been written, or an [`SBMLWriteError`] otherwise
+    pub fn write_to_file_gzipped<P: AsRef<Path>>(&self, path: P) -> Result<(), SBMLWriteError> {
+        let file = std::fs::File::create(path)?;
+        let mut encoder = GzEncoder::new(file, Compression::default());
+        self.write_to(&mut encoder)?;
+        encoder.finish()?;
+        Ok(())
+    }
+
+    /// Converts the SBML document to the given SBML level and version.
+    ///
+    /// Conversions may lose information (e.g. when downgrading from Level 3 to
+    /// Level 2), in which case libSBML reports warnings. These are returned as a
+    /// structured [`SBMLErrorLog`] even if the conversion succeeded, so callers can
+    /// inspect what has been dropped or altered.
+    ///
+    /// Package content such as FBC cannot be represented in Level 1 or 2. Downgrading
+    /// such documents fails and the returned error log reports the affected package.
+    ///
+    /// # Arguments
+    /// * `level` - The target SBML level
+    /// * `version` - The target SBML version
+    /// * `strict` - If true, the conversion fails if any information would be lost
+    ///
+    /// # Returns
+    /// The error log of the conversion containing possible warnings, or the error log
+    /// of the failed conversion
+    pub fn convert_to(
+        &self,
+        level: u32,
+        version: u32,
+        strict: bool,
+    ) -> Result<SBMLErrorLog, SBMLErrorLog> {
+        let converted = self.document.borrow_mut().pin_mut().setLevelAndVersion(
+            level.into(),
+            version.into(),
+            strict,
+            false,
+        );
+
+        let log = SBMLErrorLog::new(self);
+
+        if converted {
+            Ok(log)
+        } else {
+            Err(log)
+        }
+    }
+
+    /// Converts the SBML document to the configuration expected by COBRA tools
+    /// and serializes it to an XML string.
+    ///
+    /// Many LP/FBA tools (e.g. COBRApy) only accept SBML Level 3 Version 1
+    /// documents using version 2 of the FBC package, with the package marked as
+    /// not required. This method converts a copy of the document to that
+    /// configuration, leaving the original document untouched.
+    ///
+    /// # Returns
+    /// The XML string of the converted document, or the [`SBMLErrorLog`] of the
+    /// copy if the conversion failed.
+    pub fn to_cobra_string(&self) -> Result<String, SBMLErrorLog> {
+        // Work on a copy to keep the original document untouched
+        let copy = SBMLReader::from_xml_string(&self.to_xml_string());
+
+        copy.convert_to(3, 1, false)?;
+
+        if copy.plugins().contains(&"fbc".to_string()) {
+            let fbc_v1 = copy
+                .namespaces()
+                .values()
+                .any(|uri| uri.ends_with("fbc/version1"));
+
+            if fbc_v1 {
+                let mut props = sbmlcxx::ConversionProperties::new().within_unique_ptr();
+                let_cxx_string!(option = "convert fbc v1 to fbc v2");
+                let_cxx_string!(description = "");
+                props.pin_mut().addOption3(&option, true, &description);
+
+                let result = copy
+                    .inner()
+                    .borrow_mut()
+                    .pin_mut()
+                    .convert(props.as_ref().unwrap());
+
+                // LIBSBML_OPERATION_SUCCESS
+                if result.0 != 0 {
+                    return Err(SBMLErrorLog::new(&copy));
+                }
+            }
+
+            let_cxx_string!(fbc = "fbc");
+            copy.inner()
+                .borrow_mut()
+                .pin_mut()
+                .setPackageRequired(&fbc, false);
+        }
+
+        Ok(copy.to_xml_string())
+    }
+
+    /// Creates a deep copy of the document without annotations and notes.
+    ///
+    /// Annotations (including CV terms and model histories) and notes are removed
+    /// from every element of the copy, which yields a lean document for analysis
+    /// tasks that only depend on the model structure. The original document is
+    /// left untouched.
+    ///
+    /// # Returns
+    /// A stripped copy of the document
+    pub fn clone_stripped(&self) -> SBMLDocument {
+        let copy = SBMLReader::from_xml_string(&self.to_xml_string());
+
+        {
+            let mut document = copy.document.borrow_mut();
+            let mut base =
+                unsafe { upcast::<sbmlcxx::SBMLDocument, sbmlcxx::SBase>(document.as_mut_ptr()) };
+
+            // The returned list only owns its nodes, not the elements themselves
+            let elements =
+                unsafe { UniquePtr::from_raw(base.as_mut().getAllElements(std::ptr::null_mut())) };
+
+            let n_elements = elements.getSize().0;
+            for i in 0..n_elements {
+                let element = elements.get(i.into()).cast::<sbmlcxx::SBase>();
+                let mut element = pin_ptr!(element, sbmlcxx::SBase);
+                element.as_mut().unsetAnnotation();
+                element.as_mut().unsetNotes();
+            }
+
+            base.as_mut().unsetAnnotation();
+            base.as_mut().unsetNotes();
+        }
+
+        copy
+    }
+
+    /// Checks whether the document survives a write/read cycle without semantic loss.
+    ///
+    /// The document is serialized to XML and read back, after which the model of the
+    /// copy is compared with the original. Compartments, species, parameters, unit
+    /// definitions and reactions are matched by their identifier and compared using
+    /// their `PartialEq` implementations, rules are compared by type, variable and
+    /// formula.
+    ///
+    /// # Returns
+    /// `Ok(())` if no differences were found, or a description of each difference
+    pub fn roundtrip_check(&self) -> Result<(), Vec<String>> {
+        let copy = SBMLReader::from_xml_string(&self.to_xml_string());
+        let mut differences = Vec::new();
+
+        if (self.level(), self.version()) != (copy.level(), copy.version()) {
+            differences.push(format!(
+                "Level/version changed from L{}V{} to L{}V{}",
+                self.level(),
+                self.version(),
+                copy.level(),
+                copy.version()
+            ));
+        }
+
+        match (self.model(), copy.model()) {
+            (None, None) => {}
+            (Some(_), None) => differences.push("Model is missing after re-reading".to_string()),
+            (None, Some(_)) => differences.push("Model was added after re-reading".to_string()),
+            (Some(original), Some(reread)) => {
+                if original.id() != reread.id() || original.name() != reread.name() {
+                    differences.push(format!("Model '{}' differs", original.id()));
+                }
+
+                compare_elements(
+                    "Compartment",
+                    &original.list_of_compartments(),
+                    &reread.list_of_compartments(),
+                    |compartment| compartment.id(),
+                    &mut differences,
+                );
+                compare_elements(
+                    "Species",
+                    &original.list_of_species(),
+                    &reread.list_of_species(),
+                    |species| species.id(),
+                    &mut differences,
+                );
+                compare_elements(
+                    "Parameter",
+                    &original.list_of_parameters(),
+                    &reread.list_of_parameters(),
+                    |parameter| parameter.id(),
+                    &mut differences,
+                );
+                compare_elements(
+                    "Unit definition",
+                    &original.list_of_unit_definitions(),
+                    &reread.list_of_unit_definitions(),
+                    |unit_definition| unit_definition.id(),
+                    &mut differences,
+                );
+                compare_elements(
+                    "Reaction",
+                    &original.list_of_reactions(),
+                    &reread.list_of_reactions(),
+                    |reaction| reaction.id(),
+                    &mut differences,
+                );
+
+                let rules = |model: &Model| {
+                    model
+                        .list_of_rules()
+                        .iter()
+                        .map(|rule| (rule.rule_type().ok(), rule.variable(), rule.formula()))
+                        .collect::<Vec<_>>()
+                };
+                if rules(&original) != rules(&reread) {
+                    differences.push("Rules differ".to_string());
+                }
+            }
+        }
+
+        if differences.is_empty() {
+            Ok(())
+        } else {
+            Err(differences)
+        }
+    }
+
     /// Checks the consistency of the SBML document.
     ///
     /// This function performs a consistency check on the SBML document and returns
@@ -263,6 +748,58 @@ impl SBMLDocument {
 
         SBMLErrorLog::new(self)
     }
+
+    /// Checks the consistency of the SBML document using only the selected validators.
+    ///
+    /// The validators are reset to their defaults afterwards, so subsequent calls to
+    /// [`SBMLDocument::check_consistency`] are not affected.
+    ///
+    /// # Arguments
+    /// * `checks` - The validators to run
+    ///
+    /// # Returns
+    /// A [`SBMLErrorLog`] containing the validation status and errors of the document.
+    pub fn check_consistency_with(&self, checks: ConsistencyChecks) -> SBMLErrorLog {
+        self.set_consistency_checks(checks);
+        let log = self.check_consistency();
+        self.set_consistency_checks(ConsistencyChecks::default());
+
+        log
+    }
+
+    /// Applies the given validator selection to the underlying document.
+    fn set_consistency_checks(&self, checks: ConsistencyChecks) {
+        let mut document = self.inner().borrow_mut();
+        for (category, apply) in checks.categories() {
+            document
+                .as_mut()
+                .unwrap()
+                .setConsistencyChecks(category, apply);
+        }
+    }
+
+    /// Checks the consistency of the SBML document, reporting only the errors
+    /// of a specific SBML package.
+    ///
+    /// This is useful to isolate package-specific issues, e.g. FBC modelers who
+    /// want to focus on violations of the `fbc` package rules only. Use `"core"`
+    /// to retrieve errors of the SBML core specification.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the package to report errors for (e.g. "fbc")
+    ///
+    /// # Returns
+    /// A [`SBMLErrorLog`] containing only the errors reported by the given package
+    pub fn check_package(&self, name: &str) -> SBMLErrorLog {
+        let log = self.check_consistency();
+        let errors = log
+            .errors
+            .into_iter()
+            .filter(|error| error.package == name)
+            .collect();
+
+        SBMLErrorLog::from_errors(errors)
+    }
 }
 
 impl std::fmt::Debug for SBMLDocument {
@@ -275,6 +812,43 @@ impl std::fmt::Debug for SBMLDocument {
     }
 }
 
+/// Compares two lists of elements by identifier and records their differences.
+///
+/// # Arguments
+/// * `kind` - The kind of element, used in the descriptions (e.g. "Species")
+/// * `original` - The elements before the roundtrip
+/// * `reread` - The elements after the roundtrip
+/// * `id` - Returns the identifier of an element
+/// * `differences` - Collects the descriptions of all differences
+fn compare_elements<T: PartialEq>(
+    kind: &str,
+    original: &[Rc<T>],
+    reread: &[Rc<T>],
+    id: impl Fn(&T) -> String,
+    differences: &mut Vec<String>,
+) {
+    for element in original {
+        let element_id = id(element.as_ref());
+        match reread.iter().find(|other| id(other.as_ref()) == element_id) {
+            None => differences.push(format!("{kind} '{element_id}' is missing")),
+            Some(other) if other.as_ref() != element.as_ref() => {
+                differences.push(format!("{kind} '{element_id}' differs"))
+            }
+            Some(_) => {}
+        }
+    }
+
+    for element in reread {
+        let element_id = id(element.as_ref());
+        if !original
+            .iter()
+            .any(|other| id(other.as_ref()) == element_id)
+        {
+            differences.push(format!("{kind} '{element_id}' was added"));
+        }
+    }
+}
+
 impl Default for SBMLDocument {
     /// Creates a new SBMLDocument with the default SBML level and version, and FBC package.
     ///
@@ -287,7 +861,10 @@ impl Default for SBMLDocument {
 
 #[cfg(test)]
 mod tests {
-    use crate::prelude::SBMLErrorSeverity;
+    use crate::{
+        prelude::{FluxBoundOperation, ObjectiveType, SBMLErrorCategory, SBMLErrorSeverity},
+        traits::{annotation::Annotation, inner::Inner},
+    };
 
     use super::*;
 
@@ -374,6 +951,52 @@ mod tests {
         assert_eq!(warnings, 4);
     }
 
+    #[test]
+    fn test_sbmldoc_check_consistency_with() {
+        // Assigning a temperature to an amount raises a unit consistency warning
+        let unit_mismatch = || {
+            let doc = SBMLDocument::default();
+            let model = doc.create_model("model");
+            model
+                .build_parameter("T")
+                .value(310.0)
+                .units("kelvin")
+                .constant(true)
+                .build();
+            model
+                .build_parameter("x")
+                .units("mole")
+                .constant(false)
+                .build();
+            model.create_assignment_rule("x", "T");
+            doc
+        };
+
+        let doc = unit_mismatch();
+        let error_log = doc.check_consistency();
+        assert!(!error_log
+            .by_category(SBMLErrorCategory::UnitsConsistency)
+            .is_empty());
+
+        let doc = unit_mismatch();
+        let checks = ConsistencyChecks {
+            units: false,
+            ..Default::default()
+        };
+        let error_log = doc.check_consistency_with(checks);
+        assert!(error_log
+            .by_category(SBMLErrorCategory::UnitsConsistency)
+            .is_empty());
+
+        // The default validators are restored afterwards
+        let doc = unit_mismatch();
+        doc.check_consistency_with(ConsistencyChecks::structural());
+        assert!(!doc
+            .check_consistency()
+            .by_category(SBMLErrorCategory::UnitsConsistency)
+            .is_empty());
+    }
+
     #[test]
     fn test_sbmldoc_new_with_packages() {
         let doc = SBMLDocument::new(3, 2, vec![Package::Fbc(1).into()]);
@@ -440,6 +1063,40 @@ mod tests {
         assert!(!namespaces.contains_key("enzymeml"));
     }
 
+    #[test]
+    fn test_to_cobra_string() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("model");
+        model.build_compartment("c").constant(true).build();
+        model
+            .build_species("glc")
+            .compartment("c")
+            .boundary_condition(false)
+            .has_only_substance_units(false)
+            .constant(false)
+            .build();
+
+        let xml = doc.to_cobra_string().expect("Failed to convert to COBRA");
+        let cobra = SBMLReader::from_xml_string(&xml);
+
+        assert_eq!(cobra.level(), 3);
+        assert_eq!(cobra.version(), 1);
+
+        let namespaces = cobra.namespaces();
+        assert_eq!(
+            namespaces[""],
+            "http://www.sbml.org/sbml/level3/version1/core"
+        );
+        assert_eq!(
+            namespaces["fbc"],
+            "http://www.sbml.org/sbml/level3/version1/fbc/version2"
+        );
+        assert!(xml.contains("fbc:required=\"false\""));
+
+        // The original document is left untouched
+        assert_eq!(doc.version(), 2);
+    }
+
     #[test]
     #[should_panic]
     fn test_remove_namespace_non_existent() {
@@ -448,4 +1105,278 @@ mod tests {
         doc.remove_namespace("enzymeml")
             .expect("Could not remove namespace");
     }
+
+    #[test]
+    fn test_check_package() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("model");
+
+        // Core violation: species without a compartment
+        model.build_species("s1").build();
+
+        // FBC violation: flux objective referencing a non-existent reaction
+        let objective = model
+            .create_objective("obj", ObjectiveType::Maximize)
+            .expect("Failed to create objective");
+        objective
+            .create_flux_objective("fo", "missing_reaction", 1.0)
+            .expect("Failed to create flux objective");
+
+        let fbc_log = doc.check_package("fbc");
+        assert!(!fbc_log.valid);
+        assert!(!fbc_log.errors.is_empty());
+        assert!(fbc_log.errors.iter().all(|e| e.package == "fbc"));
+
+        // The core violation is only reported by the core check
+        let core_log = doc.check_package("core");
+        assert!(!core_log.valid);
+        assert!(core_log.errors.iter().all(|e| e.package == "core"));
+    }
+
+    #[test]
+    fn test_convert_to_reports_warnings() {
+        let doc = SBMLDocument::new(3, 2, vec![]);
+        let model = doc.create_model("model");
+
+        // Model-wide unit attributes do not exist in Level 2
+        let_cxx_string!(units = "second");
+        model.inner().borrow_mut().as_mut().setTimeUnits(&units);
+        model.inner().borrow_mut().as_mut().setExtentUnits(&units);
+
+        let log = doc
+            .convert_to(2, 4, false)
+            .expect("Non-strict conversion should succeed");
+
+        assert_eq!(doc.level(), 2);
+        assert_eq!(doc.version(), 4);
+        assert!(log
+            .errors
+            .iter()
+            .any(|e| e.severity == SBMLErrorSeverity::Warning));
+    }
+
+    #[test]
+    fn test_convert_to_reports_fbc_loss() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("model");
+        model.create_reaction("r1");
+        model
+            .create_flux_bound("fb1", "r1", FluxBoundOperation::LessEqual)
+            .unwrap();
+
+        let log = doc
+            .convert_to(2, 4, true)
+            .expect_err("Conversion of FBC content to Level 2 should fail");
+
+        assert!(!log.valid);
+        assert!(log
+            .errors
+            .iter()
+            .any(|e| e.message.to_lowercase().contains("package")));
+        assert_eq!(doc.level(), 3);
+        assert_eq!(doc.version(), 2);
+    }
+
+    #[test]
+    fn test_from_combine_archive() {
+        let doc = SBMLDocument::from_combine_archive("tests/data/test.omex")
+            .expect("Failed to read COMBINE archive");
+        let model = doc.model().expect("Model not found");
+
+        assert_eq!(model.name(), "Test");
+        assert_eq!(model.list_of_species().len(), 4);
+        assert_eq!(model.list_of_compartments().len(), 1);
+
+        let substrate = model.get_species("s0").expect("Species not found");
+        assert_eq!(substrate.name(), Some("Substrate".to_string()));
+        assert_eq!(substrate.compartment(), Some("v0".to_string()));
+    }
+
+    #[test]
+    fn test_write_to_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("model.xml");
+
+        let doc = SBMLDocument::default();
+        doc.create_model("written");
+        doc.write_to_file(&path).expect("Failed to write document");
+
+        let read = SBMLReader::from_file(&path).expect("Failed to read document");
+        assert_eq!(read.model().expect("Model not found").id(), "written");
+    }
+
+    #[test]
+    fn test_write_to_file_missing_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("missing").join("model.xml");
+
+        let doc = SBMLDocument::default();
+        doc.create_model("written");
+
+        let result = doc.write_to_file(&path);
+        assert!(matches!(result, Err(SBMLWriteError::Io(_))));
+    }
+
+    #[test]
+    fn test_with_profile_cobra() {
+        let doc = SBMLDocument::with_profile(Profile::Cobra);
+        assert_eq!(doc.level(), 3);
+        assert_eq!(doc.version(), 1);
+        assert!(doc.plugins().contains(&"fbc".to_string()));
+        assert!(doc
+            .namespaces()
+            .values()
+            .any(|uri| uri.ends_with("fbc/version2")));
+        assert!(doc.to_xml_string().contains(r#"fbc:required="false""#));
+    }
+
+    #[test]
+    fn test_with_profile_kinetic() {
+        let doc = SBMLDocument::with_profile(Profile::Kinetic);
+        assert_eq!(doc.level(), 3);
+        assert_eq!(doc.version(), 1);
+        assert!(!doc.namespaces().contains_key("fbc"));
+        assert_eq!(
+            doc.namespaces().get(""),
+            Some(&"http://www.sbml.org/sbml/level3/version1/core".to_string())
+        );
+    }
+
+    #[test]
+    fn test_with_profile_minimal() {
+        let doc = SBMLDocument::with_profile(Profile::Minimal);
+        assert_eq!(doc.level(), 3);
+        assert_eq!(doc.version(), 2);
+        assert!(!doc.namespaces().contains_key("fbc"));
+        assert_eq!(
+            doc.namespaces().get(""),
+            Some(&"http://www.sbml.org/sbml/level3/version2/core".to_string())
+        );
+    }
+
+    #[test]
+    fn test_write_to_file_gzipped() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("model.xml.gz");
+
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("compressed");
+        model.create_compartment("c");
+        model.build_species("s1").compartment("c").build();
+        doc.write_to_file_gzipped(&path)
+            .expect("Failed to write document");
+
+        let bytes = std::fs::read(&path).unwrap();
+        assert_eq!(&bytes[..2], &[0x1f, 0x8b]);
+
+        let read = SBMLReader::from_file(&path).expect("Failed to read document");
+        let model = read.model().expect("Model not found");
+        assert_eq!(model.id(), "compressed");
+        assert_eq!(model.list_of_species().len(), 1);
+    }
+
+    #[test]
+    fn test_write_to_buffer() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("buffered");
+        model.create_species("s1");
+
+        let mut buffer: Vec<u8> = Vec::new();
+        doc.write_to(&mut buffer).expect("Failed to write document");
+
+        let xml = String::from_utf8(buffer).unwrap();
+        let read = SBMLReader::from_xml_string(&xml);
+        let model = read.model().expect("Model not found");
+        assert_eq!(model.id(), "buffered");
+        assert_eq!(model.list_of_species().len(), 1);
+    }
+
+    #[test]
+    fn test_clone_stripped() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        let species = model.build_species("glucose").build();
+        species
+            .set_annotation("<test:data xmlns:test=\"http://test.org\">1</test:data>")
+            .unwrap();
+        model
+            .set_annotation("<test:model xmlns:test=\"http://test.org\">2</test:model>")
+            .unwrap();
+
+        let stripped = doc.clone_stripped();
+        let stripped_model = stripped.model().expect("Model not found");
+        let stripped_species = stripped_model
+            .get_species("glucose")
+            .expect("Species not found");
+
+        assert!(stripped_model.get_annotation().is_empty());
+        assert!(stripped_species.get_annotation().is_empty());
+        assert!(!stripped.to_xml_string().contains("http://test.org"));
+
+        // The original document keeps its annotations
+        assert!(species.get_annotation().contains("test:data"));
+        assert!(model.get_annotation().contains("test:model"));
+    }
+
+    #[test]
+    fn test_roundtrip_check() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("glycolysis");
+        model.set_name("Glycolysis");
+
+        let mm = model.build_unit_definition("mM", "millimolar").build();
+        model
+            .build_compartment("cytosol")
+            .size(1.5)
+            .constant(true)
+            .build();
+        for id in ["glc", "g6p", "hk"] {
+            model
+                .build_species(id)
+                .compartment("cytosol")
+                .initial_concentration(1.0)
+                .unit(&mm)
+                .build();
+        }
+        model.build_parameter("kcat").value(12.5).build();
+        model.create_assignment_rule("g6p", "2 * glc");
+
+        let reaction = model
+            .build_reaction("hexokinase")
+            .reactant("glc", 1.0)
+            .product("g6p", 1.0)
+            .modifier("hk")
+            .build();
+        let kinetic_law = reaction.create_kinetic_law("kcat * hk * glc / (Km + glc)");
+        kinetic_law.add_local_parameter("Km", Some(0.1));
+
+        assert_eq!(doc.roundtrip_check(), Ok(()));
+        assert_eq!(SBMLDocument::default().roundtrip_check(), Ok(()));
+    }
+
+    #[test]
+    fn test_sbmldoc_from_xml_string() {
+        let source = SBMLDocument::with_profile(Profile::Minimal);
+        source.create_model("test");
+        let xml = source.to_xml_string();
+
+        let doc = SBMLDocument::from_xml_string(&xml).expect("Failed to parse document");
+        assert_eq!(doc.model().unwrap().id(), "test");
+    }
+
+    #[test]
+    fn test_sbmldoc_from_xml_string_malformed() {
+        let result = SBMLDocument::from_xml_string(r#"<sbml><model id="test"></sbml>"#);
+
+        let Err(SBMLReadError::Invalid(log)) = result else {
+            panic!("Expected an invalid document");
+        };
+        assert!(!log.errors.is_empty());
+    }
+
+    #[test]
+    fn test_sbmldoc_from_xml_string_empty() {
+        let result = SBMLDocument::from_xml_string("");
+        assert!(matches!(result, Err(SBMLReadError::Invalid(_))));
+    }
 }
@@ -9,6 +9,7 @@
 //! - `SBMLErrorLog`: A collection of validation errors from an SBML document
 //! - `SBMLError`: An individual validation error with detailed information
 //! - `SBMLErrorSeverity`: The severity level of an error (Error, Warning, etc.)
+//! - `SBMLErrorCategory`: The category of an error (unit consistency, modeling practice, etc.)
 
 use std::pin::Pin;
 
@@ -52,6 +53,20 @@ impl SBMLErrorLog {
             errors.push(SBMLError::new(errorlog.as_ref().getError(i.into())));
         }
 
+        Self::from_errors(errors)
+    }
+
+    /// Creates a new error log from a list of errors.
+    ///
+    /// The validation status is derived from the given errors - the log is
+    /// considered invalid if any error has severity level Error or Fatal.
+    ///
+    /// # Arguments
+    /// * `errors` - The errors to collect into the log
+    ///
+    /// # Returns
+    /// A new `SBMLErrorLog` containing the given errors and validation status
+    pub(crate) fn from_errors(errors: Vec<SBMLError>) -> Self {
         // Document is invalid if it contains any Error or Fatal severity errors
         let has_errors = errors.iter().any(|error| {
             error.severity == SBMLErrorSeverity::Error || error.severity == SBMLErrorSeverity::Fatal
@@ -62,6 +77,90 @@ impl SBMLErrorLog {
             errors,
         }
     }
+
+    /// Returns all errors matching the given predicate.
+    ///
+    /// # Arguments
+    /// * `predicate` - Function deciding whether an error is included
+    ///
+    /// # Returns
+    /// References to all matching errors in the order they were reported
+    pub fn filter<F>(&self, predicate: F) -> Vec<&SBMLError>
+    where
+        F: Fn(&SBMLError) -> bool,
+    {
+        self.errors
+            .iter()
+            .filter(|error| predicate(error))
+            .collect()
+    }
+
+    /// Checks whether any error matches the given predicate.
+    ///
+    /// # Arguments
+    /// * `predicate` - Function deciding whether an error matches
+    ///
+    /// # Returns
+    /// `true` if at least one error matches, `false` otherwise
+    pub fn any<F>(&self, predicate: F) -> bool
+    where
+        F: Fn(&SBMLError) -> bool,
+    {
+        self.errors.iter().any(predicate)
+    }
+
+    /// Counts the errors matching the given predicate.
+    ///
+    /// # Arguments
+    /// * `predicate` - Function deciding whether an error is counted
+    ///
+    /// # Returns
+    /// The number of matching errors
+    pub fn count<F>(&self, predicate: F) -> usize
+    where
+        F: Fn(&SBMLError) -> bool,
+    {
+        self.errors.iter().filter(|error| predicate(error)).count()
+    }
+
+    /// Returns all errors with the given severity.
+    ///
+    /// # Arguments
+    /// * `severity` - The severity level to select
+    ///
+    /// # Returns
+    /// References to all errors with the given severity
+    pub fn by_severity(&self, severity: SBMLErrorSeverity) -> Vec<&SBMLError> {
+        self.filter(|error| error.severity == severity)
+    }
+
+    /// Returns all errors of the given category.
+    ///
+    /// # Arguments
+    /// * `category` - The category to select (e.g. unit consistency)
+    ///
+    /// # Returns
+    /// References to all errors of the given category
+    pub fn by_category(&self, category: SBMLErrorCategory) -> Vec<&SBMLError> {
+        self.filter(|error| error.error_category == category)
+    }
+
+    /// Returns the number of errors with severity level Error or Fatal.
+    pub fn num_errors(&self) -> usize {
+        self.count(|error| {
+            error.severity == SBMLErrorSeverity::Error || error.severity == SBMLErrorSeverity::Fatal
+        })
+    }
+
+    /// Returns the number of errors with severity level Warning.
+    pub fn num_warnings(&self) -> usize {
+        self.count(|error| error.severity == SBMLErrorSeverity::Warning)
+    }
+
+    /// Checks whether the log contains no errors of any severity.
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
 }
 
 /// Represents a single SBML validation error.
@@ -81,6 +180,10 @@ pub struct SBMLError {
     pub column: u32,
     /// The category of the error (e.g., "SBML", "XML", etc.)
     pub category: String,
+    /// The category of the error as reported by libSBML
+    pub error_category: SBMLErrorCategory,
+    /// The package that reported the error (e.g., "core", "fbc")
+    pub package: String,
 }
 
 impl SBMLError {
@@ -99,14 +202,20 @@ impl SBMLError {
         let line = xml_error.as_ref().getLine().0;
         let column = xml_error.as_ref().getColumn().0;
         let category = xml_error.as_ref().getCategoryAsString().to_string();
+        let error_category = SBMLErrorCategory::from(xml_error.as_ref().getCategory().0);
         let severity = SBMLErrorSeverity::from(&*xml_error);
 
+        let sbml_error = unsafe { Pin::new_unchecked(&*error) };
+        let package = sbml_error.as_ref().getPackage().to_string();
+
         Self {
             message,
             severity,
             line,
             column,
             category,
+            error_category,
+            package,
         }
     }
 }
@@ -159,3 +268,223 @@ impl From<&sbmlcxx::XMLError> for SBMLErrorSeverity {
         }
     }
 }
+
+/// Represents the category of an SBML error.
+///
+/// Categories group errors by the kind of check that reported them, e.g. to
+/// separate unit consistency warnings from structural errors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SBMLErrorCategory {
+    /// General SBML conformance
+    Sbml,
+    /// Problems with the XML content
+    Xml,
+    /// Internal error in the SBML library
+    Internal,
+    /// Operating system errors (e.g., file I/O problems)
+    System,
+    /// Consistency of SBML components
+    GeneralConsistency,
+    /// Consistency of identifiers
+    IdentifierConsistency,
+    /// Consistency of units
+    UnitsConsistency,
+    /// Consistency of MathML expressions
+    MathMLConsistency,
+    /// Consistency of SBO terms
+    SBOConsistency,
+    /// The model is overdetermined
+    OverdeterminedModel,
+    /// Recommended modeling practice
+    ModelingPractice,
+    /// Internal consistency of the document
+    InternalConsistency,
+    /// Problems converting to another SBML level or version
+    Compatibility,
+    /// Unknown category
+    Unknown,
+}
+
+impl From<u32> for SBMLErrorCategory {
+    /// Converts a native libSBML category code to an SBMLErrorCategory.
+    ///
+    /// # Arguments
+    /// * `category` - The category code as returned by `XMLError::getCategory`
+    ///
+    /// # Returns
+    /// The corresponding SBMLErrorCategory variant
+    fn from(category: u32) -> Self {
+        use sbmlcxx::{SBMLErrorCategory_t as Sbml, XMLErrorCategory_t as Xml};
+
+        let categories = [
+            (Sbml::LIBSBML_CAT_SBML as u32, SBMLErrorCategory::Sbml),
+            (Xml::LIBSBML_CAT_XML as u32, SBMLErrorCategory::Xml),
+            (
+                Xml::LIBSBML_CAT_INTERNAL as u32,
+                SBMLErrorCategory::Internal,
+            ),
+            (Xml::LIBSBML_CAT_SYSTEM as u32, SBMLErrorCategory::System),
+            (
+                Sbml::LIBSBML_CAT_GENERAL_CONSISTENCY as u32,
+                SBMLErrorCategory::GeneralConsistency,
+            ),
+            (
+                Sbml::LIBSBML_CAT_IDENTIFIER_CONSISTENCY as u32,
+                SBMLErrorCategory::IdentifierConsistency,
+            ),
+            (
+                Sbml::LIBSBML_CAT_UNITS_CONSISTENCY as u32,
+                SBMLErrorCategory::UnitsConsistency,
+            ),
+            (
+                Sbml::LIBSBML_CAT_MATHML_CONSISTENCY as u32,
+                SBMLErrorCategory::MathMLConsistency,
+            ),
+            (
+                Sbml::LIBSBML_CAT_SBO_CONSISTENCY as u32,
+                SBMLErrorCategory::SBOConsistency,
+            ),
+            (
+                Sbml::LIBSBML_CAT_OVERDETERMINED_MODEL as u32,
+                SBMLErrorCategory::OverdeterminedModel,
+            ),
+            (
+                Sbml::LIBSBML_CAT_MODELING_PRACTICE as u32,
+                SBMLErrorCategory::ModelingPractice,
+            ),
+            (
+                Sbml::LIBSBML_CAT_INTERNAL_CONSISTENCY as u32,
+                SBMLErrorCategory::InternalConsistency,
+            ),
+            (
+                Sbml::LIBSBML_CAT_SBML_L1_COMPAT as u32,
+                SBMLErrorCategory::Compatibility,
+            ),
+            (
+                Sbml::LIBSBML_CAT_SBML_L2V1_COMPAT as u32,
+                SBMLErrorCategory::Compatibility,
+            ),
+            (
+                Sbml::LIBSBML_CAT_SBML_L2V2_COMPAT as u32,
+                SBMLErrorCategory::Compatibility,
+            ),
+            (
+                Sbml::LIBSBML_CAT_SBML_L2V3_COMPAT as u32,
+                SBMLErrorCategory::Compatibility,
+            ),
+            (
+                Sbml::LIBSBML_CAT_SBML_L2V4_COMPAT as u32,
+                SBMLErrorCategory::Compatibility,
+            ),
+            (
+                Sbml::LIBSBML_CAT_SBML_L3V1_COMPAT as u32,
+                SBMLErrorCategory::Compatibility,
+            ),
+        ];
+
+        categories
+            .into_iter()
+            .find(|(code, _)| *code == category)
+            .map(|(_, category)| category)
+            .unwrap_or(SBMLErrorCategory::Unknown)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn invalid_document() -> SBMLDocument {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("model");
+
+        // A species without a compartment and a negative concentration
+        model
+            .build_species("some")
+            .initial_concentration(-10.0)
+            .build();
+
+        // A parameter without value, units or constant flag raises warnings
+        model.build_parameter("test").build();
+
+        doc
+    }
+
+    #[test]
+    fn test_error_log_filter() {
+        let doc = invalid_document();
+        let log = doc.check_consistency();
+
+        let errors = log.filter(|e| e.severity == SBMLErrorSeverity::Error);
+        assert_eq!(errors.len(), 1);
+        assert!(errors
+            .iter()
+            .all(|e| e.severity == SBMLErrorSeverity::Error));
+
+        let none = log.filter(|e| e.severity == SBMLErrorSeverity::Fatal);
+        assert!(none.is_empty());
+    }
+
+    #[test]
+    fn test_error_log_any() {
+        let doc = invalid_document();
+        let log = doc.check_consistency();
+
+        assert!(log.any(|e| e.severity == SBMLErrorSeverity::Error && e.package == "core"));
+        assert!(!log.any(|e| e.severity == SBMLErrorSeverity::Error && e.package == "fbc"));
+    }
+
+    #[test]
+    fn test_error_log_count() {
+        let doc = invalid_document();
+        let log = doc.check_consistency();
+
+        let warnings = log.count(|e| e.severity == SBMLErrorSeverity::Warning);
+        let errors = log.count(|e| e.severity == SBMLErrorSeverity::Error);
+
+        assert!(warnings > 0);
+        assert_eq!(errors, 1);
+        assert_eq!(log.count(|_| true), log.errors.len());
+    }
+
+    #[test]
+    fn test_error_log_by_severity() {
+        let doc = invalid_document();
+        let log = doc.check_consistency();
+
+        assert_eq!(log.by_severity(SBMLErrorSeverity::Error).len(), 1);
+        assert!(log.by_severity(SBMLErrorSeverity::Fatal).is_empty());
+        assert_eq!(log.num_errors(), 1);
+        assert_eq!(
+            log.num_warnings(),
+            log.by_severity(SBMLErrorSeverity::Warning).len()
+        );
+        assert!(!log.is_empty());
+    }
+
+    #[test]
+    fn test_error_log_by_category() {
+        let doc = invalid_document();
+        let log = doc.check_consistency();
+
+        for error in log.errors.iter() {
+            assert_ne!(error.error_category, SBMLErrorCategory::Unknown);
+            assert!(log
+                .by_category(error.error_category)
+                .iter()
+                .all(|e| e.error_category == error.error_category));
+        }
+
+        assert!(log.by_category(SBMLErrorCategory::System).is_empty());
+    }
+
+    #[test]
+    fn test_error_log_empty() {
+        let log = SBMLErrorLog::from_errors(vec![]);
+
+        assert!(log.is_empty());
+        assert_eq!(log.num_errors(), 0);
+        assert_eq!(log.num_warnings(), 0);
+        assert!(log.by_severity(SBMLErrorSeverity::Error).is_empty());
+    }
+}
@@ -13,7 +13,9 @@ use std::{cell::RefCell, pin::Pin, rc::Rc};
 use cxx::let_cxx_string;
 
 use crate::{
-    clone, get_unit_definition, inner, into_id,
+    clone,
+    errors::LibSBMLError,
+    get_unit_definition, inner, into_id,
     model::Model,
     optional_property, pin_ptr,
     prelude::IntoId,
@@ -21,9 +23,23 @@ use crate::{
     sbmlcxx::{self},
     sbo_term,
     traits::{fromptr::FromPtr, sbase::SBase},
-    upcast_annotation,
+    upcast_annotation, upcast_notes,
 };
 
+/// The initial quantity of a species.
+///
+/// In SBML, a species' initial quantity is given either as an amount or as a
+/// concentration, but never both. This enum discriminates between the two.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InitialQuantity {
+    /// The species has an initial amount
+    Amount(f64),
+    /// The species has an initial concentration
+    Concentration(f64),
+    /// Neither an initial amount nor an initial concentration is set
+    Unset,
+}
+
 /// A safe wrapper around the libSBML Species class.
 ///
 /// This struct maintains a reference to the underlying C++ Species object
@@ -41,6 +57,9 @@ sbase!(Species<'a>, sbmlcxx::Species);
 // Set the annotation trait for the Species struct
 upcast_annotation!(Species<'a>, sbmlcxx::Species, sbmlcxx::SBase);
 
+// Set the notes trait for the Species struct
+upcast_notes!(Species<'a>, sbmlcxx::Species, sbmlcxx::SBase);
+
 // Implement the Clone trait for the Species struct
 clone!(Species<'a>, sbmlcxx::Species);
 
@@ -117,6 +136,23 @@ impl<'a> Species<'a> {
         isSetInitialConcentration
     );
 
+    /// Returns the initial quantity of the species.
+    ///
+    /// Since only one of initial amount and initial concentration is typically set,
+    /// this returns whichever of both is present.
+    ///
+    /// # Returns
+    /// The initial quantity as an [`InitialQuantity`]
+    pub fn initial_quantity(&self) -> InitialQuantity {
+        if let Some(amount) = self.initial_amount() {
+            InitialQuantity::Amount(amount)
+        } else if let Some(concentration) = self.initial_concentration() {
+            InitialQuantity::Concentration(concentration)
+        } else {
+            InitialQuantity::Unset
+        }
+    }
+
     // Setter and getter for unit
     optional_property!(Species<'a>, unit, String, getUnits, setUnits, isSetUnits);
 
@@ -156,6 +192,68 @@ impl<'a> Species<'a> {
     // Gets the unit definition for the species
     get_unit_definition!(units);
 
+    /// Checks whether this species participates in the given reaction.
+    ///
+    /// The species participates if it is referenced as a reactant, product or
+    /// modifier of the reaction.
+    ///
+    /// # Arguments
+    /// * `reaction_id` - The identifier of the reaction
+    ///
+    /// # Returns
+    /// `true` if the species participates, `false` otherwise or if the reaction does not exist
+    pub fn is_involved_in_reaction(&self, reaction_id: &str) -> bool {
+        let model_ptr = self.base().getModel();
+        if model_ptr.is_null() {
+            return false;
+        }
+
+        let mut model = pin_ptr!(model_ptr as *mut sbmlcxx::Model, sbmlcxx::Model);
+        let_cxx_string!(reaction_sid = reaction_id);
+        let reaction_ptr = model.as_mut().getReaction3(&reaction_sid);
+        if reaction_ptr.is_null() {
+            return false;
+        }
+
+        let mut reaction = pin_ptr!(reaction_ptr, sbmlcxx::Reaction);
+        let_cxx_string!(species_sid = self.id());
+
+        !reaction.as_mut().getReactant3(&species_sid).is_null()
+            || !reaction.as_mut().getProduct3(&species_sid).is_null()
+            || !reaction.as_mut().getModifier3(&species_sid).is_null()
+    }
+
+    /// Resolves the substance units of this species.
+    ///
+    /// Following SBML scoping, the species' own `substanceUnits` take precedence.
+    /// If they are not set, the `substanceUnits` default of the model applies.
+    ///
+    /// # Arguments
+    /// * `model` - The model containing this species
+    ///
+    /// # Returns
+    /// The identifier of the substance units, or None if neither is set
+    pub fn substance_unit(&self, model: &Model<'_>) -> Option<String> {
+        self.units().or_else(|| model.substance_units())
+    }
+
+    /// Renames this species and updates all references to it within the model.
+    ///
+    /// This is a convenience wrapper around [`Model::rename_sid`].
+    ///
+    /// # Arguments
+    /// * `model` - The model containing this species
+    /// * `new_id` - The new identifier of the species
+    ///
+    /// # Returns
+    /// The number of references that have been updated
+    ///
+    /// # Errors
+    /// Returns [`LibSBMLError::DuplicateId`] if `new_id` is already in use
+    pub fn rename(&self, model: &Model<'_>, new_id: &str) -> Result<usize, LibSBMLError> {
+        model.rename_sid(&self.id(), new_id)
+    }
+
     // SBO Term Methods generated by the `sbo_term` macro
     sbo_term!(sbmlcxx::Species, sbmlcxx::SBase);
 }
@@ -323,8 +421,7 @@ impl<'a> std::fmt::Debug for Species<'a> {
         ds.field("id", &self.id());
         ds.field("name", &self.name());
         ds.field("compartment", &self.compartment());
-        ds.field("initial_amount", &self.initial_amount());
-        ds.field("initial_concentration", &self.initial_concentration());
+        ds.field("initial_quantity", &self.initial_quantity());
         ds.field("unit", &self.unit());
         ds.field("boundary_condition", &self.boundary_condition());
         ds.field("constant", &self.constant());
@@ -333,6 +430,23 @@ impl<'a> std::fmt::Debug for Species<'a> {
     }
 }
 
+/// Compares two species by their SBML content.
+///
+/// Species are equal if their identifier, name, compartment, initial quantity, unit,
+/// boundary condition, constant and hasOnlySubstanceUnits attributes are equal.
+impl<'a> PartialEq for Species<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id() == other.id()
+            && self.name() == other.name()
+            && self.compartment() == other.compartment()
+            && self.initial_quantity() == other.initial_quantity()
+            && self.unit() == other.unit()
+            && self.boundary_condition() == other.boundary_condition()
+            && self.constant() == other.constant()
+            && self.has_only_substance_units() == other.has_only_substance_units()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{unit::UnitKind, SBMLDocument};
@@ -472,4 +586,240 @@ mod tests {
         assert_eq!(unit_definition.units()[1].multiplier(), 1.0);
         assert_eq!(unit_definition.units()[1].offset(), 0.0);
     }
+
+    #[test]
+    fn test_species_debug_initial_quantity() {
+        let doc = SBMLDocument::default();
+        let model = Model::new(&doc, "test");
+
+        let amount = model.build_species("amount").initial_amount(1.0).build();
+        let concentration = model
+            .build_species("concentration")
+            .initial_concentration(2.0)
+            .build();
+        let unset = model.build_species("unset").build();
+
+        assert_eq!(amount.initial_quantity(), InitialQuantity::Amount(1.0));
+        assert_eq!(
+            concentration.initial_quantity(),
+            InitialQuantity::Concentration(2.0)
+        );
+        assert_eq!(unset.initial_quantity(), InitialQuantity::Unset);
+
+        let debug = format!("{concentration:?}");
+        assert!(debug.contains("initial_quantity: Concentration(2.0)"));
+        assert!(!debug.contains("initial_amount"));
+    }
+
+    #[test]
+    fn test_species_rename() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        let species = model.build_species("glc").build();
+        let reaction = model
+            .build_reaction("r1")
+            .reactant("glc", 1.0)
+            .product("g6p", 1.0)
+            .build();
+        reaction.create_kinetic_law("k1 * glc");
+
+        let updated = species.rename(&model, "glucose").unwrap();
+
+        assert_eq!(updated, 2);
+        assert_eq!(species.id(), "glucose");
+        assert!(reaction.get_reactant("glucose").is_some());
+        assert!(reaction.get_reactant("glc").is_none());
+        assert_eq!(reaction.kinetic_law().unwrap().formula(), "k1 * glucose");
+    }
+
+    #[test]
+    fn test_species_substance_unit() {
+        let doc = SBMLDocument::default();
+        let model = Model::new(&doc, "test");
+        let species = model.create_species("s1");
+
+        assert_eq!(species.substance_unit(&model), None);
+
+        model.set_substance_units("mole");
+        assert_eq!(species.substance_unit(&model), Some("mole".to_string()));
+
+        species.set_units("item");
+        assert_eq!(species.substance_unit(&model), Some("item".to_string()));
+    }
+
+    #[test]
+    fn test_species_key_values() {
+        let doc = SBMLDocument::default();
+        let model = Model::new(&doc, "test");
+        let species = model.create_species("glucose");
+        let namespace = "https://example.org/meta";
+
+        species
+            .set_key_value(namespace, "keggId", "C00031")
+            .expect("Failed to set key-value");
+        species
+            .set_key_value(namespace, "chebiId", "CHEBI:4167")
+            .expect("Failed to set key-value");
+
+        assert_eq!(
+            species.get_key_value(namespace, "keggId"),
+            Some("C00031".to_string())
+        );
+        assert_eq!(
+            species.get_key_value(namespace, "chebiId"),
+            Some("CHEBI:4167".to_string())
+        );
+        assert_eq!(species.get_key_value(namespace, "missing"), None);
+        assert_eq!(species.get_key_value("https://other.org", "keggId"), None);
+
+        // Overwriting keeps a single entry per key
+        species
+            .set_key_value(namespace, "keggId", "C00267")
+            .expect("Failed to set key-value");
+        assert_eq!(
+            species.get_key_value(namespace, "keggId"),
+            Some("C00267".to_string())
+        );
+        assert_eq!(species.get_annotation().matches("keggId").count(), 1);
+    }
+
+    #[test]
+    fn test_species_cv_terms() {
+        use crate::cvterm::{BiologicalQualifier, Qualifier};
+
+        let doc = SBMLDocument::default();
+        let model = Model::new(&doc, "test");
+        let species = model.create_species("glucose");
+
+        species
+            .add_cv_term(
+                BiologicalQualifier::Is,
+                &["https://identifiers.org/CHEBI:17234"],
+            )
+            .expect("Failed to add CV term");
+
+        let annotation = species.get_annotation();
+        assert!(annotation.contains("bqbiol:is"));
+        assert!(annotation.contains("https://identifiers.org/CHEBI:17234"));
+
+        // Read the term back from the written document
+        let doc = crate::reader::SBMLReader::from_xml_string(&doc.to_xml_string());
+        let species = doc.model().unwrap().get_species("glucose").unwrap();
+
+        let cv_terms = species.cv_terms();
+        assert_eq!(cv_terms.len(), 1);
+        assert_eq!(
+            cv_terms[0].qualifier,
+            Qualifier::Biological(BiologicalQualifier::Is)
+        );
+        assert_eq!(
+            cv_terms[0].resources,
+            vec!["https://identifiers.org/CHEBI:17234".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_species_eq() {
+        let doc = SBMLDocument::default();
+        let model = Model::new(&doc, "test");
+        let species = model
+            .build_species("glucose")
+            .name("Glucose")
+            .compartment("cytosol")
+            .initial_concentration(1.5)
+            .build();
+
+        let clone = (*species).clone();
+        assert_eq!(*species, clone);
+
+        clone.set_initial_concentration(1.5000001);
+        assert!(*species != clone);
+    }
+
+    #[test]
+    fn test_species_set_role() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        let species = model.create_species("glucose");
+        species.set_role(crate::role::Role::SimpleChemical);
+
+        assert_eq!(species.sbo_term_id(), "SBO:0000247");
+        assert_eq!(species.role(), Some(crate::role::Role::SimpleChemical));
+    }
+
+    #[test]
+    fn test_species_metaid() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        let species = model.create_species("glucose");
+        assert!(!species.is_set_metaid());
+        assert_eq!(species.metaid(), None);
+
+        species.set_metaid("meta_glucose").unwrap();
+
+        assert!(species.is_set_metaid());
+        assert_eq!(species.metaid(), Some("meta_glucose".to_string()));
+        assert!(species
+            .to_xml_fragment()
+            .contains(r#"metaid="meta_glucose""#));
+        assert!(doc.to_xml_string().contains(r#"metaid="meta_glucose""#));
+        assert!(species.set_metaid("1 invalid").is_err());
+    }
+
+    #[test]
+    fn test_species_is_involved_in_reaction() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        let glucose = model.create_species("glucose");
+        let atp = model.create_species("atp");
+        let hk = model.create_species("hk");
+        model
+            .build_reaction("hexokinase")
+            .reactant("glucose", 1.0)
+            .reactant("atp", 1.0)
+            .modifier("hk")
+            .build();
+        model
+            .build_reaction("atp_synthase")
+            .product("atp", 1.0)
+            .build();
+
+        assert!(glucose.is_involved_in_reaction("hexokinase"));
+        assert!(!glucose.is_involved_in_reaction("atp_synthase"));
+        assert!(atp.is_involved_in_reaction("atp_synthase"));
+        assert!(hk.is_involved_in_reaction("hexokinase"));
+        assert!(!glucose.is_involved_in_reaction("missing"));
+    }
+
+    #[test]
+    fn test_species_unit_level_2() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<sbml xmlns="http://www.sbml.org/sbml/level2/version4" level="2" version="4">
+  <model id="test">
+    <listOfCompartments>
+      <compartment id="c" size="1"/>
+    </listOfCompartments>
+    <listOfSpecies>
+      <species id="glucose" compartment="c" initialAmount="1" substanceUnits="mole"/>
+    </listOfSpecies>
+  </model>
+</sbml>"#;
+
+        let doc = crate::reader::SBMLReader::from_xml_string(xml);
+        let model = doc.model().expect("Model not found");
+        let species = model.get_species("glucose").expect("Species not found");
+
+        // libSBML maps `units` to the level-specific substanceUnits attribute
+        assert_eq!(species.unit(), Some("mole".to_string()));
+        assert_eq!(species.units(), Some("mole".to_string()));
+
+        species.set_unit("item");
+        let xml = doc.to_xml_string();
+        assert!(xml.contains(r#"substanceUnits="item""#));
+
+        let doc = crate::reader::SBMLReader::from_xml_string(&xml);
+        let model = doc.model().expect("Model not found");
+        let species = model.get_species("glucose").expect("Species not found");
+        assert_eq!(species.unit(), Some("item".to_string()));
+    }
 }
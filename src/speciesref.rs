@@ -11,14 +11,15 @@ use cxx::let_cxx_string;
 use std::{cell::RefCell, pin::Pin, rc::Rc};
 
 use crate::{
-    clone, inner, pin_ptr,
+    clone, id_is_set, inner, pin_ptr,
     prelude::IntoId,
     reaction::Reaction,
     required_property, sbase,
     sbmlcxx::{self},
     sbo_term,
     traits::fromptr::FromPtr,
-    upcast, upcast_annotation, upcast_pin, upcast_required_property,
+    upcast, upcast_annotation, upcast_notes, upcast_optional_property, upcast_pin,
+    upcast_required_property,
 };
 
 /// A safe wrapper around the libSBML SpeciesReference class.
@@ -42,6 +43,13 @@ upcast_annotation!(
     sbmlcxx::SBase
 );
 
+// Set the notes trait for the SpeciesReference struct
+upcast_notes!(
+    SpeciesReference<'a>,
+    sbmlcxx::SpeciesReference,
+    sbmlcxx::SBase
+);
+
 // Implement the Clone trait for the SpeciesReference struct
 clone!(SpeciesReference<'a>, sbmlcxx::SpeciesReference);
 
@@ -115,6 +123,21 @@ impl<'a> SpeciesReference<'a> {
         setConstant
     );
 
+    // Getter and setter for id, which rules can target to vary the stoichiometry
+    upcast_optional_property!(
+        SpeciesReference<'a>,
+        id,
+        String,
+        getId,
+        setId,
+        isSetId,
+        sbmlcxx::SpeciesReference,
+        sbmlcxx::SimpleSpeciesReference
+    );
+
+    // Checks whether the id is set, generated by the `id_is_set` macro
+    id_is_set!(sbmlcxx::SpeciesReference, sbmlcxx::SBase);
+
     // SBO Term Methods generated by the `sbo_term` macro
     sbo_term!(sbmlcxx::SpeciesReference, sbmlcxx::SBase);
 }
@@ -369,4 +392,43 @@ mod tests {
             species_reference.get_annotation_serde().unwrap();
         assert_eq!(extracted_annotation.test, "test_annotation");
     }
+
+    /// Tests variable stoichiometry through an assignment rule
+    ///
+    /// This test verifies that:
+    /// - The id of a species reference can be set and retrieved
+    /// - An assignment rule targeting a non-constant species reference is consistent
+    #[test]
+    fn test_species_reference_id_assignment_rule() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test_model");
+        model.build_compartment("c").size(1.0).build();
+        for id in ["A", "B"] {
+            model
+                .build_species(id)
+                .compartment("c")
+                .initial_concentration(1.0)
+                .has_only_substance_units(false)
+                .boundary_condition(false)
+                .constant(false)
+                .build();
+        }
+
+        let reaction = model.create_reaction("r1");
+        reaction.set_reversible(false);
+        reaction.create_product("B", 1.0);
+        let reactant = reaction.create_reactant("A", 1.0);
+
+        assert_eq!(reactant.id(), None);
+
+        reactant.set_id("stoich_a");
+        reactant.set_constant(false);
+        model.create_assignment_rule("stoich_a", "2");
+
+        assert_eq!(reactant.id(), Some("stoich_a".to_string()));
+        assert!(!reactant.constant());
+
+        let log = doc.check_consistency();
+        assert!(log.valid, "{:#?}", log.errors);
+    }
 }
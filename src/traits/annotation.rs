@@ -32,11 +32,21 @@
 //! let retrieved: MyAnnotation = model.get_annotation_serde().unwrap();
 //! ```
 
-use std::error::Error;
+use std::{error::Error, ops::Range};
 
-use quick_xml::{DeError, SeError};
+use quick_xml::{
+    escape::escape,
+    events::Event,
+    name::{Namespace, ResolveResult},
+    DeError, NsReader, SeError,
+};
 use serde::{Deserialize, Serialize};
 
+use crate::{
+    cvterm::{CVTerm, Qualifier},
+    errors::LibSBMLError,
+};
+
 /// Trait for handling annotations in SBML elements.
 ///
 /// This trait provides functionality for getting and setting annotations on SBML elements
@@ -78,4 +88,155 @@ pub trait Annotation {
     /// # Returns
     /// A Result containing either the deserialized annotation or a deserialization error
     fn get_annotation_serde<T: for<'de> Deserialize<'de>>(&self) -> Result<T, DeError>;
+
+    /// Adds a controlled vocabulary (CV) term to this element.
+    ///
+    /// CV terms are stored as MIRIAM-compliant RDF within the annotation. They require a
+    /// meta identifier, so `meta_<id>` is assigned if the element has none.
+    ///
+    /// # Arguments
+    /// * `qualifier` - The qualifier of the term (e.g. `BiologicalQualifier::Is`)
+    /// * `resources` - The URIs of the referenced resources (e.g. "https://identifiers.org/CHEBI:17234")
+    ///
+    /// # Returns
+    /// `Ok(())` if the term has been added, or an error if libSBML rejected the term
+    fn add_cv_term(
+        &self,
+        qualifier: impl Into<Qualifier>,
+        resources: &[&str],
+    ) -> Result<(), LibSBMLError>;
+
+    /// Gets all controlled vocabulary (CV) terms of this element.
+    ///
+    /// # Returns
+    /// The CV terms in the order they are defined in the annotation
+    fn cv_terms(&self) -> Vec<CVTerm>;
+
+    /// Sets a key-value entry in the annotation of this element.
+    ///
+    /// Entries are stored in a dedicated `keyValues` block within the annotation,
+    /// one block per namespace. Other annotation content is left untouched and an
+    /// existing entry with the same key is overwritten.
+    ///
+    /// # Arguments
+    /// * `namespace` - The namespace URI of the key-value block (e.g. "https://example.org/meta")
+    /// * `key` - The key of the entry (e.g. "keggId")
+    /// * `value` - The value of the entry (e.g. "C00031")
+    fn set_key_value(&self, namespace: &str, key: &str, value: &str) -> Result<(), Box<dyn Error>> {
+        let annotation = self.get_annotation();
+        let (mut entries, block) = read_key_values(&annotation, namespace)?;
+
+        match entries.iter_mut().find(|(k, _)| k == key) {
+            Some(entry) => entry.1 = value.to_string(),
+            None => entries.push((key.to_string(), value.to_string())),
+        }
+
+        let block_xml = write_key_values(namespace, &entries);
+        let updated = match (block, annotation.rfind("</annotation>")) {
+            (Some(range), _) => format!(
+                "{}{}{}",
+                &annotation[..range.start],
+                block_xml,
+                &annotation[range.end..]
+            ),
+            (None, Some(end)) => {
+                format!("{}{}{}", &annotation[..end], block_xml, &annotation[end..])
+            }
+            (None, None) => format!("<annotation>{block_xml}</annotation>"),
+        };
+
+        self.set_annotation(&updated)
+    }
+
+    /// Gets a key-value entry from the annotation of this element.
+    ///
+    /// # Arguments
+    /// * `namespace` - The namespace URI of the key-value block
+    /// * `key` - The key of the entry
+    ///
+    /// # Returns
+    /// The value of the entry, or None if the entry does not exist
+    fn get_key_value(&self, namespace: &str, key: &str) -> Option<String> {
+        let (entries, _) = read_key_values(&self.get_annotation(), namespace).ok()?;
+        entries
+            .into_iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value)
+    }
+}
+
+/// Reads all key-value entries of the given namespace from an annotation.
+///
+/// # Returns
+/// The entries in document order and the byte range of the `keyValues` block
+/// within the annotation, if present
+fn read_key_values(
+    annotation: &str,
+    namespace: &str,
+) -> Result<(Vec<(String, String)>, Option<Range<usize>>), quick_xml::Error> {
+    let mut reader = NsReader::from_str(annotation);
+    let mut entries = Vec::new();
+    let mut block_start = None;
+    let mut block = None;
+
+    loop {
+        let position = reader.buffer_position() as usize;
+
+        match reader.read_resolved_event()? {
+            (ResolveResult::Bound(Namespace(ns)), Event::Start(e))
+                if ns == namespace.as_bytes() && e.local_name().as_ref() == b"keyValues" =>
+            {
+                block_start = Some(position);
+            }
+            (ResolveResult::Bound(Namespace(ns)), Event::Empty(e))
+                if ns == namespace.as_bytes() && e.local_name().as_ref() == b"keyValues" =>
+            {
+                block = Some(position..reader.buffer_position() as usize);
+            }
+            (ResolveResult::Bound(Namespace(ns)), Event::End(e))
+                if ns == namespace.as_bytes() && e.local_name().as_ref() == b"keyValues" =>
+            {
+                block = block_start
+                    .take()
+                    .map(|start| start..reader.buffer_position() as usize);
+            }
+            (ResolveResult::Bound(Namespace(ns)), Event::Empty(e) | Event::Start(e))
+                if ns == namespace.as_bytes() && e.local_name().as_ref() == b"entry" =>
+            {
+                let key = e.try_get_attribute("key")?;
+                let value = e.try_get_attribute("value")?;
+
+                if let (Some(key), Some(value)) = (key, value) {
+                    entries.push((
+                        key.unescape_value()?.into_owned(),
+                        value.unescape_value()?.into_owned(),
+                    ));
+                }
+            }
+            (_, Event::Eof) => break,
+            _ => {}
+        }
+    }
+
+    Ok((entries, block))
+}
+
+/// Serializes key-value entries into a `keyValues` block of the given namespace.
+fn write_key_values(namespace: &str, entries: &[(String, String)]) -> String {
+    let entries = entries
+        .iter()
+        .map(|(key, value)| {
+            format!(
+                r#"<entry key="{}" value="{}"/>"#,
+                escape(key.as_str()),
+                escape(value.as_str())
+            )
+        })
+        .collect::<String>();
+
+    format!(
+        r#"<keyValues xmlns="{}">{}</keyValues>"#,
+        escape(namespace),
+        entries
+    )
 }
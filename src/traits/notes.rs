@@ -0,0 +1,52 @@
+//! Notes handling for SBML elements
+//!
+//! Besides machine-readable annotations, SBML elements can carry human-readable notes.
+//! Notes are XHTML content wrapped in a `<notes>` element, e.g. a description of a
+//! reaction or the literature a parameter value was taken from.
+//!
+//! # Example
+//! ```no_run
+//! use sbml::prelude::*;
+//!
+//! let doc = SBMLDocument::default();
+//! let model = doc.create_model("example");
+//! let reaction = model.create_reaction("r1");
+//!
+//! // Plain text is wrapped in an XHTML paragraph
+//! reaction.set_notes("Catalysed by hexokinase").unwrap();
+//!
+//! // XHTML fragments are used as given
+//! reaction.set_notes("<p>Irreversible under physiological conditions</p>").unwrap();
+//!
+//! assert!(reaction.notes().contains("Irreversible"));
+//! ```
+
+use crate::errors::LibSBMLError;
+
+/// Trait for handling notes in SBML elements.
+///
+/// This trait provides functionality for getting and setting the human-readable XHTML
+/// notes of SBML elements like Models, Species, and Reactions.
+pub trait Notes {
+    /// Gets the notes of this element.
+    ///
+    /// # Returns
+    /// The notes including the enclosing `<notes>` element, or an empty String if unset
+    fn notes(&self) -> String;
+
+    /// Sets the notes of this element.
+    ///
+    /// The content may be an XHTML fragment (e.g. `<p>A note</p>`) or plain text,
+    /// which is wrapped in an XHTML paragraph. The enclosing `<notes>` element and the
+    /// XHTML namespace are added if missing.
+    ///
+    /// # Arguments
+    /// * `notes` - The XHTML fragment or plain text to set
+    ///
+    /// # Returns
+    /// Result indicating success or an error if the content is not valid XHTML
+    fn set_notes(&self, notes: &str) -> Result<(), LibSBMLError>;
+
+    /// Returns whether notes are set on this element.
+    fn is_set_notes(&self) -> bool;
+}
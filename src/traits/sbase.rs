@@ -1,4 +1,8 @@
-use crate::sbmlcxx;
+use std::ffi::CStr;
+
+use cxx::let_cxx_string;
+
+use crate::{errors::LibSBMLError, sbmlcxx};
 
 use super::inner::Inner;
 
@@ -8,4 +12,59 @@ pub(crate) trait SBase<'a, T>: Inner<'a, T> {
     /// This is useful when you need to pass a pinned reference to C++ code.
     #[allow(clippy::mut_from_ref)]
     fn base(&self) -> std::pin::Pin<&mut sbmlcxx::SBase>;
+
+    /// Serializes this element in isolation to an XML string.
+    ///
+    /// The string returned by libSBML is owned by the caller and is
+    /// released after it has been copied into a Rust `String`.
+    fn to_xml_fragment(&self) -> String {
+        let ptr = self.base().toSBML();
+        if ptr.is_null() {
+            return String::new();
+        }
+
+        let fragment = unsafe { CStr::from_ptr(ptr) }
+            .to_string_lossy()
+            .into_owned();
+
+        unsafe { sbmlcxx::safe_free(ptr as *mut autocxx::c_void) };
+
+        fragment
+    }
+
+    /// Returns the meta identifier of this element.
+    ///
+    /// The metaid is referenced by RDF annotations (e.g. CV terms) to link them to
+    /// the element.
+    fn metaid(&self) -> Option<String> {
+        let base = self.base();
+        if !base.isSetMetaId() {
+            return None;
+        }
+
+        Some(base.getMetaId().to_str().unwrap().to_string())
+    }
+
+    /// Sets the meta identifier of this element.
+    ///
+    /// Fails if the metaid is not a valid XML ID or the element does not support it.
+    fn set_metaid(&self, metaid: &str) -> Result<(), LibSBMLError> {
+        let_cxx_string!(cxx_metaid = metaid);
+        let result = self.base().setMetaId(&cxx_metaid);
+
+        // LIBSBML_OPERATION_SUCCESS
+        if result.0 != 0 {
+            return Err(LibSBMLError::InvalidArgument(format!(
+                "Invalid metaid: {metaid} (libSBML code {})",
+                result.0
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Returns whether the meta identifier of this element is set.
+    fn is_set_metaid(&self) -> bool {
+        self.base().isSetMetaId()
+    }
 }
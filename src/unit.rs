@@ -12,7 +12,7 @@ use std::{cell::RefCell, fmt::Display, pin::Pin, rc::Rc, str::FromStr};
 
 use crate::{
     clone, inner, pin_ptr, required_property, sbmlcxx, sbo_term, traits::fromptr::FromPtr,
-    unitdef::UnitDefinition, upcast_annotation,
+    unitdef::UnitDefinition, upcast_annotation, upcast_notes,
 };
 
 /// A safe wrapper around the libSBML Species class.
@@ -33,6 +33,9 @@ inner!(sbmlcxx::Unit, Unit<'a>);
 // Set the annotation trait for the Unit struct
 upcast_annotation!(Unit<'a>, sbmlcxx::Unit, sbmlcxx::SBase);
 
+// Set the notes trait for the Unit struct
+upcast_notes!(Unit<'a>, sbmlcxx::Unit, sbmlcxx::SBase);
+
 // Implement the Clone trait for the Unit struct
 clone!(Unit<'a>, sbmlcxx::Unit);
 
@@ -92,6 +95,19 @@ impl<'a> std::fmt::Debug for Unit<'a> {
     }
 }
 
+/// Compares two units by their SBML content.
+///
+/// Units are equal if their kind, exponent, multiplier, scale and offset are equal.
+impl<'a> PartialEq for Unit<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.kind() == other.kind()
+            && self.exponent() == other.exponent()
+            && self.multiplier() == other.multiplier()
+            && self.scale() == other.scale()
+            && self.offset() == other.offset()
+    }
+}
+
 impl FromPtr<sbmlcxx::Unit> for Unit<'_> {
     /// Creates a new Unit instance from a unique pointer to a libSBML Unit.
     ///
@@ -181,6 +197,18 @@ impl<'a> UnitBuilder<'a> {
         self
     }
 
+    /// Sets the scale of the unit from an SI prefix.
+    ///
+    /// # Arguments
+    /// * `prefix` - The SI prefix to apply (e.g. `SiPrefix::Milli` sets a scale of -3)
+    ///
+    /// # Returns
+    /// The builder for method chaining
+    pub fn prefix(self, prefix: SiPrefix) -> Self {
+        self.inner.set_scale(prefix.scale());
+        self
+    }
+
     /// Sets the offset of the unit.
     ///
     /// # Arguments
@@ -193,6 +221,32 @@ impl<'a> UnitBuilder<'a> {
         self
     }
 
+    /// Sets the annotation for this unit.
+    ///
+    /// # Arguments
+    /// * `annotation` - The XML annotation string to set
+    ///
+    /// # Returns
+    /// Result containing the builder instance or an error if the annotation is invalid
+    pub fn annotation(self, annotation: &str) -> Result<Self, SeError> {
+        self.inner
+            .set_annotation(annotation)
+            .map_err(|e| SeError::Custom(e.to_string()))?;
+        Ok(self)
+    }
+
+    /// Sets the annotation using a serializable type.
+    ///
+    /// # Arguments
+    /// * `annotation` - The annotation data to serialize and set
+    ///
+    /// # Returns
+    /// Result containing the builder instance or a serialization error
+    pub fn annotation_serde<T: Serialize>(self, annotation: &T) -> Result<Self, SeError> {
+        self.inner.set_annotation_serde(annotation)?;
+        Ok(self)
+    }
+
     /// Builds and returns the constructed Unit.
     ///
     /// # Returns
@@ -361,6 +415,7 @@ impl FromStr for UnitKind {
             "kelvin" => Ok(UnitKind::Kelvin),
             "kilogram" => Ok(UnitKind::Kilogram),
             "liter" => Ok(UnitKind::Liter),
+            "litre" => Ok(UnitKind::Litre),
             "lumen" => Ok(UnitKind::Lumen),
             "lux" => Ok(UnitKind::Lux),
             "meter" => Ok(UnitKind::Meter),
@@ -427,6 +482,51 @@ impl From<sbmlcxx::UnitKind_t> for UnitKind {
     }
 }
 
+/// Represents the SI prefixes that can be applied to a unit.
+///
+/// Each prefix corresponds to a power of ten, which is used as the `scale`
+/// attribute of an SBML unit (e.g. `Milli` corresponds to a scale of -3).
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SiPrefix {
+    Tera,
+    Giga,
+    Mega,
+    Kilo,
+    Hecto,
+    Deca,
+    Deci,
+    Centi,
+    Milli,
+    Micro,
+    Nano,
+    Pico,
+    Femto,
+}
+
+impl SiPrefix {
+    /// Returns the power of ten associated with this prefix.
+    ///
+    /// # Returns
+    /// The scale value to use for an SBML unit
+    pub fn scale(&self) -> i32 {
+        match self {
+            SiPrefix::Tera => 12,
+            SiPrefix::Giga => 9,
+            SiPrefix::Mega => 6,
+            SiPrefix::Kilo => 3,
+            SiPrefix::Hecto => 2,
+            SiPrefix::Deca => 1,
+            SiPrefix::Deci => -1,
+            SiPrefix::Centi => -2,
+            SiPrefix::Milli => -3,
+            SiPrefix::Micro => -6,
+            SiPrefix::Nano => -9,
+            SiPrefix::Pico => -12,
+            SiPrefix::Femto => -15,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use autocxx::c_int;
@@ -687,4 +787,62 @@ mod tests {
         let sbml_kind: sbmlcxx::UnitKind_t = kind.into();
         assert_eq!(kind, UnitKind::from(sbml_kind));
     }
+
+    #[test]
+    fn test_unit_builder_prefix() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        let unit_definition = model.create_unit_definition("mmol", "millimole");
+        let unit = unit_definition
+            .build_unit(UnitKind::Litre)
+            .kind(UnitKind::Mole)
+            .prefix(SiPrefix::Milli)
+            .build();
+
+        assert_eq!(unit.kind(), UnitKind::Mole);
+        assert_eq!(unit.scale(), -3);
+    }
+
+    #[test]
+    fn test_unit_annotation() {
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        let unit_definition = model.create_unit_definition("test", "test");
+        let unit = unit_definition
+            .build_unit(UnitKind::Mole)
+            .annotation("<test>test</test>")
+            .expect("Failed to set annotation")
+            .build();
+
+        assert_eq!(
+            unit.get_annotation().replace("\n", "").replace(" ", ""),
+            "<annotation><test>test</test></annotation>"
+        );
+    }
+
+    #[test]
+    fn test_unit_annotation_serde() {
+        #[derive(Serialize, Deserialize)]
+        struct TestAnnotation {
+            test: String,
+        }
+
+        let annotation = TestAnnotation {
+            test: "test".to_string(),
+        };
+
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        let unit_definition = model.create_unit_definition("test", "test");
+        let unit = unit_definition
+            .build_unit(UnitKind::Mole)
+            .annotation_serde(&annotation)
+            .expect("Failed to set annotation")
+            .build();
+
+        let extracted: TestAnnotation = unit
+            .get_annotation_serde()
+            .expect("Failed to get annotation");
+        assert_eq!(extracted.test, "test");
+    }
 }
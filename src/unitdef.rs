@@ -14,12 +14,12 @@ use cxx::let_cxx_string;
 use crate::{
     clone, inner, into_id,
     model::Model,
-    optional_property, pin_ptr, required_property,
+    optional_property, pin_ptr, required_property, sbase,
     sbmlcxx::{self},
     sbo_term,
     traits::fromptr::FromPtr,
     unit::{Unit, UnitBuilder, UnitKind},
-    upcast_annotation,
+    upcast_annotation, upcast_notes,
 };
 
 /// A safe wrapper around the libSBML UnitDefinition class.
@@ -34,9 +34,15 @@ pub struct UnitDefinition<'a> {
 // Set the inner trait for the UnitDefinition struct
 inner!(sbmlcxx::UnitDefinition, UnitDefinition<'a>);
 
+// Set the sbase trait for the UnitDefinition struct
+sbase!(UnitDefinition<'a>, sbmlcxx::UnitDefinition);
+
 // Set the annotation trait for the UnitDefinition struct
 upcast_annotation!(UnitDefinition<'a>, sbmlcxx::UnitDefinition, sbmlcxx::SBase);
 
+// Set the notes trait for the UnitDefinition struct
+upcast_notes!(UnitDefinition<'a>, sbmlcxx::UnitDefinition, sbmlcxx::SBase);
+
 // Implement the Clone trait for the UnitDefinition struct
 clone!(UnitDefinition<'a>, sbmlcxx::UnitDefinition, units);
 
@@ -120,6 +126,55 @@ impl<'a> UnitDefinition<'a> {
             .map(Rc::clone)
     }
 
+    /// Returns whether this unit definition is identical to another one.
+    ///
+    /// Both definitions must contain the same units with identical attributes
+    /// (kind, exponent, scale, multiplier and offset). The order of the units is ignored.
+    ///
+    /// # Arguments
+    /// * `other` - The unit definition to compare with
+    ///
+    /// # Returns
+    /// `true` if both unit definitions are identical, `false` otherwise
+    pub fn is_identical_to(&self, other: &UnitDefinition<'_>) -> bool {
+        let ud1: *const sbmlcxx::UnitDefinition = &**self.inner.borrow();
+        let ud2: *const sbmlcxx::UnitDefinition = &**other.inner.borrow();
+        unsafe { sbmlcxx::UnitDefinition::areIdentical(ud1, ud2) }
+    }
+
+    /// Returns whether this unit definition is dimensionally equivalent to another one.
+    ///
+    /// Both definitions are converted to SI units and like units are combined before
+    /// comparing kinds and exponents, so ordering, scales and multipliers are ignored
+    /// (e.g. `mole litre^-1` is equivalent to `litre^-1 mole` and `mole metre^-3`).
+    ///
+    /// # Arguments
+    /// * `other` - The unit definition to compare with
+    ///
+    /// # Returns
+    /// `true` if both unit definitions are equivalent, `false` otherwise
+    pub fn is_equivalent_to(&self, other: &UnitDefinition<'_>) -> bool {
+        let ud1: *const sbmlcxx::UnitDefinition = &**self.inner.borrow();
+        let ud2: *const sbmlcxx::UnitDefinition = &**other.inner.borrow();
+        unsafe { sbmlcxx::UnitDefinition::areEquivalent(ud1, ud2) }
+    }
+
+    /// Returns a simplified copy of this unit definition.
+    ///
+    /// Units of the same kind are combined into a single unit and units with an exponent
+    /// of zero are removed. libSBML deletes the merged units, so the simplification is
+    /// applied to a copy and this definition as well as its units stay untouched. Like
+    /// [`Clone::clone`], the copy is not part of the model.
+    ///
+    /// # Returns
+    /// A new UnitDefinition with the simplified units
+    pub fn simplify(&self) -> UnitDefinition<'a> {
+        let copy_ptr = self.inner.borrow_mut().as_mut().clone();
+        unsafe { sbmlcxx::UnitDefinition::simplify(copy_ptr) };
+
+        UnitDefinition::from_ptr(copy_ptr)
+    }
+
     // SBO Term Methods generated by the `sbo_term` macro
     sbo_term!(sbmlcxx::UnitDefinition, sbmlcxx::SBase);
 }
@@ -134,6 +189,16 @@ impl<'a> std::fmt::Debug for UnitDefinition<'a> {
     }
 }
 
+/// Compares two unit definitions by their SBML content.
+///
+/// Unit definitions are equal if their identifier, name and units are equal. Units
+/// are compared in order.
+impl<'a> PartialEq for UnitDefinition<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id() == other.id() && self.name() == other.name() && self.units() == other.units()
+    }
+}
+
 impl FromPtr<sbmlcxx::UnitDefinition> for UnitDefinition<'_> {
     /// Creates a new UnitDefinition instance from a unique pointer to a libSBML UnitDefinition.
     ///
@@ -219,6 +284,32 @@ impl<'a> UnitDefinitionBuilder<'a> {
         self
     }
 
+    /// Sets the annotation for this unit definition.
+    ///
+    /// # Arguments
+    /// * `annotation` - The XML annotation string to set
+    ///
+    /// # Returns
+    /// Result containing the builder instance or an error if the annotation is invalid
+    pub fn annotation(self, annotation: &str) -> Result<Self, SeError> {
+        self.unit_definition
+            .set_annotation(annotation)
+            .map_err(|e| SeError::Custom(e.to_string()))?;
+        Ok(self)
+    }
+
+    /// Sets the annotation using a serializable type.
+    ///
+    /// # Arguments
+    /// * `annotation` - The annotation data to serialize and set
+    ///
+    /// # Returns
+    /// Result containing the builder instance or a serialization error
+    pub fn annotation_serde<T: Serialize>(self, annotation: &T) -> Result<Self, SeError> {
+        self.unit_definition.set_annotation_serde(annotation)?;
+        Ok(self)
+    }
+
     /// Builds and returns the configured UnitDefinition instance.
     ///
     /// # Returns
@@ -345,4 +436,122 @@ mod tests {
             .expect("Failed to get annotation");
         assert_eq!(annotation.test, "test");
     }
+
+    #[test]
+    fn test_unit_definition_builder_annotation_serde_roundtrip() {
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        struct UnitAnnotation {
+            ontology: String,
+            term: String,
+        }
+
+        let annotation = UnitAnnotation {
+            ontology: "UO".to_string(),
+            term: "UO:0000098".to_string(),
+        };
+
+        let doc = SBMLDocument::default();
+        let model = doc.create_model("test");
+        model
+            .build_unit_definition("ml", "milliliter")
+            .unit(UnitKind::Litre, Some(1), Some(-3), None, None)
+            .annotation_serde(&annotation)
+            .expect("Failed to set annotation")
+            .build();
+
+        // Round-trip through XML to ensure the annotation is persisted
+        let doc = SBMLReader::from_xml_string(&doc.to_xml_string());
+        let model = doc.model().expect("Model not found");
+        let unit_definition = model
+            .get_unit_definition("ml")
+            .expect("Unit definition not found");
+
+        let extracted: UnitAnnotation = unit_definition
+            .get_annotation_serde()
+            .expect("Failed to get annotation");
+        assert_eq!(extracted, annotation);
+    }
+
+    #[test]
+    fn test_unit_definition_eq() {
+        let doc = SBMLDocument::default();
+        let model = Model::new(&doc, "test");
+        let unit_definition = model
+            .build_unit_definition("mM", "millimolar")
+            .unit(UnitKind::Mole, Some(1), Some(-3), None, None)
+            .unit(UnitKind::Litre, Some(-1), None, None, None)
+            .build();
+
+        let clone = (*unit_definition).clone();
+        assert_eq!(*unit_definition, clone);
+
+        clone.set_name("mmol/l");
+        assert_ne!(*unit_definition, clone);
+    }
+
+    #[test]
+    fn test_unit_definition_identical_and_equivalent() {
+        let doc = SBMLDocument::default();
+        let model = Model::new(&doc, "test");
+
+        let mole_per_litre = model
+            .build_unit_definition("mole_per_litre", "mole per litre")
+            .unit(UnitKind::Mole, Some(1), None, None, None)
+            .unit(UnitKind::Litre, Some(-1), None, None, None)
+            .build();
+        let per_litre_mole = model
+            .build_unit_definition("per_litre_mole", "per litre mole")
+            .unit(UnitKind::Litre, Some(-1), None, None, None)
+            .unit(UnitKind::Mole, Some(1), None, None, None)
+            .build();
+        let unsimplified = model
+            .build_unit_definition("unsimplified", "mole per litre squared times litre")
+            .unit(UnitKind::Mole, Some(1), None, None, None)
+            .unit(UnitKind::Litre, Some(-2), None, None, None)
+            .unit(UnitKind::Litre, Some(1), None, None, None)
+            .build();
+        let millimolar = model
+            .build_unit_definition("mM", "millimolar")
+            .unit(UnitKind::Mole, Some(1), Some(-3), None, None)
+            .unit(UnitKind::Litre, Some(-1), None, None, None)
+            .build();
+        let per_second = model
+            .build_unit_definition("per_second", "per second")
+            .unit(UnitKind::Second, Some(-1), None, None, None)
+            .build();
+
+        assert!(mole_per_litre.is_identical_to(&per_litre_mole));
+        assert!(!mole_per_litre.is_identical_to(&unsimplified));
+        assert!(!mole_per_litre.is_identical_to(&millimolar));
+
+        assert!(mole_per_litre.is_equivalent_to(&per_litre_mole));
+        assert!(mole_per_litre.is_equivalent_to(&unsimplified));
+        assert!(mole_per_litre.is_equivalent_to(&millimolar));
+        assert!(!mole_per_litre.is_equivalent_to(&per_second));
+    }
+
+    #[test]
+    fn test_unit_definition_simplify() {
+        let doc = SBMLDocument::default();
+        let model = Model::new(&doc, "test");
+
+        let unit_definition = model
+            .build_unit_definition("unsimplified", "mole per litre squared times litre")
+            .unit(UnitKind::Mole, Some(1), None, None, None)
+            .unit(UnitKind::Litre, Some(-2), None, None, None)
+            .unit(UnitKind::Litre, Some(1), None, None, None)
+            .build();
+
+        let units = unit_definition.units();
+        let simplified = unit_definition.simplify();
+
+        assert_eq!(simplified.units().len(), 2);
+        assert_eq!(simplified.get_unit(UnitKind::Litre).unwrap().exponent(), -1);
+        assert_eq!(simplified.get_unit(UnitKind::Mole).unwrap().exponent(), 1);
+
+        // The original definition and its units are left untouched
+        assert_eq!(unit_definition.units().len(), 3);
+        assert_eq!(units[2].exponent(), 1);
+        assert!(simplified.is_equivalent_to(&unit_definition));
+    }
 }